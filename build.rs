@@ -61,6 +61,64 @@ fn generate_luarc_json() {
     println!("cargo:warning=Generated .luarc.json for LSP support");
 }
 
+/// Emit annotated stubs for `(name, return type, params, description)` entries.
+///
+/// Methods are declared as `Owner:name` with an explicit `self` parameter,
+/// functions as `owner.name`.
+fn push_definitions(
+    output: &mut String,
+    owner: &str,
+    is_method: bool,
+    entries: &[(&str, &str, &str, &str)],
+) {
+    for (name, ret_type, params, desc) in entries {
+        output.push_str(&format!("---{}\n", desc));
+        if is_method {
+            output.push_str(&format!("---@param self {}\n", owner));
+        }
+
+        let mut new_params = "(".to_string();
+
+        // Parse parameters for proper annotations
+        if !params.is_empty() && *params != "()" {
+            // Strip only the outer parentheses so `fun(...)` types stay intact
+            let param_str = params
+                .strip_prefix('(')
+                .and_then(|p| p.strip_suffix(')'))
+                .unwrap_or(params);
+            for param in param_str.split(", ") {
+                if let Some((name, typ)) = param.split_once(": ") {
+                    // insert comma if needed
+                    if new_params != "(" {
+                        new_params.push_str(", ");
+                    }
+                    new_params.push_str(name.trim_end_matches('?'));
+                    output.push_str(&format!("---@param {} {}\n", name.trim(), typ));
+                }
+            }
+        }
+
+        new_params.push(')');
+
+        output.push_str(&format!("---@return {}\n", ret_type));
+        output.push_str(&format!(
+            "function {}{}{}{} end\n\n",
+            owner,
+            if is_method { ":" } else { "." },
+            name,
+            new_params.trim()
+        ));
+    }
+}
+
+fn push_methods(output: &mut String, class: &str, methods: &[(&str, &str, &str, &str)]) {
+    push_definitions(output, class, true, methods);
+}
+
+fn push_functions(output: &mut String, module: &str, functions: &[(&str, &str, &str, &str)]) {
+    push_definitions(output, module, false, functions);
+}
+
 fn generate_raylib_definitions() {
     let mut output = String::new();
 
@@ -81,13 +139,149 @@ fn generate_raylib_definitions() {
     // Vector2 type
     output.push_str("---@class Vector2\n");
     output.push_str("---@field x number X coordinate\n");
-    output.push_str("---@field y number Y coordinate\n\n");
+    output.push_str("---@field y number Y coordinate\n");
+    for op in ["add", "sub", "mul", "div", "mod", "pow"] {
+        output.push_str(&format!("---@operator {}(Vector2|number): Vector2\n", op));
+    }
+    output.push_str("---@operator unm: Vector2\n");
+    output.push_str("---@operator len: number\n");
+    output.push_str("---@operator concat(any): string\n");
+    output.push_str("local Vector2 = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "Vector2",
+        &[
+            ("length", "number", "()", "Vector length"),
+            (
+                "length_sqr",
+                "number",
+                "()",
+                "Squared vector length, cheaper than length()",
+            ),
+            (
+                "normalize",
+                "Vector2",
+                "()",
+                "Unit vector in the same direction",
+            ),
+            ("dot", "number", "(other: Vector2)", "Dot product"),
+            (
+                "cross",
+                "number",
+                "(other: Vector2)",
+                "Z component of the cross product",
+            ),
+            (
+                "angle",
+                "number",
+                "(other?: Vector2)",
+                "Angle of the vector in radians, or the signed angle to other",
+            ),
+            (
+                "rotate",
+                "Vector2",
+                "(angle: number)",
+                "Rotate by an angle in radians",
+            ),
+            (
+                "distance",
+                "number",
+                "(other: Vector2)",
+                "Distance to another vector",
+            ),
+            (
+                "lerp",
+                "Vector2",
+                "(other: Vector2, amount: number)",
+                "Linear interpolation towards another vector",
+            ),
+            (
+                "move_towards",
+                "Vector2",
+                "(target: Vector2, max_distance: number)",
+                "Move towards a target by at most max_distance",
+            ),
+            (
+                "clamp",
+                "Vector2",
+                "(min: Vector2|number, max: Vector2|number)",
+                "Clamp each component between min and max",
+            ),
+            (
+                "reflect",
+                "Vector2",
+                "(normal: Vector2)",
+                "Reflect off a surface with the given normal",
+            ),
+            (
+                "perpendicular",
+                "Vector2",
+                "()",
+                "The vector rotated 90 degrees counter-clockwise",
+            ),
+            ("copy", "Vector2", "()", "Create a copy of the vector"),
+            ("unpack", "number, number", "()", "Return x and y"),
+        ],
+    );
 
     // Vector3 type
     output.push_str("---@class Vector3\n");
     output.push_str("---@field x number X coordinate\n");
     output.push_str("---@field y number Y coordinate\n");
-    output.push_str("---@field z number Z coordinate\n\n");
+    output.push_str("---@field z number Z coordinate\n");
+    for op in ["add", "sub", "mul", "div"] {
+        output.push_str(&format!("---@operator {}(Vector3|number): Vector3\n", op));
+    }
+    output.push_str("---@operator unm: Vector3\n");
+    output.push_str("---@operator len: number\n");
+    output.push_str("local Vector3 = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "Vector3",
+        &[
+            ("dot", "number", "(other: Vector3)", "Dot product"),
+            ("cross", "Vector3", "(other: Vector3)", "Cross product"),
+            ("length", "number", "()", "Vector length"),
+            (
+                "normalize",
+                "Vector3",
+                "()",
+                "Unit vector in the same direction",
+            ),
+            (
+                "distance",
+                "number",
+                "(other: Vector3)",
+                "Distance to another vector",
+            ),
+            (
+                "lerp",
+                "Vector3",
+                "(other: Vector3, amount: number)",
+                "Linear interpolation towards another vector",
+            ),
+            (
+                "rotate_by_axis_angle",
+                "Vector3",
+                "(axis: Vector3, angle: number)",
+                "Rotate around an axis by an angle in radians",
+            ),
+            (
+                "transform",
+                "Vector3",
+                "(matrix: number[]|table)",
+                "Transform by a 4x4 matrix given as m0..m15 fields or 16 numbers row by row",
+            ),
+            (
+                "reflect",
+                "Vector3",
+                "(normal: Vector3)",
+                "Reflect off a surface with the given normal",
+            ),
+        ],
+    );
 
     // Rectangle type
     output.push_str("---@class Rectangle\n");
@@ -120,7 +314,442 @@ fn generate_raylib_definitions() {
     );
     output.push_str("---@field zoom number Camera zoom (scaling), default 1.0\n\n");
 
-    output.push_str("---@class Font\n\n");
+    output.push_str("---@alias CameraProjection \"perspective\"|\"orthographic\"\n");
+    output.push_str(
+        "---@alias CameraMode \"custom\"|\"free\"|\"orbital\"|\"first_person\"|\"third_person\"\n\n",
+    );
+
+    output.push_str("---@class Camera3D\n");
+    output.push_str("---@field position Vector3 Camera position\n");
+    output.push_str("---@field target Vector3 Camera target it looks-at\n");
+    output.push_str("---@field up Vector3 Camera up vector (rotation over its axis)\n");
+    output.push_str(
+        "---@field fovy number Camera field-of-view aperture in Y (degrees) in perspective, used as near plane width in orthographic\n",
+    );
+    output.push_str("---@field projection CameraProjection Camera projection\n\n");
+
+    // Font type
+    output.push_str("---Font in GPU memory, unloaded when collected or closed\n");
+    output.push_str("---@class Font\n");
+    output.push_str("---@field base_size integer Base size (default chars height)\n");
+    output.push_str("---@field glyph_count integer Number of glyph characters\n");
+    output.push_str("---@field glyph_padding integer Padding around the glyph characters\n");
+    output.push_str("local Font = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "Font",
+        &[
+            (
+                "is_valid",
+                "boolean",
+                "()",
+                "Check if the font is loaded and its window is still open",
+            ),
+            (
+                "unload",
+                "nil",
+                "()",
+                "Unload the font from GPU memory (the default font is never unloaded)",
+            ),
+        ],
+    );
+
+    output.push_str("---Codepoints to load, as a string of characters or a list of integers\n");
+    output.push_str("---@alias Codepoints string|integer[]\n\n");
+
+    // Text layout
+    output.push_str("---Options for draw_text_boxed and layout_text\n");
+    output.push_str("---@class TextLayoutOptions\n");
+    output.push_str(
+        "---@field wrap? \"word\"|\"char\"|\"none\" Where lines may break (default \"word\")\n",
+    );
+    output.push_str("---@field align? \"left\"|\"center\"|\"right\"|\"justify\" Horizontal alignment (default \"left\")\n");
+    output.push_str(
+        "---@field valign? \"top\"|\"middle\"|\"bottom\" Vertical alignment (default \"top\")\n",
+    );
+    output.push_str("---@field ellipsis? boolean|string Drop lines that do not fit and end the last one with \"...\" or this string\n");
+    output.push_str("---@field line_spacing? number Extra space between lines (default 0)\n\n");
+
+    output.push_str("---One line of a text layout\n");
+    output.push_str("---@class TextLayoutLine\n");
+    output.push_str("---@field text string Text drawn on the line, including any ellipsis\n");
+    output.push_str("---@field start integer Index of the line's first character in the source text (1-based, in characters)\n");
+    output.push_str("---@field length integer Number of source characters on the line\n");
+    output.push_str("---@field x number\n");
+    output.push_str("---@field y number\n");
+    output.push_str("---@field width number\n");
+    output.push_str("---@field height number\n\n");
+
+    output.push_str("---Result of layout_text\n");
+    output.push_str("---@class TextLayout\n");
+    output.push_str("---@field lines TextLayoutLine[]\n");
+    output.push_str("---@field width number Width of the widest line\n");
+    output.push_str("---@field height number Height of the laid out block\n");
+    output.push_str("---@field truncated boolean Whether the ellipsis cut any text\n\n");
+
+    // Texture type
+    output.push_str("---GPU texture, unloaded when collected or closed\n");
+    output.push_str("---@class Texture\n");
+    output.push_str("---@field id integer OpenGL texture id\n");
+    output.push_str("---@field width integer Texture width\n");
+    output.push_str("---@field height integer Texture height\n");
+    output.push_str("---@field mipmaps integer Mipmap levels\n");
+    output.push_str("local Texture = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "Texture",
+        &[
+            (
+                "is_valid",
+                "boolean",
+                "()",
+                "Check if the texture can still be drawn",
+            ),
+            ("unload", "nil", "()", "Unload the texture from GPU memory"),
+        ],
+    );
+
+    // RenderTexture type
+    output.push_str("---Offscreen render target, unloaded when collected or closed\n");
+    output.push_str("---@class RenderTexture\n");
+    output.push_str("---@field id integer OpenGL framebuffer id\n");
+    output.push_str("---@field width integer Render texture width\n");
+    output.push_str("---@field height integer Render texture height\n");
+    output.push_str("---@field texture Texture Color buffer, valid while the render texture is loaded (stored upside down, draw it with a negative source height)\n");
+    output.push_str("local RenderTexture = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "RenderTexture",
+        &[
+            (
+                "is_valid",
+                "boolean",
+                "()",
+                "Check if the render texture can still be used",
+            ),
+            (
+                "unload",
+                "nil",
+                "()",
+                "Unload the render texture from GPU memory",
+            ),
+        ],
+    );
+
+    // Shader type
+    output.push_str("---GLSL shader program, unloaded when collected or closed\n");
+    output.push_str("---@class Shader\n");
+    output.push_str("---@field id integer OpenGL program id\n");
+    output.push_str("local Shader = {}\n\n");
+
+    output.push_str("---Uniform value: a number (float), boolean (int), Vector2/Vector3 or {x, y, z, w} table, list of 1-4 numbers, Color (normalized to vec4; plain lists too with uniform_type \"color\"), Matrix as m0..m15 fields or 16 numbers row by row (like Vector3:transform) or Texture\n");
+    output.push_str(
+        "---@alias UniformValue number|boolean|Vector2|Vector3|Color|number[]|Texture\n\n",
+    );
+    output.push_str("---@alias UniformType \"float\"|\"vec2\"|\"vec3\"|\"vec4\"|\"int\"|\"ivec2\"|\"ivec3\"|\"ivec4\"|\"mat4\"|\"sampler2d\"|\"color\"\n\n");
+
+    output.push_str("---@alias Points (Vector2|{x: number, y: number})[]\n\n");
+
+    output.push_str("---@alias BlendModeName \"alpha\"|\"additive\"|\"multiplied\"|\"add_colors\"|\"subtract_colors\"|\"alpha_premultiply\"|\"custom\"|\"custom_separate\"\n\n");
+
+    push_methods(
+        &mut output,
+        "Shader",
+        &[
+            (
+                "is_valid",
+                "boolean",
+                "()",
+                "Check if the shader can still be used",
+            ),
+            ("unload", "nil", "()", "Unload the shader from GPU memory"),
+            (
+                "get_location",
+                "integer",
+                "(name: string)",
+                "Get a uniform location, -1 if the shader has no such uniform",
+            ),
+            (
+                "set_value",
+                "nil",
+                "(location: integer|string, value: UniformValue, uniform_type?: UniformType)",
+                "Set a uniform, inferring its type from the value unless uniform_type is given",
+            ),
+        ],
+    );
+
+    // Image type
+    output.push_str("---CPU-side image data\n");
+    output.push_str("---@class Image\n");
+    output.push_str("---@field width integer Image width\n");
+    output.push_str("---@field height integer Image height\n");
+    output.push_str("---@field mipmaps integer Mipmap levels\n");
+    output.push_str("---@field format integer Pixel format\n");
+    output.push_str("local Image = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "Image",
+        &[
+            (
+                "get_pixel",
+                "Color",
+                "(x: integer, y: integer)",
+                "Get pixel color at (x, y)",
+            ),
+            (
+                "set_pixel",
+                "nil",
+                "(x: integer, y: integer, color: Color)",
+                "Set pixel color at (x, y)",
+            ),
+            (
+                "crop",
+                "nil",
+                "(rect: Rectangle)",
+                "Crop the image to a rectangle",
+            ),
+            (
+                "resize",
+                "nil",
+                "(width: integer, height: integer)",
+                "Resize the image (bicubic scaling)",
+            ),
+            (
+                "flip",
+                "nil",
+                "(direction: \"horizontal\"|\"vertical\")",
+                "Flip the image horizontally or vertically",
+            ),
+            (
+                "rotate",
+                "nil",
+                "(degrees: integer)",
+                "Rotate the image by an angle in degrees (-359 to 359)",
+            ),
+            (
+                "color_tint",
+                "nil",
+                "(color: Color)",
+                "Modify image color: tint",
+            ),
+            ("copy", "Image", "()", "Create a copy of the image"),
+            (
+                "export",
+                "nil",
+                "(path: string)",
+                "Export image data to file, format is chosen by extension",
+            ),
+            (
+                "to_texture",
+                "Texture",
+                "(window: Window)",
+                "Upload the image to GPU memory as a texture",
+            ),
+        ],
+    );
+
+    // Audio types
+    output.push_str("---Audio device, closing it invalidates every Sound and Music it loaded\n");
+    output.push_str("---@class AudioDevice\n");
+    output.push_str("local AudioDevice = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "AudioDevice",
+        &[
+            (
+                "is_ready",
+                "boolean",
+                "()",
+                "Check if the audio device is ready",
+            ),
+            (
+                "get_master_volume",
+                "number",
+                "()",
+                "Get master volume (listener)",
+            ),
+            (
+                "set_master_volume",
+                "nil",
+                "(volume: number)",
+                "Set master volume (listener)",
+            ),
+            (
+                "load_sound",
+                "Sound",
+                "(path: string)",
+                "Load sound from file",
+            ),
+            (
+                "load_sound_from_wave",
+                "Sound",
+                "(wave: Wave)",
+                "Load sound from wave data",
+            ),
+            (
+                "load_music",
+                "Music",
+                "(path: string)",
+                "Load music stream from file",
+            ),
+            (
+                "load_wave",
+                "Wave",
+                "(path: string)",
+                "Load wave data from file into CPU memory",
+            ),
+            (
+                "close",
+                "nil",
+                "()",
+                "Close the audio device and invalidate its sounds and music",
+            ),
+        ],
+    );
+
+    output.push_str("---Sound effect loaded into an audio buffer\n");
+    output.push_str("---@class Sound\n");
+    output.push_str("---@field frame_count integer Total number of frames\n");
+    output.push_str("local Sound = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "Sound",
+        &[
+            ("play", "nil", "()", "Play the sound"),
+            ("stop", "nil", "()", "Stop playing the sound"),
+            ("pause", "nil", "()", "Pause the sound"),
+            ("resume", "nil", "()", "Resume a paused sound"),
+            (
+                "is_playing",
+                "boolean",
+                "()",
+                "Check if the sound is playing",
+            ),
+            (
+                "set_volume",
+                "nil",
+                "(volume: number)",
+                "Set volume (1.0 is max level)",
+            ),
+            (
+                "set_pitch",
+                "nil",
+                "(pitch: number)",
+                "Set pitch (1.0 is base level)",
+            ),
+            ("set_pan", "nil", "(pan: number)", "Set pan (0.5 is center)"),
+            (
+                "is_valid",
+                "boolean",
+                "()",
+                "Check if the sound can still be played",
+            ),
+            ("unload", "nil", "()", "Unload the sound"),
+        ],
+    );
+
+    output.push_str("---Music stream, call update() every frame while it plays\n");
+    output.push_str("---@class Music\n");
+    output.push_str("---@field frame_count integer Total number of frames\n");
+    output.push_str("---@field looping boolean Restart when the stream ends\n");
+    output.push_str("local Music = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "Music",
+        &[
+            ("play", "nil", "()", "Start playing the music stream"),
+            ("update", "nil", "()", "Refill the stream buffers"),
+            ("stop", "nil", "()", "Stop the music stream"),
+            ("pause", "nil", "()", "Pause the music stream"),
+            ("resume", "nil", "()", "Resume a paused music stream"),
+            (
+                "is_playing",
+                "boolean",
+                "()",
+                "Check if the music stream is playing",
+            ),
+            (
+                "seek",
+                "nil",
+                "(position: number)",
+                "Seek to a position in seconds",
+            ),
+            (
+                "set_volume",
+                "nil",
+                "(volume: number)",
+                "Set volume (1.0 is max level)",
+            ),
+            (
+                "set_pitch",
+                "nil",
+                "(pitch: number)",
+                "Set pitch (1.0 is base level)",
+            ),
+            ("set_pan", "nil", "(pan: number)", "Set pan (0.5 is center)"),
+            (
+                "get_time_length",
+                "number",
+                "()",
+                "Get total length in seconds",
+            ),
+            (
+                "get_time_played",
+                "number",
+                "()",
+                "Get current playing time in seconds",
+            ),
+            (
+                "is_valid",
+                "boolean",
+                "()",
+                "Check if the music stream can still be played",
+            ),
+            ("unload", "nil", "()", "Unload the music stream"),
+        ],
+    );
+
+    output.push_str("---Wave samples in CPU memory\n");
+    output.push_str("---@class Wave\n");
+    output.push_str("---@field frame_count integer Total number of frames\n");
+    output.push_str("---@field sample_rate integer Frequency (samples per second)\n");
+    output.push_str("---@field sample_size integer Bits per sample\n");
+    output.push_str("---@field channels integer Number of channels\n");
+    output.push_str("local Wave = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "Wave",
+        &[
+            ("copy", "Wave", "()", "Create a copy of the wave"),
+            (
+                "crop",
+                "nil",
+                "(init_frame: integer, final_frame: integer)",
+                "Crop the wave to a range of frames",
+            ),
+            (
+                "format",
+                "nil",
+                "(sample_rate: integer, sample_size: integer, channels: integer)",
+                "Convert wave data to the desired format",
+            ),
+            (
+                "export",
+                "nil",
+                "(path: string)",
+                "Export wave data to file",
+            ),
+            ("unload", "nil", "()", "Unload the wave data"),
+        ],
+    );
 
     // Window class
     output.push_str("---Raylib window handle\n");
@@ -157,9 +786,9 @@ fn generate_raylib_definitions() {
         // Drawing functions
         (
             "begin_drawing",
-            "nil",
+            "DrawContext",
             "()",
-            "Setup canvas (framebuffer) to start drawing",
+            "Setup canvas (framebuffer) to start drawing, returns the frame's drawing context",
         ),
         (
             "end_drawing",
@@ -167,6 +796,12 @@ fn generate_raylib_definitions() {
             "()",
             "End canvas drawing and swap buffers (double buffering)",
         ),
+        (
+            "draw_frame",
+            "nil",
+            "(callback: fun(ctx: DrawContext))",
+            "Draw one frame with callback; the frame is ended even if callback errors",
+        ),
         (
             "clear_background",
             "nil",
@@ -177,14 +812,20 @@ fn generate_raylib_definitions() {
         (
             "draw_text",
             "nil",
-            "(text: string, x: integer, y: integer, size: integer, color: Color)",
-            "Draw text (using default font)",
+            "(text: string, x: number, y: number, size: number, color: Color)",
+            "Draw text (using default font), x and y may also be given as one Vector2",
         ),
         (
             "draw_rectangle",
             "nil",
-            "(x: integer, y: integer, width: integer, height: integer, color: Color)",
-            "Draw a color-filled rectangle",
+            "(x: number, y: number, width: number, height: number, color: Color)",
+            "Draw a color-filled rectangle, position and size may also be given as Vector2s",
+        ),
+        (
+            "draw_rectangle_v",
+            "nil",
+            "(position: Vector2, size: Vector2, color: Color)",
+            "Draw a color-filled rectangle (Vector version)",
         ),
         (
             "draw_rectangle_rec",
@@ -201,14 +842,20 @@ fn generate_raylib_definitions() {
         (
             "draw_circle",
             "nil",
-            "(x: integer, y: integer, radius: number, color: Color)",
-            "Draw a color-filled circle",
+            "(x: number, y: number, radius: number, color: Color)",
+            "Draw a color-filled circle, x and y may also be given as one Vector2",
         ),
         (
             "draw_line",
             "nil",
-            "(x1: integer, y1: integer, x2: integer, y2: integer, color: Color)",
-            "Draw line",
+            "(x1: number, y1: number, x2: number, y2: number, color: Color)",
+            "Draw line, each end point may also be given as one Vector2",
+        ),
+        (
+            "draw_line_v",
+            "nil",
+            "(start_pos: Vector2, end_pos: Vector2, color: Color)",
+            "Draw line (Vector version)",
         ),
         (
             "draw_line_ex",
@@ -219,8 +866,14 @@ fn generate_raylib_definitions() {
         (
             "draw_pixel",
             "nil",
-            "(x: integer, y: integer, color: Color)",
-            "Draw pixel",
+            "(x: number, y: number, color: Color)",
+            "Draw pixel, x and y may also be given as one Vector2",
+        ),
+        (
+            "draw_pixel_v",
+            "nil",
+            "(position: Vector2, color: Color)",
+            "Draw pixel (Vector version)",
         ),
         (
             "draw_ring",
@@ -231,44 +884,182 @@ fn generate_raylib_definitions() {
         (
             "draw_rectangle_lines",
             "nil",
-            "(x: integer, y: integer, width: integer, height: integer, color: Color)",
+            "(x: number, y: number, width: number, height: number, color: Color)",
             "Draw rectangle outline",
         ),
         (
             "draw_circle_lines",
             "nil",
-            "(x: integer, y: integer, radius: number, color: Color)",
+            "(x: number, y: number, radius: number, color: Color)",
             "Draw circle outline",
         ),
         (
             "draw_circle_v",
             "nil",
-            "(v: Vector2, radius: number, color: Color)",
-            "Draw circle outline",
+            "(center: Vector2, radius: number, color: Color)",
+            "Draw a color-filled circle (Vector version)",
+        ),
+        (
+            "draw_rectangle_rounded",
+            "nil",
+            "(rec: Rectangle, roundness: number, segments: integer, color: Color)",
+            "Draw rectangle with rounded corners, rec may also be given as four numbers",
+        ),
+        (
+            "draw_rectangle_rounded_lines",
+            "nil",
+            "(rec: Rectangle, roundness: number, segments: integer, color: Color, thick?: number)",
+            "Draw rounded rectangle outline, with an optional line thickness, rec may also be given as four numbers",
+        ),
+        (
+            "draw_rectangle_gradient_v",
+            "nil",
+            "(x: number, y: number, width: number, height: number, top: Color, bottom: Color)",
+            "Draw rectangle with a vertical gradient",
+        ),
+        (
+            "draw_rectangle_gradient_h",
+            "nil",
+            "(x: number, y: number, width: number, height: number, left: Color, right: Color)",
+            "Draw rectangle with a horizontal gradient",
+        ),
+        (
+            "draw_rectangle_gradient_ex",
+            "nil",
+            "(rec: Rectangle, top_left: Color, bottom_left: Color, top_right: Color, bottom_right: Color)",
+            "Draw rectangle with a color per corner, rec may also be given as four numbers",
+        ),
+        (
+            "draw_rectangle_pro",
+            "nil",
+            "(rec: Rectangle, origin: Vector2, rotation: number, color: Color)",
+            "Draw rectangle rotated around origin, Vector2 arguments may also be given as two numbers and Rectangle arguments as four numbers",
+        ),
+        (
+            "draw_rectangle_lines_ex",
+            "nil",
+            "(rec: Rectangle, thick: number, color: Color)",
+            "Draw rectangle outline with thickness, rec may also be given as four numbers",
+        ),
+        (
+            "draw_circle_sector",
+            "nil",
+            "(center: Vector2, radius: number, start_angle: number, end_angle: number, segments: integer, color: Color)",
+            "Draw circle sector, center may also be given as two numbers",
+        ),
+        (
+            "draw_circle_gradient",
+            "nil",
+            "(x: number, y: number, radius: number, inner: Color, outer: Color)",
+            "Draw circle with a radial gradient, x and y may also be given as one Vector2",
+        ),
+        (
+            "draw_ellipse",
+            "nil",
+            "(x: number, y: number, radius_h: number, radius_v: number, color: Color)",
+            "Draw ellipse, x and y may also be given as one Vector2",
+        ),
+        (
+            "draw_ring_lines",
+            "nil",
+            "(center: Vector2, inner_radius: number, outer_radius: number, start_angle: number, end_angle: number, segments: integer, color: Color)",
+            "Draw ring outline, center may also be given as two numbers",
+        ),
+        (
+            "draw_triangle_lines",
+            "nil",
+            "(v1: Vector2, v2: Vector2, v3: Vector2, color: Color)",
+            "Draw triangle outline (counter-clockwise vertices), each vertex may also be given as two numbers",
+        ),
+        (
+            "draw_triangle_fan",
+            "nil",
+            "(points: Points, color: Color)",
+            "Draw triangle fan, the first point is the center (at least 3 points)",
+        ),
+        (
+            "draw_triangle_strip",
+            "nil",
+            "(points: Points, color: Color)",
+            "Draw triangle strip (at least 3 points)",
+        ),
+        (
+            "draw_poly",
+            "nil",
+            "(center: Vector2, sides: integer, radius: number, rotation: number, color: Color)",
+            "Draw regular polygon, center may also be given as two numbers",
+        ),
+        (
+            "draw_poly_lines_ex",
+            "nil",
+            "(center: Vector2, sides: integer, radius: number, rotation: number, thick: number, color: Color)",
+            "Draw regular polygon outline with thickness, center may also be given as two numbers",
+        ),
+        (
+            "draw_line_strip",
+            "nil",
+            "(points: Points, color: Color)",
+            "Draw connected lines through points (at least 2 points)",
+        ),
+        (
+            "draw_line_bezier",
+            "nil",
+            "(start_pos: Vector2, end_pos: Vector2, thick: number, color: Color)",
+            "Draw line with cubic-bezier in-out easing, each end point may also be given as two numbers",
+        ),
+        (
+            "draw_spline_linear",
+            "nil",
+            "(points: Points, thick: number, color: Color)",
+            "Draw linear spline (at least 2 points)",
+        ),
+        (
+            "draw_spline_basis",
+            "nil",
+            "(points: Points, thick: number, color: Color)",
+            "Draw B-spline (at least 4 points)",
+        ),
+        (
+            "draw_spline_catmull_rom",
+            "nil",
+            "(points: Points, thick: number, color: Color)",
+            "Draw Catmull-Rom spline (at least 4 points)",
+        ),
+        (
+            "draw_spline_bezier_quadratic",
+            "nil",
+            "(points: Points, thick: number, color: Color)",
+            "Draw quadratic bezier spline: start, control, end, control, end... (at least 3 points)",
+        ),
+        (
+            "draw_spline_bezier_cubic",
+            "nil",
+            "(points: Points, thick: number, color: Color)",
+            "Draw cubic bezier spline: start, control, control, end... (at least 4 points)",
         ),
         // Input - Keyboard
         (
             "is_key_pressed",
             "boolean",
-            "(key: string)",
+            "(key: Key)",
             "Check if a key has been pressed once",
         ),
         (
             "is_key_down",
             "boolean",
-            "(key: string)",
+            "(key: Key)",
             "Check if a key is being pressed",
         ),
         (
             "is_key_released",
             "boolean",
-            "(key: string)",
+            "(key: Key)",
             "Check if a key has been released once",
         ),
         (
             "is_key_up",
             "boolean",
-            "(key: string)",
+            "(key: Key)",
             "Check if a key is NOT being pressed",
         ),
         // Input - Mouse
@@ -283,25 +1074,25 @@ fn generate_raylib_definitions() {
         (
             "is_mouse_button_pressed",
             "boolean",
-            "(button: string)",
+            "(button: MouseButtonName)",
             "Check if a mouse button has been pressed once (\"LEFT\"=left, \"RIGHT\"=right, \"MIDDLE\"=middle, \"EXTRA\"=extra, \"BACK\"=back, \"FORWARD\"=forward)",
         ),
         (
             "is_mouse_button_down",
             "boolean",
-            "(button: string)",
+            "(button: MouseButtonName)",
             "Check if a mouse button is being pressed",
         ),
         (
             "is_mouse_button_released",
             "boolean",
-            "(button: string)",
+            "(button: MouseButtonName)",
             "Check if a mouse button has been released once",
         ),
         (
             "is_mouse_button_up",
             "boolean",
-            "(button: string)",
+            "(button: MouseButtonName)",
             "Check if a mouse button is NOT being pressed",
         ),
         (
@@ -322,6 +1113,176 @@ fn generate_raylib_definitions() {
             "",
             "Get the height of the screen",
         ),
+        (
+            "set_window_title",
+            "nil",
+            "(title: string)",
+            "Set the window title",
+        ),
+        (
+            "set_window_size",
+            "nil",
+            "(width: integer, height: integer)",
+            "Set the window dimensions",
+        ),
+        (
+            "set_window_position",
+            "nil",
+            "(x: integer, y: integer)",
+            "Set the window position on screen",
+        ),
+        (
+            "set_window_min_size",
+            "nil",
+            "(width: integer, height: integer)",
+            "Set the minimum dimensions for a resizable window",
+        ),
+        (
+            "toggle_fullscreen",
+            "nil",
+            "()",
+            "Toggle between fullscreen and windowed mode",
+        ),
+        (
+            "toggle_borderless_windowed",
+            "nil",
+            "()",
+            "Toggle between borderless windowed and windowed mode",
+        ),
+        ("maximize", "nil", "()", "Maximize a resizable window"),
+        ("minimize", "nil", "()", "Minimize the window"),
+        (
+            "restore",
+            "nil",
+            "()",
+            "Restore a maximized or minimized window",
+        ),
+        (
+            "is_window_resized",
+            "boolean",
+            "()",
+            "Check if the window has been resized last frame",
+        ),
+        (
+            "is_window_focused",
+            "boolean",
+            "()",
+            "Check if the window is currently focused",
+        ),
+        (
+            "get_window_position",
+            "Vector2",
+            "()",
+            "Get the window position on screen",
+        ),
+        (
+            "get_window_scale_dpi",
+            "Vector2",
+            "()",
+            "Get the window scale DPI factor",
+        ),
+        (
+            "set_window_icon",
+            "nil",
+            "(image: Image)",
+            "Set the window icon from an image (R8G8B8A8 format)",
+        ),
+        (
+            "is_gamepad_available",
+            "boolean",
+            "(gamepad: integer)",
+            "Check if a gamepad is available",
+        ),
+        (
+            "get_gamepad_name",
+            "string|nil",
+            "(gamepad: integer)",
+            "Get the internal name of a gamepad",
+        ),
+        (
+            "is_gamepad_button_pressed",
+            "boolean",
+            "(gamepad: integer, button: GamepadButtonName)",
+            "Check if a gamepad button has been pressed once",
+        ),
+        (
+            "is_gamepad_button_down",
+            "boolean",
+            "(gamepad: integer, button: GamepadButtonName)",
+            "Check if a gamepad button is being pressed",
+        ),
+        (
+            "is_gamepad_button_released",
+            "boolean",
+            "(gamepad: integer, button: GamepadButtonName)",
+            "Check if a gamepad button has been released once",
+        ),
+        (
+            "is_gamepad_button_up",
+            "boolean",
+            "(gamepad: integer, button: GamepadButtonName)",
+            "Check if a gamepad button is not being pressed",
+        ),
+        (
+            "get_gamepad_axis_movement",
+            "number",
+            "(gamepad: integer, axis: GamepadAxisName)",
+            "Get the movement of a gamepad axis (-1 to 1)",
+        ),
+        (
+            "get_gamepad_axis_count",
+            "integer",
+            "(gamepad: integer)",
+            "Get the number of axes of a gamepad",
+        ),
+        (
+            "get_gamepad_button_pressed",
+            "string|nil",
+            "()",
+            "Get the name of the last gamepad button pressed",
+        ),
+        (
+            "set_gamepad_mappings",
+            "integer",
+            "(mappings: string)",
+            "Load SDL_GameControllerDB mappings",
+        ),
+        (
+            "set_gamepad_override",
+            "nil",
+            "(gamepad: integer, state: GamepadOverrideState)",
+            "Replace a gamepad's state with fake input; each call counts as a new frame",
+        ),
+        (
+            "clear_gamepad_override",
+            "nil",
+            "(gamepad?: integer)",
+            "Remove the fake input for a gamepad, or for all gamepads",
+        ),
+        (
+            "get_key_pressed",
+            "string|nil",
+            "()",
+            "Get the next key pressed this frame from the key queue, named as in rl.keys",
+        ),
+        (
+            "get_char_pressed",
+            "string|nil",
+            "()",
+            "Get the next character typed this frame as a UTF-8 string",
+        ),
+        (
+            "get_clipboard_text",
+            "string",
+            "()",
+            "Get the clipboard text",
+        ),
+        (
+            "set_clipboard_text",
+            "nil",
+            "(text: string)",
+            "Set the clipboard text",
+        ),
         (
             "get_mouse_x",
             "number",
@@ -391,6 +1352,85 @@ fn generate_raylib_definitions() {
             "Begin 2D mode",
         ),
         ("end_mode_2d", "nil", "", "End 2D mode"),
+        (
+            "with_mode_2d",
+            "nil",
+            "(camera: Camera2D, callback: fun(window: Window))",
+            "Run callback in 2D mode, ending it even if the callback errors",
+        ),
+        (
+            "get_world_to_screen_2d",
+            "Vector2",
+            "(pos: Vector2, camera: Camera2D)",
+            "Convert world coordinates to screen coordinates",
+        ),
+        (
+            "begin_mode_3d",
+            "nil",
+            "(camera: Camera3D)",
+            "Begin 3D mode",
+        ),
+        ("end_mode_3d", "nil", "", "End 3D mode"),
+        (
+            "with_mode_3d",
+            "nil",
+            "(camera: Camera3D, callback: fun(window: Window))",
+            "Run callback in 3D mode, ending it even if the callback errors",
+        ),
+        (
+            "update_camera",
+            "nil",
+            "(camera: Camera3D, mode: CameraMode)",
+            "Update camera position for the selected mode",
+        ),
+        (
+            "draw_cube",
+            "nil",
+            "(position: Vector3, width: number, height: number, length: number, color: Color)",
+            "Draw cube",
+        ),
+        (
+            "draw_cube_wires",
+            "nil",
+            "(position: Vector3, width: number, height: number, length: number, color: Color)",
+            "Draw cube wires",
+        ),
+        (
+            "draw_sphere",
+            "nil",
+            "(center: Vector3, radius: number, color: Color)",
+            "Draw sphere",
+        ),
+        (
+            "draw_cylinder",
+            "nil",
+            "(position: Vector3, radius_top: number, radius_bottom: number, height: number, slices: integer, color: Color)",
+            "Draw a cylinder/cone",
+        ),
+        (
+            "draw_plane",
+            "nil",
+            "(center: Vector3, size: Vector2, color: Color)",
+            "Draw a plane XZ",
+        ),
+        (
+            "draw_grid",
+            "nil",
+            "(slices: integer, spacing: number)",
+            "Draw a grid (centered at (0, 0, 0))",
+        ),
+        (
+            "draw_line_3d",
+            "nil",
+            "(start_pos: Vector3, end_pos: Vector3, color: Color)",
+            "Draw a line in 3D world space",
+        ),
+        (
+            "draw_ray",
+            "nil",
+            "(position: Vector3, direction: Vector3, color: Color)",
+            "Draw a ray line",
+        ),
         ("rl_push_matrix", "nil", "", "Push a matrix to the stack"),
         ("rl_pop_matrix", "nil", "", "Pop a matrix from the stack"),
         (
@@ -411,56 +1451,234 @@ fn generate_raylib_definitions() {
             "(width: number, height: number)",
             "Draw a grid",
         ),
+        // Text and fonts
         (
             "draw_text_ex",
             "nil",
-            "(font: Font, text: string, position: Vector2, font_size: number,  spacing: number, tint: Color)",
-            "Draw text using a custom font",
+            "(font: Font, text: string, position: Vector2, font_size: number, spacing: number, tint: Color)",
+            "Draw text using a custom font, position may also be given as two numbers",
+        ),
+        (
+            "draw_text_pro",
+            "nil",
+            "(font: Font, text: string, position: Vector2, origin: Vector2, rotation: number, font_size: number, spacing: number, tint: Color)",
+            "Draw text using a custom font, rotated around origin, Vector2 arguments may also be given as two numbers",
+        ),
+        (
+            "draw_text_codepoint",
+            "nil",
+            "(font: Font, codepoint: integer|string, position: Vector2, font_size: number, tint: Color)",
+            "Draw a single character, position may also be given as two numbers",
+        ),
+        (
+            "measure_text",
+            "integer",
+            "(text: string, font_size: integer)",
+            "Measure the width of text drawn with the default font",
+        ),
+        (
+            "measure_text_ex",
+            "Vector2",
+            "(font: Font, text: string, font_size: number, spacing: number)",
+            "Measure the size of text drawn with a font",
+        ),
+        (
+            "set_text_line_spacing",
+            "nil",
+            "(spacing: integer)",
+            "Set the vertical line spacing used when drawing text with line breaks",
+        ),
+        (
+            "get_font_default",
+            "Font",
+            "()",
+            "Get raylib's built-in default font",
+        ),
+        (
+            "draw_text_boxed",
+            "nil",
+            "(font: Font|nil, text: string, rect: Rectangle, font_size: number, spacing: number, color: Color, options?: TextLayoutOptions)",
+            "Draw text wrapped and aligned inside a rectangle (nil font uses the default font)",
+        ),
+        // Textures
+        (
+            "load_texture",
+            "Texture",
+            "(path: string)",
+            "Load texture from file into GPU memory",
+        ),
+        (
+            "load_texture_from_image",
+            "Texture",
+            "(image: Image)",
+            "Load texture from image data",
+        ),
+        (
+            "load_render_texture",
+            "RenderTexture",
+            "(width: integer, height: integer)",
+            "Load a render texture to draw into",
+        ),
+        (
+            "begin_texture_mode",
+            "nil",
+            "(target: RenderTexture)",
+            "Redirect drawing to a render texture, errors if texture mode or a camera mode is already active",
+        ),
+        ("end_texture_mode", "nil", "()", "End texture mode"),
+        (
+            "with_texture_mode",
+            "nil",
+            "(target: RenderTexture, callback: fun(window: Window))",
+            "Run callback drawing into a render texture, ending texture mode even if the callback errors",
+        ),
+        (
+            "load_shader",
+            "Shader",
+            "(vs_path: string|nil, fs_path: string|nil)",
+            "Load and compile a shader from files, nil uses the default stage; compile errors include the GLSL log",
+        ),
+        (
+            "load_shader_from_memory",
+            "Shader",
+            "(vs_code: string|nil, fs_code: string|nil)",
+            "Compile a shader from source code, nil uses the default stage; compile errors include the GLSL log",
+        ),
+        (
+            "begin_shader_mode",
+            "nil",
+            "(shader: Shader)",
+            "Draw with a custom shader, errors if shader mode is already active",
+        ),
+        ("end_shader_mode", "nil", "()", "End shader mode"),
+        (
+            "with_shader_mode",
+            "nil",
+            "(shader: Shader, callback: fun(window: Window))",
+            "Run callback drawing with a shader, ending shader mode even if the callback errors",
+        ),
+        (
+            "begin_blend_mode",
+            "nil",
+            "(mode: BlendModeName)",
+            "Begin blending with a mode; nested calls stack",
+        ),
+        (
+            "end_blend_mode",
+            "nil",
+            "()",
+            "End the innermost blend mode, restoring the one it replaced",
+        ),
+        (
+            "with_blend_mode",
+            "nil",
+            "(mode: BlendModeName, callback: fun(window: Window))",
+            "Run callback with a blend mode, restoring the previous mode even if the callback errors",
+        ),
+        (
+            "set_blend_factors",
+            "nil",
+            "(src_factor: integer, dst_factor: integer, equation: integer)",
+            "Set the OpenGL blend factors and equation used by the \"custom\" blend mode",
+        ),
+        (
+            "begin_scissor_mode",
+            "nil",
+            "(x: integer|Rectangle, y?: integer, width?: integer, height?: integer)",
+            "Clip drawing to an area given as x, y, width, height or a rectangle; nested areas are clipped to their parent",
+        ),
+        (
+            "end_scissor_mode",
+            "nil",
+            "()",
+            "End the innermost scissor mode, restoring the area it replaced",
+        ),
+        (
+            "with_scissor_mode",
+            "nil",
+            "(x: integer|Rectangle, ...: integer|fun(window: Window))",
+            "Run callback clipped to an area, given like begin_scissor_mode followed by the callback",
+        ),
+        (
+            "draw_texture",
+            "nil",
+            "(texture: Texture, x: number, y: number, tint: Color)",
+            "Draw a texture, x and y may also be given as one Vector2",
+        ),
+        (
+            "draw_texture_v",
+            "nil",
+            "(texture: Texture, position: Vector2, tint: Color)",
+            "Draw a texture with position defined as Vector2",
+        ),
+        (
+            "draw_texture_ex",
+            "nil",
+            "(texture: Texture, position: Vector2, rotation: number, scale: number, tint: Color)",
+            "Draw a texture with extended parameters, position may also be given as two numbers",
+        ),
+        (
+            "draw_texture_rec",
+            "nil",
+            "(texture: Texture, source: Rectangle, position: Vector2, tint: Color)",
+            "Draw a part of a texture defined by a rectangle, Vector2 arguments may also be given as two numbers and Rectangle arguments as four numbers",
+        ),
+        (
+            "draw_texture_pro",
+            "nil",
+            "(texture: Texture, source: Rectangle, dest: Rectangle, origin: Vector2, rotation: number, tint: Color)",
+            "Draw a part of a texture defined by a rectangle with 'pro' parameters, Vector2 arguments may also be given as two numbers and Rectangle arguments as four numbers",
         ),
-        ("get_font_default", "Font", "", ""),
     ];
 
     let _other_functions: Vec<(&str, &str, &str, &'static str)> = vec![];
 
-    for (name, ret_type, params, desc) in window_methods {
-        output.push_str(&format!("---{}\n", desc));
-        output.push_str(&format!("---@param self Window\n"));
-
-        let mut new_params = "(".to_string();
-
-        // Parse parameters for proper annotations
-        if !params.is_empty() && params != "()" {
-            let param_str = params.trim_matches(|c| c == '(' || c == ')');
-            for param in param_str.split(", ") {
-                if let Some((name, typ)) = param.split_once(": ") {
-                    // insert comma if needed
-                    if !(new_params == "(".to_string()) {
-                        new_params.push(',');
-                        new_params.push_str(" ");
-                    }
-                    new_params.push_str(name);
-                    output.push_str(&format!("---@param {} {}\n", name.trim(), typ));
-                }
-            }
-        }
+    push_methods(&mut output, "Window", &window_methods);
 
-        new_params.push_str(")");
+    output.push_str(
+        "---Drawing surface of one frame, passed to the draw_frame callback. Offers the\n",
+    );
+    output.push_str("---window's draw_*, clear_background and mode methods, and errors once its frame has ended\n");
+    output.push_str("---@class DrawContext: Window\n");
+    output.push_str("local DrawContext = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "DrawContext",
+        &[(
+            "is_valid",
+            "boolean",
+            "()",
+            "Check if the frame this context belongs to is still in progress",
+        )],
+    );
 
-        output.push_str(&format!("---@return {}\n", ret_type));
-        output.push_str(&format!(
-            "function Window:{}{} end\n\n",
-            name,
-            new_params.trim()
-        ));
+    // Module functions
+    output.push_str("---Configuration flags for init_window, all default to false\n");
+    output.push_str("---@class WindowOptions\n");
+    for (flag, desc) in [
+        ("resizable", "Allow the window to be resized"),
+        ("vsync", "Wait for vertical sync"),
+        ("msaa_4x", "Enable 4x multisample anti-aliasing"),
+        ("fullscreen", "Start in fullscreen mode"),
+        ("undecorated", "Hide the window decorations"),
+        ("highdpi", "Support high DPI displays"),
+        ("transparent", "Allow a transparent framebuffer"),
+        ("hidden", "Start with the window hidden"),
+        ("always_run", "Keep running while minimized"),
+        ("strict", "Turn strict mode on, see raylib.set_strict"),
+    ] {
+        output.push_str(&format!("---@field {}? boolean {}\n", flag, desc));
     }
+    output.push('\n');
 
-    // Module functions
     output.push_str("---Initialize window and OpenGL context\n");
     output.push_str("---@param width integer Window width\n");
     output.push_str("---@param height integer Window height\n");
     output.push_str("---@param title string Window title\n");
+    output.push_str("---@param options? WindowOptions Window configuration flags\n");
     output.push_str("---@return Window\n");
-    output.push_str("function raylib.init_window(width, height, title) end\n\n");
+    output.push_str("function raylib.init_window(width, height, title, options) end\n\n");
 
     output.push_str("---Create a color from RGBA values\n");
     output.push_str("---@param r integer Red (0-255)\n");
@@ -475,6 +1693,136 @@ fn generate_raylib_definitions() {
     output.push_str("---@param callback fun(window: Window) Drawing callback function\n");
     output.push_str("function raylib.draw(window, callback) end\n\n");
 
+    output.push_str("---Callbacks and timing for raylib.run, every field is optional\n");
+    output.push_str("---@class RunOptions\n");
+    output.push_str("---@field load? fun(window: Window) Called once before the first frame, and again on restart\n");
+    output.push_str(
+        "---@field update? fun(dt: number) Called every frame with the frame time in seconds\n",
+    );
+    output.push_str("---@field fixed_update? fun(step: number) Called tick_rate times per second with the fixed step in seconds\n");
+    output.push_str("---@field draw? fun(ctx: DrawContext, alpha: number) Called inside the frame; alpha is how far (0-1) time is between the last and the next fixed step\n");
+    output.push_str("---@field quit? fun() Called once the loop ends\n");
+    output.push_str("---@field on_error? fun(err: string, phase: RunPhase): \"continue\"|\"restart\"|nil Handle a callback error; return \"continue\" to keep running, \"restart\" to call load again or nil to stop. Without a handler the crash screen is shown\n");
+    output.push_str("---@field crash_screen? boolean Show errors without an on_error handler on a crash screen with the traceback, offering restart, copy and quit keys; false raises them instead (default true)\n");
+    output.push_str("---@field tick_rate? number Fixed steps per second (default 60)\n");
+    output.push_str("---@field max_steps? integer Most fixed steps run in one frame, extra time is dropped (default 5)\n");
+    output.push_str("---@field hot_reload? boolean Re-execute Lua files in the script's directory when they change, keeping the window open (default false)\n");
+    output.push_str("---@field on_reload? fun(old_state: any): any Called instead of load after the main script is reloaded, with the state of the previous run; return a value to replace the state\n");
+    output.push_str("---@field state? any Game state handed to on_reload after a reload\n\n");
+    output
+        .push_str("---@alias RunPhase \"load\"|\"update\"|\"fixed_update\"|\"draw\"|\"quit\"\n\n");
+
+    push_functions(
+        &mut output,
+        "raylib",
+        &[(
+            "run",
+            "nil",
+            "(window: Window, callbacks: RunOptions)",
+            "Run the game loop until the window closes, with fixed timestep updates and interpolated drawing",
+        )],
+    );
+
+    push_functions(
+        &mut output,
+        "raylib",
+        &[
+            (
+                "set_strict",
+                "nil",
+                "(strict: boolean)",
+                "Raise errors for drawing outside a frame, unbalanced begin_drawing/end_drawing and modes left active when the frame ends",
+            ),
+            ("is_strict", "boolean", "()", "Check if strict mode is on"),
+        ],
+    );
+
+    push_functions(
+        &mut output,
+        "raylib",
+        &[
+            (
+                "load_font",
+                "Font",
+                "(path: string, size?: integer, codepoints?: Codepoints)",
+                "Load a font into GPU memory (size defaults to 32, codepoints to ASCII); needs an open window",
+            ),
+            (
+                "layout_text",
+                "TextLayout",
+                "(font: Font|nil, text: string, rect: Rectangle, font_size: number, spacing: number, options?: TextLayoutOptions)",
+                "Lay out text like draw_text_boxed without drawing it, for measuring and hit-testing",
+            ),
+            (
+                "load_font_from_memory",
+                "Font",
+                "(file_type: string, data: string, size?: integer, codepoints?: Codepoints)",
+                "Load a font from file data, file_type is the extension such as \".ttf\"; needs an open window",
+            ),
+        ],
+    );
+
+    push_functions(
+        &mut output,
+        "raylib",
+        &[
+            (
+                "load_image",
+                "Image",
+                "(path: string)",
+                "Load image from file into CPU memory",
+            ),
+            (
+                "gen_image_color",
+                "Image",
+                "(width: integer, height: integer, color: Color)",
+                "Generate image: plain color",
+            ),
+            (
+                "gen_image_checked",
+                "Image",
+                "(width: integer, height: integer, checks_x: integer, checks_y: integer, col1: Color, col2: Color)",
+                "Generate image: checked",
+            ),
+            (
+                "gen_image_gradient_linear",
+                "Image",
+                "(width: integer, height: integer, direction: integer, start: Color, end: Color)",
+                "Generate image: linear gradient, direction in degrees [0..360]",
+            ),
+            (
+                "gen_image_gradient_radial",
+                "Image",
+                "(width: integer, height: integer, density: number, inner: Color, outer: Color)",
+                "Generate image: radial gradient",
+            ),
+            (
+                "gen_image_white_noise",
+                "Image",
+                "(width: integer, height: integer, factor: number)",
+                "Generate image: white noise",
+            ),
+            (
+                "gen_image_perlin_noise",
+                "Image",
+                "(width: integer, height: integer, offset_x: integer, offset_y: integer, scale: number)",
+                "Generate image: perlin noise",
+            ),
+            (
+                "gen_image_cellular",
+                "Image",
+                "(width: integer, height: integer, tile_size: integer)",
+                "Generate image: cellular algorithm, bigger tile_size means bigger cells",
+            ),
+            (
+                "init_audio_device",
+                "AudioDevice",
+                "()",
+                "Initialize audio device and context",
+            ),
+        ],
+    );
+
     output.push_str("---Get a random value\n");
     output.push_str("---@return number\n");
     output.push_str("function raylib.get_random_value(min, max) end\n\n");
@@ -505,12 +1853,58 @@ fn generate_raylib_definitions() {
     output.push_str("---@field BEIGE Color Beige (211, 176, 131, 255)\n");
     output.push_str("raylib.colors = {}\n\n");
 
-    // Key constants documentation
-    output.push_str("---Key string constants for keyboard input\n");
+    // Key and mouse button constants, read from the tables in src/input.rs
+    let input_src = fs::read_to_string("src/input.rs").expect("failed to read src/input.rs");
+    println!("cargo:rerun-if-changed=src/input.rs");
+
+    output.push_str(
+        "---Key name such as \"SPACE\" or \"KEY_SPACE\" (case-insensitive), or a raylib key code\n",
+    );
+    output.push_str("---@alias Key string|integer\n\n");
+    output.push_str(
+        "---Mouse button name such as \"LEFT\" or \"MOUSE_BUTTON_LEFT\", or a raylib button code\n",
+    );
+    output.push_str("---@alias MouseButtonName string|integer\n\n");
+
+    output.push_str("---Raylib key codes by name\n");
+    output.push_str("---@class Keys\n");
+    for name in const_table_names(&input_src, "pub const KEYS") {
+        output.push_str(&format!("---@field {} integer\n", name));
+    }
+    output.push_str("raylib.keys = {}\n\n");
+
+    output.push_str("---Raylib mouse button codes by name\n");
+    output.push_str("---@class MouseButtons\n");
+    for name in const_table_names(&input_src, "pub const MOUSE_BUTTONS") {
+        output.push_str(&format!("---@field {} integer\n", name));
+    }
+    output.push_str("raylib.mouse_buttons = {}\n\n");
+
     output
-        .push_str("---Supported keys: \"SPACE\", \"ESCAPE\", \"ENTER\", \"TAB\", \"BACKSPACE\",\n");
-    output.push_str("---\"UP\", \"DOWN\", \"LEFT\", \"RIGHT\", \"A\"-\"Z\", \"0\"-\"9\",\n");
-    output.push_str("---\"F1\"-\"F12\", \"SHIFT\", \"CTRL\", \"ALT\"\n\n");
+        .push_str("---Gamepad button name such as \"RIGHT_FACE_DOWN\", or a raylib button code\n");
+    output.push_str("---@alias GamepadButtonName string|integer\n\n");
+    output.push_str("---Gamepad axis name such as \"LEFT_X\", or a raylib axis code\n");
+    output.push_str("---@alias GamepadAxisName string|integer\n\n");
+
+    output.push_str("---Raylib gamepad button codes by name\n");
+    output.push_str("---@class GamepadButtons\n");
+    for name in const_table_names(&input_src, "pub const GAMEPAD_BUTTONS") {
+        output.push_str(&format!("---@field {} integer\n", name));
+    }
+    output.push_str("raylib.gamepad_buttons = {}\n\n");
+
+    output.push_str("---Raylib gamepad axis codes by name\n");
+    output.push_str("---@class GamepadAxes\n");
+    for name in const_table_names(&input_src, "pub const GAMEPAD_AXES") {
+        output.push_str(&format!("---@field {} integer\n", name));
+    }
+    output.push_str("raylib.gamepad_axes = {}\n\n");
+
+    output.push_str("---Fake gamepad state for window:set_gamepad_override\n");
+    output.push_str("---@class GamepadOverrideState\n");
+    output.push_str("---@field name? string Name reported by get_gamepad_name\n");
+    output.push_str("---@field buttons? table<GamepadButtonName, boolean> Buttons held down\n");
+    output.push_str("---@field axes? table<GamepadAxisName, number> Axis values (-1 to 1)\n\n");
 
     output.push_str("return raylib\n");
 
@@ -607,7 +2001,7 @@ fn generate_rlmlua_definitions() {
     output.push_str("---@return Rectangle\n");
     output.push_str("function rlmlua.rect(x, y, width, height) end\n\n");
 
-    output.push_str("---Create a 2D camera");
+    output.push_str("---Create a 2D camera\n");
     output.push_str("---@param offset Vector2|nil Camera offset\n");
     output.push_str("---@param target Vector2|nil Camera target\n");
     output.push_str("---@param rotation number|nil Camera rotation\n");
@@ -615,6 +2009,15 @@ fn generate_rlmlua_definitions() {
     output.push_str("---@return Camera2D\n");
     output.push_str("function rlmlua.camera2d(offset, target, rotation, zoom) end\n\n");
 
+    output.push_str("---Create a 3D camera\n");
+    output.push_str("---@param position Vector3 Camera position\n");
+    output.push_str("---@param target Vector3 Camera target\n");
+    output.push_str("---@param up Vector3|nil Camera up vector, defaults to +Y\n");
+    output.push_str("---@param fovy number|nil Field of view in degrees, defaults to 45\n");
+    output.push_str("---@param projection CameraProjection|nil Defaults to \"perspective\"\n");
+    output.push_str("---@return Camera3D\n");
+    output.push_str("function rlmlua.camera3d(position, target, up, fovy, projection) end\n\n");
+
     output.push_str("---Check if point is inside rectangle\n");
     output.push_str("---@param rect Rectangle\n");
     output.push_str("---@param x number Point X coordinate\n");
@@ -721,33 +2124,207 @@ fn generate_rlmlua_definitions() {
     output.push_str("---@class Ease\n");
     output.push_str("rlmlua.ease = {}\n\n");
 
-    let easing_functions = vec![
-        "linear",
-        "in_quad",
-        "out_quad",
-        "in_out_quad",
-        "in_cubic",
-        "out_cubic",
-        "in_out_cubic",
-        "in_elastic",
-        "out_elastic",
-        "in_bounce",
-        "out_bounce",
-    ];
+    let mut easing_functions = vec!["linear".to_string()];
+    for family in [
+        "sine", "quad", "cubic", "quart", "quint", "expo", "circ", "back", "elastic", "bounce",
+    ] {
+        for variant in ["in", "out", "in_out"] {
+            easing_functions.push(format!("{}_{}", variant, family));
+        }
+    }
 
     for ease_fn in easing_functions {
-        output.push_str(&format!("---@param t number Input (0-1)\n"));
-        output.push_str(&format!("---@return number Output (0-1)\n"));
+        output.push_str("---@param t number Input (0-1)\n");
+        output.push_str("---@return number Output (0-1, back and elastic overshoot)\n");
         output.push_str(&format!("function rlmlua.ease.{}(t) end\n\n", ease_fn));
     }
 
     // Timer
+    output.push_str("---Countdown timer advanced with update(dt)\n");
+    output.push_str("---@class Timer\n");
+    output.push_str("---@field duration number Timer duration in seconds\n");
+    output.push_str("---@field elapsed number Time elapsed so far in seconds\n");
+    output.push_str("local Timer = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "Timer",
+        &[
+            (
+                "update",
+                "boolean",
+                "(dt: number)",
+                "Advance the timer, returns true once it has finished",
+            ),
+            (
+                "progress",
+                "number",
+                "()",
+                "Fraction of the duration elapsed (0-1)",
+            ),
+            (
+                "is_done",
+                "boolean",
+                "()",
+                "Check if the timer has finished",
+            ),
+            ("reset", "nil", "()", "Restart the timer from zero"),
+        ],
+    );
+
     output.push_str("---Create a simple timer\n");
     output.push_str("---@param duration number Timer duration in seconds\n");
+    output.push_str("---@return Timer\n");
+    output.push_str("function rlmlua.timer(duration) end\n\n");
+
+    // Tween
     output.push_str(
-        "---@return table timer Timer object with update(), progress(), reset() methods\n",
+        "---Easing name from rlmlua.ease or a function mapping progress to eased progress\n",
     );
-    output.push_str("function rlmlua.timer(duration) end\n\n");
+    output.push_str("---@alias EasingName string|fun(t: number): number\n\n");
+    output.push_str("---Animates fields of a table towards goal values\n");
+    output.push_str("---@class Tween\n");
+    output.push_str("---@field target table Table whose fields are animated\n");
+    output.push_str("---@field duration number Length of one run in seconds\n");
+    output.push_str("---@field elapsed number Time elapsed in the current run\n");
+    output.push_str("---@field yoyo boolean Play every repeat in the opposite direction\n");
+    output.push_str("---@field repeats integer Extra runs after the first, -1 repeats forever\n");
+    output.push_str(
+        "---@field on_complete fun(target: table, tween: Tween)|nil Called once when the tween finishes\n",
+    );
+    output.push_str("local Tween = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "Tween",
+        &[
+            (
+                "update",
+                "boolean",
+                "(dt: number)",
+                "Advance the tween and write the new values, returns true on the update it finishes",
+            ),
+            (
+                "is_done",
+                "boolean",
+                "()",
+                "Check if the tween has finished",
+            ),
+            (
+                "progress",
+                "number",
+                "()",
+                "Fraction of the current run elapsed (0-1), before easing",
+            ),
+            ("reset", "nil", "()", "Restart from the start values"),
+        ],
+    );
+
+    output.push_str(
+        "---Tween number, Vector2, Vector3 ({x, y} and {x, y, z} tables too) and color fields of a table towards goal values\n",
+    );
+    output.push_str("---@param target table Table holding the fields to animate\n");
+    output.push_str("---@param goals table Goal value per field name\n");
+    output.push_str("---@param duration number Length of one run in seconds\n");
+    output.push_str("---@param easing? EasingName Easing curve (default \"linear\")\n");
+    output.push_str(
+        "---@param options? {yoyo?: boolean, repeats?: integer, on_complete?: fun(target: table, tween: Tween)}\n",
+    );
+    output.push_str("---@return Tween\n");
+    output.push_str("function rlmlua.tween(target, goals, duration, easing, options) end\n\n");
+
+    // Text input
+    output
+        .push_str("---Single-line text field; cursor and selection are character offsets from 0\n");
+    output.push_str("---@class TextInput\n");
+    output.push_str("---@field text string Current text\n");
+    output.push_str("---@field cursor integer Number of characters before the caret\n");
+    output.push_str("---@field length integer Number of characters in the text\n");
+    output.push_str("---@field max_length integer|nil Maximum number of characters\n");
+    output.push_str("local TextInput = {}\n\n");
+
+    push_methods(
+        &mut output,
+        "TextInput",
+        &[
+            (
+                "update",
+                "boolean",
+                "()",
+                "Read typed characters and editing keys for this frame, returns true if the text changed",
+            ),
+            (
+                "insert",
+                "boolean",
+                "(text: string)",
+                "Insert text at the caret, replacing the selection",
+            ),
+            (
+                "backspace",
+                "boolean",
+                "()",
+                "Delete the selection or the character before the caret",
+            ),
+            (
+                "delete",
+                "boolean",
+                "()",
+                "Delete the selection or the character after the caret",
+            ),
+            (
+                "move_left",
+                "nil",
+                "(select?: boolean)",
+                "Move the caret left, extending the selection if select is true",
+            ),
+            (
+                "move_right",
+                "nil",
+                "(select?: boolean)",
+                "Move the caret right, extending the selection if select is true",
+            ),
+            (
+                "move_home",
+                "nil",
+                "(select?: boolean)",
+                "Move the caret to the start",
+            ),
+            (
+                "move_end",
+                "nil",
+                "(select?: boolean)",
+                "Move the caret to the end",
+            ),
+            ("select_all", "nil", "()", "Select the whole text"),
+            (
+                "get_selection",
+                "integer|nil, integer|nil",
+                "()",
+                "Start and end offsets of the selection, or nil",
+            ),
+            ("selected_text", "string", "()", "Get the selected text"),
+            ("copy", "nil", "()", "Copy the selection to the clipboard"),
+            (
+                "cut",
+                "boolean",
+                "()",
+                "Move the selection to the clipboard",
+            ),
+            (
+                "paste",
+                "boolean",
+                "()",
+                "Insert the clipboard text at the caret",
+            ),
+            ("clear", "nil", "()", "Remove all text"),
+        ],
+    );
+
+    output.push_str("---Create a text field that edits text from keyboard input\n");
+    output.push_str("---@param text? string Initial text\n");
+    output.push_str("---@param max_length? integer Maximum number of characters\n");
+    output.push_str("---@return TextInput\n");
+    output.push_str("function rlmlua.text_input(text, max_length) end\n\n");
 
     // Version info
     output.push_str("---Version string\n");
@@ -762,4 +2339,75 @@ fn generate_rlmlua_definitions() {
     fs::create_dir_all("lua/rlmlua").unwrap();
     let mut file = fs::File::create("lua/rlmlua/meta.lua").unwrap();
     file.write_all(output.as_bytes()).unwrap();
+
+    check_rlmlua_exports(&output);
+}
+
+/// Names the Lua wrapper in `lua/rlmlua/init.lua` sets instead of `rlm_lua`.
+const RLMLUA_LUA_ONLY: &[&str] = &["raylib"];
+
+/// Fail the build when `meta.lua` declares a helper that `rlm_lua` does not
+/// export, so the editor never suggests a function that is nil at runtime.
+fn check_rlmlua_exports(meta: &str) {
+    println!("cargo:rerun-if-changed=src/rlm.rs");
+    println!("cargo:rerun-if-changed=src/easing.rs");
+
+    let rlm_src = fs::read_to_string("src/rlm.rs").unwrap();
+    let easing_src = fs::read_to_string("src/easing.rs").unwrap();
+
+    let mut exported: Vec<String> = quoted_after(&rlm_src, "exports.set(");
+    exported.extend(
+        const_table_names(&easing_src, "pub const EASINGS")
+            .into_iter()
+            .map(|name| format!("ease.{}", name)),
+    );
+    exported.extend(RLMLUA_LUA_ONLY.iter().map(|name| name.to_string()));
+
+    let mut missing = Vec::new();
+    for line in meta.lines() {
+        let declared = if let Some(rest) = line.strip_prefix("function rlmlua.") {
+            rest.split('(').next()
+        } else if let Some(rest) = line.strip_prefix("rlmlua.") {
+            rest.split(" =").next()
+        } else {
+            None
+        };
+        if let Some(name) = declared
+            && !exported.iter().any(|e| e == name)
+        {
+            missing.push(name.to_string());
+        }
+    }
+
+    if !missing.is_empty() {
+        panic!(
+            "lua/rlmlua/meta.lua declares names that rlm_lua does not export: {}",
+            missing.join(", ")
+        );
+    }
+}
+
+/// Names of a `&[("NAME", value), ...]` constant table declared in `src`.
+fn const_table_names(src: &str, declaration: &str) -> Vec<String> {
+    let start = src
+        .find(declaration)
+        .unwrap_or_else(|| panic!("`{}` not found", declaration));
+    let body = &src[start..];
+    let end = body.find("\n];").expect("unterminated constant table");
+    quoted_after(&body[..end], "(\"")
+}
+
+/// Collect the string literal that directly follows each occurrence of
+/// `prefix`, e.g. the key of every `exports.set("key", ...)` call.
+fn quoted_after(src: &str, prefix: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = src;
+    while let Some(start) = rest.find(prefix) {
+        rest = &rest[start + prefix.len()..];
+        let trimmed = rest.trim_start().trim_start_matches('"');
+        if let Some(end) = trimmed.find('"') {
+            names.push(trimmed[..end].to_string());
+        }
+    }
+    names
 }
@@ -12,8 +12,7 @@ local screen_height = 450
 local window = rl.init_window(screen_width, screen_height, "rlmlua example - 2d camera mouse zoom")
 
 ---@type Camera2D
-local camera = rlm.camera2d(0, rlm.vec2(0, 0), 0, 0)
-camera.zoom = 1.0
+local camera = rlm.camera2d(rlm.vec2(0, 0), rlm.vec2(0, 0), 0, 1.0)
 
 local zoom_mode = 0
 
@@ -0,0 +1,96 @@
+local rl = require("raylib")
+local rlm = require("rlmlua")
+local rlc = rl.colors
+
+local PLAYER_SIZE = 40
+
+local screen_width = 800
+local screen_height = 440
+
+local window = rl.init_window(screen_width, screen_height, "rlmlua example - 2d camera split screen")
+
+local player1 = { x = 200, y = 200, width = PLAYER_SIZE, height = PLAYER_SIZE }
+local player2 = { x = 250, y = 200, width = PLAYER_SIZE, height = PLAYER_SIZE }
+
+local camera1 = rlm.camera2d(rlm.vec2(200, 200), rlm.vec2(player1.x, player1.y), 0.0, 1.0)
+local camera2 = rlm.camera2d(rlm.vec2(200, 200), rlm.vec2(player2.x, player2.y), 0.0, 1.0)
+
+local screen_camera1 = window:load_render_texture(screen_width // 2, screen_height)
+local screen_camera2 = window:load_render_texture(screen_width // 2, screen_height)
+
+-- Render textures are stored upside down, so flip the source rectangle
+local split_screen_rect = rlm.rect(0, 0, screen_camera1.width, -screen_camera1.height)
+
+window:set_target_fps(60)
+
+-- Grid and players, drawn once through each camera
+local function draw_world(win)
+    for i = 0, screen_width // PLAYER_SIZE do
+        win:draw_line(PLAYER_SIZE * i, 0, PLAYER_SIZE * i, screen_height, rlc.LIGHTGRAY)
+    end
+    for i = 0, screen_height // PLAYER_SIZE do
+        win:draw_line(0, PLAYER_SIZE * i, screen_width, PLAYER_SIZE * i, rlc.LIGHTGRAY)
+    end
+
+    for i = 0, screen_width // PLAYER_SIZE - 1 do
+        for j = 0, screen_height // PLAYER_SIZE - 1 do
+            win:draw_text(string.format("[%d,%d]", i, j), 10 + PLAYER_SIZE * i, 15 + PLAYER_SIZE * j, 10, rlc.LIGHTGRAY)
+        end
+    end
+
+    win:draw_rectangle_rec(player1, rlc.RED)
+    win:draw_rectangle_rec(player2, rlc.BLUE)
+end
+
+while not window:should_close() do
+    if window:is_key_down("S") then
+        player1.y = player1.y + 3
+    elseif window:is_key_down("W") then
+        player1.y = player1.y - 3
+    end
+    if window:is_key_down("D") then
+        player1.x = player1.x + 3
+    elseif window:is_key_down("A") then
+        player1.x = player1.x - 3
+    end
+
+    if window:is_key_down("UP") then
+        player2.y = player2.y - 3
+    elseif window:is_key_down("DOWN") then
+        player2.y = player2.y + 3
+    end
+    if window:is_key_down("RIGHT") then
+        player2.x = player2.x + 3
+    elseif window:is_key_down("LEFT") then
+        player2.x = player2.x - 3
+    end
+
+    camera1.target = rlm.vec2(player1.x, player1.y)
+    camera2.target = rlm.vec2(player2.x, player2.y)
+
+    window:begin_drawing()
+
+    window:with_texture_mode(screen_camera1, function(win)
+        win:clear_background(rlc.RAYWHITE)
+        win:with_mode_2d(camera1, draw_world)
+        win:draw_rectangle(0, 0, screen_width // 2, 30, rl.color(245, 245, 245, 128))
+        win:draw_text("PLAYER1: W/S/A/D to move", 10, 10, 10, rlc.MAROON)
+    end)
+
+    window:with_texture_mode(screen_camera2, function(win)
+        win:clear_background(rlc.RAYWHITE)
+        win:with_mode_2d(camera2, draw_world)
+        win:draw_rectangle(0, 0, screen_width // 2, 30, rl.color(245, 245, 245, 128))
+        win:draw_text("PLAYER2: UP/DOWN/LEFT/RIGHT to move", 10, 10, 10, rlc.DARKBLUE)
+    end)
+
+    window:clear_background(rlc.BLACK)
+    window:draw_texture_rec(screen_camera1.texture, split_screen_rect, rlm.vec2(0, 0), rlc.WHITE)
+    window:draw_texture_rec(screen_camera2.texture, split_screen_rect, rlm.vec2(screen_width / 2.0, 0), rlc.WHITE)
+    window:draw_rectangle(screen_width // 2 - 2, 0, 4, screen_height, rlc.LIGHTGRAY)
+
+    window:end_drawing()
+end
+
+screen_camera1:unload()
+screen_camera2:unload()
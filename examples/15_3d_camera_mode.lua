@@ -0,0 +1,40 @@
+local rl = require("raylib")
+local rlm = require("rlmlua")
+local rlc = rl.colors
+
+local screen_width = 800
+local screen_height = 450
+
+local window = rl.init_window(screen_width, screen_height, "rlmlua example - 3d camera mode")
+
+local camera = rlm.camera3d(
+    rlm.vec3(0.0, 10.0, 10.0), -- position
+    rlm.vec3(0.0, 0.0, 0.0),   -- target
+    rlm.vec3(0.0, 1.0, 0.0),   -- up
+    45.0,                      -- fovy
+    "perspective"              -- projection
+)
+
+local cube_position = { x = 0.0, y = 0.0, z = 0.0 }
+
+window:set_target_fps(60)
+
+while not window:should_close() do
+    window:update_camera(camera, "orbital")
+
+    window:begin_drawing()
+
+    window:clear_background(rlc.RAYWHITE)
+
+    window:with_mode_3d(camera, function(w)
+        w:draw_cube(cube_position, 2.0, 2.0, 2.0, rlc.RED)
+        w:draw_cube_wires(cube_position, 2.0, 2.0, 2.0, rlc.MAROON)
+        w:draw_sphere({ x = 3.0, y = 1.0, z = 0.0 }, 0.5, rlc.BLUE)
+        w:draw_line_3d(rlm.vec3(0, 0, 0), rlm.vec3(0, 3, 0), rlc.GREEN)
+        w:draw_grid(10, 1.0)
+    end)
+
+    window:draw_text("Welcome to the third dimension!", 10, 40, 20, rlc.DARKGRAY)
+
+    window:end_drawing()
+end
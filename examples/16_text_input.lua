@@ -0,0 +1,42 @@
+local rl = require("raylib")
+local rlm = require("rlmlua")
+local rlc = rl.colors
+
+local screen_width = 800
+local screen_height = 450
+
+local window = rl.init_window(screen_width, screen_height, "rlmlua example - text input")
+
+local name = rlm.text_input("", 16)
+local frames = 0
+
+window:set_target_fps(60)
+
+while not window:should_close() do
+    name:update()
+    frames = frames + 1
+
+    -- Show the caret by splitting the text at the cursor (offsets count characters)
+    local text = name.text
+    local split = utf8.offset(text, name.cursor + 1) or (#text + 1)
+    local caret = (frames // 30) % 2 == 0 and "|" or " "
+    local shown = text:sub(1, split - 1) .. caret .. text:sub(split)
+
+    window:begin_drawing()
+
+    window:clear_background(rlc.RAYWHITE)
+
+    window:draw_text("Enter your name:", 240, 140, 20, rlc.GRAY)
+    window:draw_rectangle(240, 180, 320, 50, rlc.LIGHTGRAY)
+    window:draw_rectangle_lines(240, 180, 320, 50, rlc.RED)
+    window:draw_text(shown, 250, 192, 30, rlc.MAROON)
+
+    window:draw_text(string.format("Characters: %d/%d", name.length, name.max_length), 240, 250, 20, rlc.DARKGRAY)
+    local selected = name:selected_text()
+    if selected ~= "" then
+        window:draw_text("Selected: " .. selected, 240, 280, 20, rlc.DARKGRAY)
+    end
+    window:draw_text("Shift+arrows select, Ctrl+A/C/X/V edit", 240, 320, 20, rlc.GRAY)
+
+    window:end_drawing()
+end
@@ -0,0 +1,64 @@
+local rl = require("raylib")
+local rlc = rl.colors
+
+local screen_width = 800
+local screen_height = 450
+
+local window = rl.init_window(screen_width, screen_height, "rlmlua example - fixed timestep")
+window:set_target_fps(144)
+
+local GRAVITY = 900
+local BOUNCE = 0.8
+
+local ball = {}
+
+rl.run(window, {
+    -- Simulation runs at 30 ticks per second regardless of the frame rate
+    tick_rate = 30,
+
+    load = function()
+        ball = { x = 100, y = 50, prev_x = 100, prev_y = 50, vx = 180, vy = 0, radius = 20 }
+    end,
+
+    update = function()
+        if window:is_key_pressed("R") then
+            ball.x, ball.y, ball.vy = 100, 50, 0
+        end
+    end,
+
+    fixed_update = function(step)
+        ball.prev_x, ball.prev_y = ball.x, ball.y
+
+        ball.vy = ball.vy + GRAVITY * step
+        ball.x = ball.x + ball.vx * step
+        ball.y = ball.y + ball.vy * step
+
+        if ball.y + ball.radius > screen_height then
+            ball.y = screen_height - ball.radius
+            ball.vy = -ball.vy * BOUNCE
+        end
+        if ball.x - ball.radius < 0 or ball.x + ball.radius > screen_width then
+            ball.vx = -ball.vx
+        end
+    end,
+
+    draw = function(ctx, alpha)
+        ctx:clear_background(rlc.RAYWHITE)
+
+        -- Blend the last two simulation states so motion stays smooth between ticks
+        local x = ball.prev_x + (ball.x - ball.prev_x) * alpha
+        local y = ball.prev_y + (ball.y - ball.prev_y) * alpha
+        ctx:draw_circle(x, y, ball.radius, rlc.MAROON)
+
+        ctx:draw_text("Physics at 30 ticks per second, drawn interpolated", 10, 10, 20, rlc.DARKGRAY)
+        ctx:draw_text(string.format("FPS: %i  alpha: %.2f", window:get_fps(), alpha), 10, 40, 20, rlc.GRAY)
+        ctx:draw_text("Press R to reset", 10, 70, 20, rlc.GRAY)
+    end,
+
+    on_error = function(err, phase)
+        print(string.format("error in %s: %s", phase, err))
+        return "restart"
+    end,
+})
+
+window:close()
@@ -0,0 +1,40 @@
+local rl = require("raylib")
+local rlc = rl.colors
+
+local screen_width = 800
+local screen_height = 450
+
+local window = rl.init_window(screen_width, screen_height, "rlmlua example - hot reload")
+window:set_target_fps(60)
+
+-- Everything worth keeping across reloads lives in one table
+local state = { x = screen_width / 2, direction = 1, reloads = 0 }
+
+-- Edit the speed or colors below and save: the ball keeps its position
+local SPEED = 200
+
+rl.run(window, {
+    hot_reload = true,
+    state = state,
+
+    on_reload = function(old_state)
+        state = old_state
+        state.reloads = state.reloads + 1
+    end,
+
+    update = function(dt)
+        state.x = state.x + SPEED * state.direction * dt
+        if state.x < 40 or state.x > screen_width - 40 then
+            state.direction = -state.direction
+        end
+    end,
+
+    draw = function(ctx)
+        ctx:clear_background(rlc.RAYWHITE)
+        ctx:draw_circle(state.x, screen_height / 2, 40, rlc.MAROON)
+        ctx:draw_text("Edit and save this file to reload it", 10, 10, 20, rlc.DARKGRAY)
+        ctx:draw_text(string.format("Reloads: %i", state.reloads), 10, 40, 20, rlc.GRAY)
+    end,
+})
+
+window:close()
@@ -9,6 +9,10 @@ local rl = {}
 rl.init_window = raylib_core.init_window
 rl.color = raylib_core.color
 rl.colors = raylib_core.colors
+rl.keys = raylib_core.keys
+rl.mouse_buttons = raylib_core.mouse_buttons
+rl.gamepad_buttons = raylib_core.gamepad_buttons
+rl.gamepad_axes = raylib_core.gamepad_axes
 rl.Vector2 = raylib_core.Vector2
 rl.Vector3 = raylib_core.Vector3
 
@@ -17,6 +21,31 @@ rl.check_collision_point_rec = raylib_core.check_collision_point_rec
 
 rl.fade = raylib_core.fade
 
+-- Strict mode
+rl.set_strict = raylib_core.set_strict
+rl.is_strict = raylib_core.is_strict
+
+-- Managed game loop
+rl.run = raylib_core.run
+
+-- Images
+rl.load_image = raylib_core.load_image
+rl.gen_image_color = raylib_core.gen_image_color
+rl.gen_image_checked = raylib_core.gen_image_checked
+rl.gen_image_gradient_linear = raylib_core.gen_image_gradient_linear
+rl.gen_image_gradient_radial = raylib_core.gen_image_gradient_radial
+rl.gen_image_white_noise = raylib_core.gen_image_white_noise
+rl.gen_image_perlin_noise = raylib_core.gen_image_perlin_noise
+rl.gen_image_cellular = raylib_core.gen_image_cellular
+
+-- Fonts
+rl.load_font = raylib_core.load_font
+rl.load_font_from_memory = raylib_core.load_font_from_memory
+rl.layout_text = raylib_core.layout_text
+
+-- Audio
+rl.init_audio_device = raylib_core.init_audio_device
+
 ---@type Gesture|integer
 rl.GESTURE_NONE = raylib_core.GESTURE_NONE
 ---@type Gesture|integer
@@ -13,11 +13,169 @@ local raylib = {}
 ---@class Vector2
 ---@field x number X coordinate
 ---@field y number Y coordinate
+---@operator add(Vector2|number): Vector2
+---@operator sub(Vector2|number): Vector2
+---@operator mul(Vector2|number): Vector2
+---@operator div(Vector2|number): Vector2
+---@operator mod(Vector2|number): Vector2
+---@operator pow(Vector2|number): Vector2
+---@operator unm: Vector2
+---@operator len: number
+---@operator concat(any): string
+local Vector2 = {}
+
+---Vector length
+---@param self Vector2
+---@return number
+function Vector2:length() end
+
+---Squared vector length, cheaper than length()
+---@param self Vector2
+---@return number
+function Vector2:length_sqr() end
+
+---Unit vector in the same direction
+---@param self Vector2
+---@return Vector2
+function Vector2:normalize() end
+
+---Dot product
+---@param self Vector2
+---@param other Vector2
+---@return number
+function Vector2:dot(other) end
+
+---Z component of the cross product
+---@param self Vector2
+---@param other Vector2
+---@return number
+function Vector2:cross(other) end
+
+---Angle of the vector in radians, or the signed angle to other
+---@param self Vector2
+---@param other? Vector2
+---@return number
+function Vector2:angle(other) end
+
+---Rotate by an angle in radians
+---@param self Vector2
+---@param angle number
+---@return Vector2
+function Vector2:rotate(angle) end
+
+---Distance to another vector
+---@param self Vector2
+---@param other Vector2
+---@return number
+function Vector2:distance(other) end
+
+---Linear interpolation towards another vector
+---@param self Vector2
+---@param other Vector2
+---@param amount number
+---@return Vector2
+function Vector2:lerp(other, amount) end
+
+---Move towards a target by at most max_distance
+---@param self Vector2
+---@param target Vector2
+---@param max_distance number
+---@return Vector2
+function Vector2:move_towards(target, max_distance) end
+
+---Clamp each component between min and max
+---@param self Vector2
+---@param min Vector2|number
+---@param max Vector2|number
+---@return Vector2
+function Vector2:clamp(min, max) end
+
+---Reflect off a surface with the given normal
+---@param self Vector2
+---@param normal Vector2
+---@return Vector2
+function Vector2:reflect(normal) end
+
+---The vector rotated 90 degrees counter-clockwise
+---@param self Vector2
+---@return Vector2
+function Vector2:perpendicular() end
+
+---Create a copy of the vector
+---@param self Vector2
+---@return Vector2
+function Vector2:copy() end
+
+---Return x and y
+---@param self Vector2
+---@return number, number
+function Vector2:unpack() end
 
 ---@class Vector3
 ---@field x number X coordinate
 ---@field y number Y coordinate
 ---@field z number Z coordinate
+---@operator add(Vector3|number): Vector3
+---@operator sub(Vector3|number): Vector3
+---@operator mul(Vector3|number): Vector3
+---@operator div(Vector3|number): Vector3
+---@operator unm: Vector3
+---@operator len: number
+local Vector3 = {}
+
+---Dot product
+---@param self Vector3
+---@param other Vector3
+---@return number
+function Vector3:dot(other) end
+
+---Cross product
+---@param self Vector3
+---@param other Vector3
+---@return Vector3
+function Vector3:cross(other) end
+
+---Vector length
+---@param self Vector3
+---@return number
+function Vector3:length() end
+
+---Unit vector in the same direction
+---@param self Vector3
+---@return Vector3
+function Vector3:normalize() end
+
+---Distance to another vector
+---@param self Vector3
+---@param other Vector3
+---@return number
+function Vector3:distance(other) end
+
+---Linear interpolation towards another vector
+---@param self Vector3
+---@param other Vector3
+---@param amount number
+---@return Vector3
+function Vector3:lerp(other, amount) end
+
+---Rotate around an axis by an angle in radians
+---@param self Vector3
+---@param axis Vector3
+---@param angle number
+---@return Vector3
+function Vector3:rotate_by_axis_angle(axis, angle) end
+
+---Transform by a 4x4 matrix given as m0..m15 fields or 16 numbers row by row
+---@param self Vector3
+---@param matrix number[]|table
+---@return Vector3
+function Vector3:transform(matrix) end
+
+---Reflect off a surface with the given normal
+---@param self Vector3
+---@param normal Vector3
+---@return Vector3
+function Vector3:reflect(normal) end
 
 ---@class Rectangle
 ---@field x number X position
@@ -44,7 +202,431 @@ local raylib = {}
 ---@field target Vector2 Camera target (used for 2d drawing, added to offset)
 ---@field zoom number Camera zoom (scaling), default 1.0
 
+---@alias CameraProjection "perspective"|"orthographic"
+---@alias CameraMode "custom"|"free"|"orbital"|"first_person"|"third_person"
+
+---@class Camera3D
+---@field position Vector3 Camera position
+---@field target Vector3 Camera target it looks-at
+---@field up Vector3 Camera up vector (rotation over its axis)
+---@field fovy number Camera field-of-view aperture in Y (degrees) in perspective, used as near plane width in orthographic
+---@field projection CameraProjection Camera projection
+
+---Font in GPU memory, unloaded when collected or closed
 ---@class Font
+---@field base_size integer Base size (default chars height)
+---@field glyph_count integer Number of glyph characters
+---@field glyph_padding integer Padding around the glyph characters
+local Font = {}
+
+---Check if the font is loaded and its window is still open
+---@param self Font
+---@return boolean
+function Font:is_valid() end
+
+---Unload the font from GPU memory (the default font is never unloaded)
+---@param self Font
+---@return nil
+function Font:unload() end
+
+---Codepoints to load, as a string of characters or a list of integers
+---@alias Codepoints string|integer[]
+
+---Options for draw_text_boxed and layout_text
+---@class TextLayoutOptions
+---@field wrap? "word"|"char"|"none" Where lines may break (default "word")
+---@field align? "left"|"center"|"right"|"justify" Horizontal alignment (default "left")
+---@field valign? "top"|"middle"|"bottom" Vertical alignment (default "top")
+---@field ellipsis? boolean|string Drop lines that do not fit and end the last one with "..." or this string
+---@field line_spacing? number Extra space between lines (default 0)
+
+---One line of a text layout
+---@class TextLayoutLine
+---@field text string Text drawn on the line, including any ellipsis
+---@field start integer Index of the line's first character in the source text (1-based, in characters)
+---@field length integer Number of source characters on the line
+---@field x number
+---@field y number
+---@field width number
+---@field height number
+
+---Result of layout_text
+---@class TextLayout
+---@field lines TextLayoutLine[]
+---@field width number Width of the widest line
+---@field height number Height of the laid out block
+---@field truncated boolean Whether the ellipsis cut any text
+
+---GPU texture, unloaded when collected or closed
+---@class Texture
+---@field id integer OpenGL texture id
+---@field width integer Texture width
+---@field height integer Texture height
+---@field mipmaps integer Mipmap levels
+local Texture = {}
+
+---Check if the texture can still be drawn
+---@param self Texture
+---@return boolean
+function Texture:is_valid() end
+
+---Unload the texture from GPU memory
+---@param self Texture
+---@return nil
+function Texture:unload() end
+
+---Offscreen render target, unloaded when collected or closed
+---@class RenderTexture
+---@field id integer OpenGL framebuffer id
+---@field width integer Render texture width
+---@field height integer Render texture height
+---@field texture Texture Color buffer, valid while the render texture is loaded (stored upside down, draw it with a negative source height)
+local RenderTexture = {}
+
+---Check if the render texture can still be used
+---@param self RenderTexture
+---@return boolean
+function RenderTexture:is_valid() end
+
+---Unload the render texture from GPU memory
+---@param self RenderTexture
+---@return nil
+function RenderTexture:unload() end
+
+---GLSL shader program, unloaded when collected or closed
+---@class Shader
+---@field id integer OpenGL program id
+local Shader = {}
+
+---Uniform value: a number (float), boolean (int), Vector2/Vector3 or {x, y, z, w} table, list of 1-4 numbers, Color (normalized to vec4; plain lists too with uniform_type "color"), Matrix as m0..m15 fields or 16 numbers row by row (like Vector3:transform) or Texture
+---@alias UniformValue number|boolean|Vector2|Vector3|Color|number[]|Texture
+
+---@alias UniformType "float"|"vec2"|"vec3"|"vec4"|"int"|"ivec2"|"ivec3"|"ivec4"|"mat4"|"sampler2d"|"color"
+
+---@alias Points (Vector2|{x: number, y: number})[]
+
+---@alias BlendModeName "alpha"|"additive"|"multiplied"|"add_colors"|"subtract_colors"|"alpha_premultiply"|"custom"|"custom_separate"
+
+---Check if the shader can still be used
+---@param self Shader
+---@return boolean
+function Shader:is_valid() end
+
+---Unload the shader from GPU memory
+---@param self Shader
+---@return nil
+function Shader:unload() end
+
+---Get a uniform location, -1 if the shader has no such uniform
+---@param self Shader
+---@param name string
+---@return integer
+function Shader:get_location(name) end
+
+---Set a uniform, inferring its type from the value unless uniform_type is given
+---@param self Shader
+---@param location integer|string
+---@param value UniformValue
+---@param uniform_type? UniformType
+---@return nil
+function Shader:set_value(location, value, uniform_type) end
+
+---CPU-side image data
+---@class Image
+---@field width integer Image width
+---@field height integer Image height
+---@field mipmaps integer Mipmap levels
+---@field format integer Pixel format
+local Image = {}
+
+---Get pixel color at (x, y)
+---@param self Image
+---@param x integer
+---@param y integer
+---@return Color
+function Image:get_pixel(x, y) end
+
+---Set pixel color at (x, y)
+---@param self Image
+---@param x integer
+---@param y integer
+---@param color Color
+---@return nil
+function Image:set_pixel(x, y, color) end
+
+---Crop the image to a rectangle
+---@param self Image
+---@param rect Rectangle
+---@return nil
+function Image:crop(rect) end
+
+---Resize the image (bicubic scaling)
+---@param self Image
+---@param width integer
+---@param height integer
+---@return nil
+function Image:resize(width, height) end
+
+---Flip the image horizontally or vertically
+---@param self Image
+---@param direction "horizontal"|"vertical"
+---@return nil
+function Image:flip(direction) end
+
+---Rotate the image by an angle in degrees (-359 to 359)
+---@param self Image
+---@param degrees integer
+---@return nil
+function Image:rotate(degrees) end
+
+---Modify image color: tint
+---@param self Image
+---@param color Color
+---@return nil
+function Image:color_tint(color) end
+
+---Create a copy of the image
+---@param self Image
+---@return Image
+function Image:copy() end
+
+---Export image data to file, format is chosen by extension
+---@param self Image
+---@param path string
+---@return nil
+function Image:export(path) end
+
+---Upload the image to GPU memory as a texture
+---@param self Image
+---@param window Window
+---@return Texture
+function Image:to_texture(window) end
+
+---Audio device, closing it invalidates every Sound and Music it loaded
+---@class AudioDevice
+local AudioDevice = {}
+
+---Check if the audio device is ready
+---@param self AudioDevice
+---@return boolean
+function AudioDevice:is_ready() end
+
+---Get master volume (listener)
+---@param self AudioDevice
+---@return number
+function AudioDevice:get_master_volume() end
+
+---Set master volume (listener)
+---@param self AudioDevice
+---@param volume number
+---@return nil
+function AudioDevice:set_master_volume(volume) end
+
+---Load sound from file
+---@param self AudioDevice
+---@param path string
+---@return Sound
+function AudioDevice:load_sound(path) end
+
+---Load sound from wave data
+---@param self AudioDevice
+---@param wave Wave
+---@return Sound
+function AudioDevice:load_sound_from_wave(wave) end
+
+---Load music stream from file
+---@param self AudioDevice
+---@param path string
+---@return Music
+function AudioDevice:load_music(path) end
+
+---Load wave data from file into CPU memory
+---@param self AudioDevice
+---@param path string
+---@return Wave
+function AudioDevice:load_wave(path) end
+
+---Close the audio device and invalidate its sounds and music
+---@param self AudioDevice
+---@return nil
+function AudioDevice:close() end
+
+---Sound effect loaded into an audio buffer
+---@class Sound
+---@field frame_count integer Total number of frames
+local Sound = {}
+
+---Play the sound
+---@param self Sound
+---@return nil
+function Sound:play() end
+
+---Stop playing the sound
+---@param self Sound
+---@return nil
+function Sound:stop() end
+
+---Pause the sound
+---@param self Sound
+---@return nil
+function Sound:pause() end
+
+---Resume a paused sound
+---@param self Sound
+---@return nil
+function Sound:resume() end
+
+---Check if the sound is playing
+---@param self Sound
+---@return boolean
+function Sound:is_playing() end
+
+---Set volume (1.0 is max level)
+---@param self Sound
+---@param volume number
+---@return nil
+function Sound:set_volume(volume) end
+
+---Set pitch (1.0 is base level)
+---@param self Sound
+---@param pitch number
+---@return nil
+function Sound:set_pitch(pitch) end
+
+---Set pan (0.5 is center)
+---@param self Sound
+---@param pan number
+---@return nil
+function Sound:set_pan(pan) end
+
+---Check if the sound can still be played
+---@param self Sound
+---@return boolean
+function Sound:is_valid() end
+
+---Unload the sound
+---@param self Sound
+---@return nil
+function Sound:unload() end
+
+---Music stream, call update() every frame while it plays
+---@class Music
+---@field frame_count integer Total number of frames
+---@field looping boolean Restart when the stream ends
+local Music = {}
+
+---Start playing the music stream
+---@param self Music
+---@return nil
+function Music:play() end
+
+---Refill the stream buffers
+---@param self Music
+---@return nil
+function Music:update() end
+
+---Stop the music stream
+---@param self Music
+---@return nil
+function Music:stop() end
+
+---Pause the music stream
+---@param self Music
+---@return nil
+function Music:pause() end
+
+---Resume a paused music stream
+---@param self Music
+---@return nil
+function Music:resume() end
+
+---Check if the music stream is playing
+---@param self Music
+---@return boolean
+function Music:is_playing() end
+
+---Seek to a position in seconds
+---@param self Music
+---@param position number
+---@return nil
+function Music:seek(position) end
+
+---Set volume (1.0 is max level)
+---@param self Music
+---@param volume number
+---@return nil
+function Music:set_volume(volume) end
+
+---Set pitch (1.0 is base level)
+---@param self Music
+---@param pitch number
+---@return nil
+function Music:set_pitch(pitch) end
+
+---Set pan (0.5 is center)
+---@param self Music
+---@param pan number
+---@return nil
+function Music:set_pan(pan) end
+
+---Get total length in seconds
+---@param self Music
+---@return number
+function Music:get_time_length() end
+
+---Get current playing time in seconds
+---@param self Music
+---@return number
+function Music:get_time_played() end
+
+---Check if the music stream can still be played
+---@param self Music
+---@return boolean
+function Music:is_valid() end
+
+---Unload the music stream
+---@param self Music
+---@return nil
+function Music:unload() end
+
+---Wave samples in CPU memory
+---@class Wave
+---@field frame_count integer Total number of frames
+---@field sample_rate integer Frequency (samples per second)
+---@field sample_size integer Bits per sample
+---@field channels integer Number of channels
+local Wave = {}
+
+---Create a copy of the wave
+---@param self Wave
+---@return Wave
+function Wave:copy() end
+
+---Crop the wave to a range of frames
+---@param self Wave
+---@param init_frame integer
+---@param final_frame integer
+---@return nil
+function Wave:crop(init_frame, final_frame) end
+
+---Convert wave data to the desired format
+---@param self Wave
+---@param sample_rate integer
+---@param sample_size integer
+---@param channels integer
+---@return nil
+function Wave:format(sample_rate, sample_size, channels) end
+
+---Export wave data to file
+---@param self Wave
+---@param path string
+---@return nil
+function Wave:export(path) end
+
+---Unload the wave data
+---@param self Wave
+---@return nil
+function Wave:unload() end
 
 ---Raylib window handle
 ---@class Window
@@ -76,9 +658,9 @@ function Window:get_frame_time() end
 ---@return number
 function Window:get_time() end
 
----Setup canvas (framebuffer) to start drawing
+---Setup canvas (framebuffer) to start drawing, returns the frame's drawing context
 ---@param self Window
----@return nil
+---@return DrawContext
 function Window:begin_drawing() end
 
 ---End canvas drawing and swap buffers (double buffering)
@@ -86,32 +668,46 @@ function Window:begin_drawing() end
 ---@return nil
 function Window:end_drawing() end
 
+---Draw one frame with callback; the frame is ended even if callback errors
+---@param self Window
+---@param callback fun(ctx: DrawContext)
+---@return nil
+function Window:draw_frame(callback) end
+
 ---Set background color (clear screen)
 ---@param self Window
 ---@param color Color
 ---@return nil
 function Window:clear_background(color) end
 
----Draw text (using default font)
+---Draw text (using default font), x and y may also be given as one Vector2
 ---@param self Window
 ---@param text string
----@param x integer
----@param y integer
----@param size integer
+---@param x number
+---@param y number
+---@param size number
 ---@param color Color
 ---@return nil
 function Window:draw_text(text, x, y, size, color) end
 
----Draw a color-filled rectangle
+---Draw a color-filled rectangle, position and size may also be given as Vector2s
 ---@param self Window
----@param x integer
----@param y integer
----@param width integer
----@param height integer
+---@param x number
+---@param y number
+---@param width number
+---@param height number
 ---@param color Color
 ---@return nil
 function Window:draw_rectangle(x, y, width, height, color) end
 
+---Draw a color-filled rectangle (Vector version)
+---@param self Window
+---@param position Vector2
+---@param size Vector2
+---@param color Color
+---@return nil
+function Window:draw_rectangle_v(position, size, color) end
+
 ---Draw a color-filled rectangle
 ---@param self Window
 ---@param rect Rectangle
@@ -128,25 +724,33 @@ function Window:draw_rectangle_rec(rect, color) end
 ---@return nil
 function Window:draw_triangle(v1, v2, v3, color) end
 
----Draw a color-filled circle
+---Draw a color-filled circle, x and y may also be given as one Vector2
 ---@param self Window
----@param x integer
----@param y integer
+---@param x number
+---@param y number
 ---@param radius number
 ---@param color Color
 ---@return nil
 function Window:draw_circle(x, y, radius, color) end
 
----Draw line
+---Draw line, each end point may also be given as one Vector2
 ---@param self Window
----@param x1 integer
----@param y1 integer
----@param x2 integer
----@param y2 integer
+---@param x1 number
+---@param y1 number
+---@param x2 number
+---@param y2 number
 ---@param color Color
 ---@return nil
 function Window:draw_line(x1, y1, x2, y2, color) end
 
+---Draw line (Vector version)
+---@param self Window
+---@param start_pos Vector2
+---@param end_pos Vector2
+---@param color Color
+---@return nil
+function Window:draw_line_v(start_pos, end_pos, color) end
+
 ---Draw line with thickness
 ---@param self Window
 ---@param start_pos Vector2
@@ -156,14 +760,21 @@ function Window:draw_line(x1, y1, x2, y2, color) end
 ---@return nil
 function Window:draw_line_ex(start_pos, end_pos, thick, color) end
 
----Draw pixel
+---Draw pixel, x and y may also be given as one Vector2
 ---@param self Window
----@param x integer
----@param y integer
+---@param x number
+---@param y number
 ---@param color Color
 ---@return nil
 function Window:draw_pixel(x, y, color) end
 
+---Draw pixel (Vector version)
+---@param self Window
+---@param position Vector2
+---@param color Color
+---@return nil
+function Window:draw_pixel_v(position, color) end
+
 ---Draw ring
 ---@param self Window
 ---@param center Vector2
@@ -178,91 +789,302 @@ function Window:draw_ring(center, inner_radius, outer_radius, start_angle, end_a
 
 ---Draw rectangle outline
 ---@param self Window
----@param x integer
----@param y integer
----@param width integer
----@param height integer
+---@param x number
+---@param y number
+---@param width number
+---@param height number
 ---@param color Color
 ---@return nil
 function Window:draw_rectangle_lines(x, y, width, height, color) end
 
 ---Draw circle outline
 ---@param self Window
----@param x integer
----@param y integer
+---@param x number
+---@param y number
 ---@param radius number
 ---@param color Color
 ---@return nil
 function Window:draw_circle_lines(x, y, radius, color) end
 
----Draw circle outline
+---Draw a color-filled circle (Vector version)
 ---@param self Window
----@param v Vector2
+---@param center Vector2
 ---@param radius number
 ---@param color Color
 ---@return nil
-function Window:draw_circle_v(v, radius, color) end
+function Window:draw_circle_v(center, radius, color) end
 
----Check if a key has been pressed once
+---Draw rectangle with rounded corners, rec may also be given as four numbers
 ---@param self Window
----@param key string
----@return boolean
-function Window:is_key_pressed(key) end
+---@param rec Rectangle
+---@param roundness number
+---@param segments integer
+---@param color Color
+---@return nil
+function Window:draw_rectangle_rounded(rec, roundness, segments, color) end
 
----Check if a key is being pressed
+---Draw rounded rectangle outline, with an optional line thickness, rec may also be given as four numbers
 ---@param self Window
----@param key string
----@return boolean
-function Window:is_key_down(key) end
+---@param rec Rectangle
+---@param roundness number
+---@param segments integer
+---@param color Color
+---@param thick? number
+---@return nil
+function Window:draw_rectangle_rounded_lines(rec, roundness, segments, color, thick) end
 
----Check if a key has been released once
+---Draw rectangle with a vertical gradient
 ---@param self Window
----@param key string
----@return boolean
-function Window:is_key_released(key) end
+---@param x number
+---@param y number
+---@param width number
+---@param height number
+---@param top Color
+---@param bottom Color
+---@return nil
+function Window:draw_rectangle_gradient_v(x, y, width, height, top, bottom) end
 
----Check if a key is NOT being pressed
+---Draw rectangle with a horizontal gradient
 ---@param self Window
----@param key string
----@return boolean
-function Window:is_key_up(key) end
+---@param x number
+---@param y number
+---@param width number
+---@param height number
+---@param left Color
+---@param right Color
+---@return nil
+function Window:draw_rectangle_gradient_h(x, y, width, height, left, right) end
 
----Get mouse position X and Y
+---Draw rectangle with a color per corner, rec may also be given as four numbers
 ---@param self Window
----@return Vector2
-function Window:get_mouse_position() end
+---@param rec Rectangle
+---@param top_left Color
+---@param bottom_left Color
+---@param top_right Color
+---@param bottom_right Color
+---@return nil
+function Window:draw_rectangle_gradient_ex(rec, top_left, bottom_left, top_right, bottom_right) end
 
----Get mouse position X
+---Draw rectangle rotated around origin, Vector2 arguments may also be given as two numbers and Rectangle arguments as four numbers
 ---@param self Window
----@return number
-function Window:get_mouse_x() end
+---@param rec Rectangle
+---@param origin Vector2
+---@param rotation number
+---@param color Color
+---@return nil
+function Window:draw_rectangle_pro(rec, origin, rotation, color) end
 
----Get mouse position Y
+---Draw rectangle outline with thickness, rec may also be given as four numbers
 ---@param self Window
----@return number
-function Window:get_mouse_y() end
+---@param rec Rectangle
+---@param thick number
+---@param color Color
+---@return nil
+function Window:draw_rectangle_lines_ex(rec, thick, color) end
 
----Check if a mouse button has been pressed once ("LEFT"=left, "RIGHT"=right, "MIDDLE"=middle, "EXTRA"=extra, "BACK"=back, "FORWARD"=forward)
+---Draw circle sector, center may also be given as two numbers
 ---@param self Window
----@param button string
----@return boolean
-function Window:is_mouse_button_pressed(button) end
+---@param center Vector2
+---@param radius number
+---@param start_angle number
+---@param end_angle number
+---@param segments integer
+---@param color Color
+---@return nil
+function Window:draw_circle_sector(center, radius, start_angle, end_angle, segments, color) end
+
+---Draw circle with a radial gradient, x and y may also be given as one Vector2
+---@param self Window
+---@param x number
+---@param y number
+---@param radius number
+---@param inner Color
+---@param outer Color
+---@return nil
+function Window:draw_circle_gradient(x, y, radius, inner, outer) end
+
+---Draw ellipse, x and y may also be given as one Vector2
+---@param self Window
+---@param x number
+---@param y number
+---@param radius_h number
+---@param radius_v number
+---@param color Color
+---@return nil
+function Window:draw_ellipse(x, y, radius_h, radius_v, color) end
+
+---Draw ring outline, center may also be given as two numbers
+---@param self Window
+---@param center Vector2
+---@param inner_radius number
+---@param outer_radius number
+---@param start_angle number
+---@param end_angle number
+---@param segments integer
+---@param color Color
+---@return nil
+function Window:draw_ring_lines(center, inner_radius, outer_radius, start_angle, end_angle, segments, color) end
+
+---Draw triangle outline (counter-clockwise vertices), each vertex may also be given as two numbers
+---@param self Window
+---@param v1 Vector2
+---@param v2 Vector2
+---@param v3 Vector2
+---@param color Color
+---@return nil
+function Window:draw_triangle_lines(v1, v2, v3, color) end
+
+---Draw triangle fan, the first point is the center (at least 3 points)
+---@param self Window
+---@param points Points
+---@param color Color
+---@return nil
+function Window:draw_triangle_fan(points, color) end
+
+---Draw triangle strip (at least 3 points)
+---@param self Window
+---@param points Points
+---@param color Color
+---@return nil
+function Window:draw_triangle_strip(points, color) end
+
+---Draw regular polygon, center may also be given as two numbers
+---@param self Window
+---@param center Vector2
+---@param sides integer
+---@param radius number
+---@param rotation number
+---@param color Color
+---@return nil
+function Window:draw_poly(center, sides, radius, rotation, color) end
+
+---Draw regular polygon outline with thickness, center may also be given as two numbers
+---@param self Window
+---@param center Vector2
+---@param sides integer
+---@param radius number
+---@param rotation number
+---@param thick number
+---@param color Color
+---@return nil
+function Window:draw_poly_lines_ex(center, sides, radius, rotation, thick, color) end
+
+---Draw connected lines through points (at least 2 points)
+---@param self Window
+---@param points Points
+---@param color Color
+---@return nil
+function Window:draw_line_strip(points, color) end
+
+---Draw line with cubic-bezier in-out easing, each end point may also be given as two numbers
+---@param self Window
+---@param start_pos Vector2
+---@param end_pos Vector2
+---@param thick number
+---@param color Color
+---@return nil
+function Window:draw_line_bezier(start_pos, end_pos, thick, color) end
+
+---Draw linear spline (at least 2 points)
+---@param self Window
+---@param points Points
+---@param thick number
+---@param color Color
+---@return nil
+function Window:draw_spline_linear(points, thick, color) end
+
+---Draw B-spline (at least 4 points)
+---@param self Window
+---@param points Points
+---@param thick number
+---@param color Color
+---@return nil
+function Window:draw_spline_basis(points, thick, color) end
+
+---Draw Catmull-Rom spline (at least 4 points)
+---@param self Window
+---@param points Points
+---@param thick number
+---@param color Color
+---@return nil
+function Window:draw_spline_catmull_rom(points, thick, color) end
+
+---Draw quadratic bezier spline: start, control, end, control, end... (at least 3 points)
+---@param self Window
+---@param points Points
+---@param thick number
+---@param color Color
+---@return nil
+function Window:draw_spline_bezier_quadratic(points, thick, color) end
+
+---Draw cubic bezier spline: start, control, control, end... (at least 4 points)
+---@param self Window
+---@param points Points
+---@param thick number
+---@param color Color
+---@return nil
+function Window:draw_spline_bezier_cubic(points, thick, color) end
+
+---Check if a key has been pressed once
+---@param self Window
+---@param key Key
+---@return boolean
+function Window:is_key_pressed(key) end
+
+---Check if a key is being pressed
+---@param self Window
+---@param key Key
+---@return boolean
+function Window:is_key_down(key) end
+
+---Check if a key has been released once
+---@param self Window
+---@param key Key
+---@return boolean
+function Window:is_key_released(key) end
+
+---Check if a key is NOT being pressed
+---@param self Window
+---@param key Key
+---@return boolean
+function Window:is_key_up(key) end
+
+---Get mouse position X and Y
+---@param self Window
+---@return Vector2
+function Window:get_mouse_position() end
+
+---Get mouse position X
+---@param self Window
+---@return number
+function Window:get_mouse_x() end
+
+---Get mouse position Y
+---@param self Window
+---@return number
+function Window:get_mouse_y() end
+
+---Check if a mouse button has been pressed once ("LEFT"=left, "RIGHT"=right, "MIDDLE"=middle, "EXTRA"=extra, "BACK"=back, "FORWARD"=forward)
+---@param self Window
+---@param button MouseButtonName
+---@return boolean
+function Window:is_mouse_button_pressed(button) end
 
 ---Check if a mouse button is being pressed
 ---@param self Window
----@param button string
+---@param button MouseButtonName
 ---@return boolean
 function Window:is_mouse_button_down(button) end
 
 ---Check if a mouse button has been released once
 ---@param self Window
----@param button string
+---@param button MouseButtonName
 ---@return boolean
 function Window:is_mouse_button_released(button) end
 
 ---Check if a mouse button is NOT being pressed
 ---@param self Window
----@param button string
+---@param button MouseButtonName
 ---@return boolean
 function Window:is_mouse_button_up(button) end
 
@@ -281,6 +1103,182 @@ function Window:get_screen_width() end
 ---@return number
 function Window:get_screen_height() end
 
+---Set the window title
+---@param self Window
+---@param title string
+---@return nil
+function Window:set_window_title(title) end
+
+---Set the window dimensions
+---@param self Window
+---@param width integer
+---@param height integer
+---@return nil
+function Window:set_window_size(width, height) end
+
+---Set the window position on screen
+---@param self Window
+---@param x integer
+---@param y integer
+---@return nil
+function Window:set_window_position(x, y) end
+
+---Set the minimum dimensions for a resizable window
+---@param self Window
+---@param width integer
+---@param height integer
+---@return nil
+function Window:set_window_min_size(width, height) end
+
+---Toggle between fullscreen and windowed mode
+---@param self Window
+---@return nil
+function Window:toggle_fullscreen() end
+
+---Toggle between borderless windowed and windowed mode
+---@param self Window
+---@return nil
+function Window:toggle_borderless_windowed() end
+
+---Maximize a resizable window
+---@param self Window
+---@return nil
+function Window:maximize() end
+
+---Minimize the window
+---@param self Window
+---@return nil
+function Window:minimize() end
+
+---Restore a maximized or minimized window
+---@param self Window
+---@return nil
+function Window:restore() end
+
+---Check if the window has been resized last frame
+---@param self Window
+---@return boolean
+function Window:is_window_resized() end
+
+---Check if the window is currently focused
+---@param self Window
+---@return boolean
+function Window:is_window_focused() end
+
+---Get the window position on screen
+---@param self Window
+---@return Vector2
+function Window:get_window_position() end
+
+---Get the window scale DPI factor
+---@param self Window
+---@return Vector2
+function Window:get_window_scale_dpi() end
+
+---Set the window icon from an image (R8G8B8A8 format)
+---@param self Window
+---@param image Image
+---@return nil
+function Window:set_window_icon(image) end
+
+---Check if a gamepad is available
+---@param self Window
+---@param gamepad integer
+---@return boolean
+function Window:is_gamepad_available(gamepad) end
+
+---Get the internal name of a gamepad
+---@param self Window
+---@param gamepad integer
+---@return string|nil
+function Window:get_gamepad_name(gamepad) end
+
+---Check if a gamepad button has been pressed once
+---@param self Window
+---@param gamepad integer
+---@param button GamepadButtonName
+---@return boolean
+function Window:is_gamepad_button_pressed(gamepad, button) end
+
+---Check if a gamepad button is being pressed
+---@param self Window
+---@param gamepad integer
+---@param button GamepadButtonName
+---@return boolean
+function Window:is_gamepad_button_down(gamepad, button) end
+
+---Check if a gamepad button has been released once
+---@param self Window
+---@param gamepad integer
+---@param button GamepadButtonName
+---@return boolean
+function Window:is_gamepad_button_released(gamepad, button) end
+
+---Check if a gamepad button is not being pressed
+---@param self Window
+---@param gamepad integer
+---@param button GamepadButtonName
+---@return boolean
+function Window:is_gamepad_button_up(gamepad, button) end
+
+---Get the movement of a gamepad axis (-1 to 1)
+---@param self Window
+---@param gamepad integer
+---@param axis GamepadAxisName
+---@return number
+function Window:get_gamepad_axis_movement(gamepad, axis) end
+
+---Get the number of axes of a gamepad
+---@param self Window
+---@param gamepad integer
+---@return integer
+function Window:get_gamepad_axis_count(gamepad) end
+
+---Get the name of the last gamepad button pressed
+---@param self Window
+---@return string|nil
+function Window:get_gamepad_button_pressed() end
+
+---Load SDL_GameControllerDB mappings
+---@param self Window
+---@param mappings string
+---@return integer
+function Window:set_gamepad_mappings(mappings) end
+
+---Replace a gamepad's state with fake input; each call counts as a new frame
+---@param self Window
+---@param gamepad integer
+---@param state GamepadOverrideState
+---@return nil
+function Window:set_gamepad_override(gamepad, state) end
+
+---Remove the fake input for a gamepad, or for all gamepads
+---@param self Window
+---@param gamepad? integer
+---@return nil
+function Window:clear_gamepad_override(gamepad) end
+
+---Get the next key pressed this frame from the key queue, named as in rl.keys
+---@param self Window
+---@return string|nil
+function Window:get_key_pressed() end
+
+---Get the next character typed this frame as a UTF-8 string
+---@param self Window
+---@return string|nil
+function Window:get_char_pressed() end
+
+---Get the clipboard text
+---@param self Window
+---@return string
+function Window:get_clipboard_text() end
+
+---Set the clipboard text
+---@param self Window
+---@param text string
+---@return nil
+function Window:set_clipboard_text(text) end
+
 ---Get the x position of the mouse
 ---@param self Window
 ---@return number
@@ -356,6 +1354,115 @@ function Window:begin_mode_2d(camera) end
 ---@return nil
 function Window:end_mode_2d() end
 
+---Run callback in 2D mode, ending it even if the callback errors
+---@param self Window
+---@param camera Camera2D
+---@param callback fun(window: Window)
+---@return nil
+function Window:with_mode_2d(camera, callback) end
+
+---Convert world coordinates to screen coordinates
+---@param self Window
+---@param pos Vector2
+---@param camera Camera2D
+---@return Vector2
+function Window:get_world_to_screen_2d(pos, camera) end
+
+---Begin 3D mode
+---@param self Window
+---@param camera Camera3D
+---@return nil
+function Window:begin_mode_3d(camera) end
+
+---End 3D mode
+---@param self Window
+---@return nil
+function Window:end_mode_3d() end
+
+---Run callback in 3D mode, ending it even if the callback errors
+---@param self Window
+---@param camera Camera3D
+---@param callback fun(window: Window)
+---@return nil
+function Window:with_mode_3d(camera, callback) end
+
+---Update camera position for the selected mode
+---@param self Window
+---@param camera Camera3D
+---@param mode CameraMode
+---@return nil
+function Window:update_camera(camera, mode) end
+
+---Draw cube
+---@param self Window
+---@param position Vector3
+---@param width number
+---@param height number
+---@param length number
+---@param color Color
+---@return nil
+function Window:draw_cube(position, width, height, length, color) end
+
+---Draw cube wires
+---@param self Window
+---@param position Vector3
+---@param width number
+---@param height number
+---@param length number
+---@param color Color
+---@return nil
+function Window:draw_cube_wires(position, width, height, length, color) end
+
+---Draw sphere
+---@param self Window
+---@param center Vector3
+---@param radius number
+---@param color Color
+---@return nil
+function Window:draw_sphere(center, radius, color) end
+
+---Draw a cylinder/cone
+---@param self Window
+---@param position Vector3
+---@param radius_top number
+---@param radius_bottom number
+---@param height number
+---@param slices integer
+---@param color Color
+---@return nil
+function Window:draw_cylinder(position, radius_top, radius_bottom, height, slices, color) end
+
+---Draw a plane XZ
+---@param self Window
+---@param center Vector3
+---@param size Vector2
+---@param color Color
+---@return nil
+function Window:draw_plane(center, size, color) end
+
+---Draw a grid (centered at (0, 0, 0))
+---@param self Window
+---@param slices integer
+---@param spacing number
+---@return nil
+function Window:draw_grid(slices, spacing) end
+
+---Draw a line in 3D world space
+---@param self Window
+---@param start_pos Vector3
+---@param end_pos Vector3
+---@param color Color
+---@return nil
+function Window:draw_line_3d(start_pos, end_pos, color) end
+
+---Draw a ray line
+---@param self Window
+---@param position Vector3
+---@param direction Vector3
+---@param color Color
+---@return nil
+function Window:draw_ray(position, direction, color) end
+
 ---Push a matrix to the stack
 ---@param self Window
 ---@return nil
@@ -391,28 +1498,272 @@ function Window:rl_rotate(angle, x, y, z) end
 ---@return nil
 function Window:rl_draw_grid(width, height) end
 
----Draw text using a custom font
+---Draw text using a custom font, position may also be given as two numbers
+---@param self Window
+---@param font Font
+---@param text string
+---@param position Vector2
+---@param font_size number
+---@param spacing number
+---@param tint Color
+---@return nil
+function Window:draw_text_ex(font, text, position, font_size, spacing, tint) end
+
+---Draw text using a custom font, rotated around origin, Vector2 arguments may also be given as two numbers
 ---@param self Window
 ---@param font Font
 ---@param text string
 ---@param position Vector2
+---@param origin Vector2
+---@param rotation number
 ---@param font_size number
 ---@param spacing number
 ---@param tint Color
 ---@return nil
-function Window:draw_text_ex(font, text, position, font_size,  spacing, tint) end
+function Window:draw_text_pro(font, text, position, origin, rotation, font_size, spacing, tint) end
+
+---Draw a single character, position may also be given as two numbers
+---@param self Window
+---@param font Font
+---@param codepoint integer|string
+---@param position Vector2
+---@param font_size number
+---@param tint Color
+---@return nil
+function Window:draw_text_codepoint(font, codepoint, position, font_size, tint) end
 
----
+---Measure the width of text drawn with the default font
+---@param self Window
+---@param text string
+---@param font_size integer
+---@return integer
+function Window:measure_text(text, font_size) end
+
+---Measure the size of text drawn with a font
+---@param self Window
+---@param font Font
+---@param text string
+---@param font_size number
+---@param spacing number
+---@return Vector2
+function Window:measure_text_ex(font, text, font_size, spacing) end
+
+---Set the vertical line spacing used when drawing text with line breaks
+---@param self Window
+---@param spacing integer
+---@return nil
+function Window:set_text_line_spacing(spacing) end
+
+---Get raylib's built-in default font
 ---@param self Window
 ---@return Font
 function Window:get_font_default() end
 
+---Draw text wrapped and aligned inside a rectangle (nil font uses the default font)
+---@param self Window
+---@param font Font|nil
+---@param text string
+---@param rect Rectangle
+---@param font_size number
+---@param spacing number
+---@param color Color
+---@param options? TextLayoutOptions
+---@return nil
+function Window:draw_text_boxed(font, text, rect, font_size, spacing, color, options) end
+
+---Load texture from file into GPU memory
+---@param self Window
+---@param path string
+---@return Texture
+function Window:load_texture(path) end
+
+---Load texture from image data
+---@param self Window
+---@param image Image
+---@return Texture
+function Window:load_texture_from_image(image) end
+
+---Load a render texture to draw into
+---@param self Window
+---@param width integer
+---@param height integer
+---@return RenderTexture
+function Window:load_render_texture(width, height) end
+
+---Redirect drawing to a render texture, errors if texture mode or a camera mode is already active
+---@param self Window
+---@param target RenderTexture
+---@return nil
+function Window:begin_texture_mode(target) end
+
+---End texture mode
+---@param self Window
+---@return nil
+function Window:end_texture_mode() end
+
+---Run callback drawing into a render texture, ending texture mode even if the callback errors
+---@param self Window
+---@param target RenderTexture
+---@param callback fun(window: Window)
+---@return nil
+function Window:with_texture_mode(target, callback) end
+
+---Load and compile a shader from files, nil uses the default stage; compile errors include the GLSL log
+---@param self Window
+---@param vs_path string|nil
+---@param fs_path string|nil
+---@return Shader
+function Window:load_shader(vs_path, fs_path) end
+
+---Compile a shader from source code, nil uses the default stage; compile errors include the GLSL log
+---@param self Window
+---@param vs_code string|nil
+---@param fs_code string|nil
+---@return Shader
+function Window:load_shader_from_memory(vs_code, fs_code) end
+
+---Draw with a custom shader, errors if shader mode is already active
+---@param self Window
+---@param shader Shader
+---@return nil
+function Window:begin_shader_mode(shader) end
+
+---End shader mode
+---@param self Window
+---@return nil
+function Window:end_shader_mode() end
+
+---Run callback drawing with a shader, ending shader mode even if the callback errors
+---@param self Window
+---@param shader Shader
+---@param callback fun(window: Window)
+---@return nil
+function Window:with_shader_mode(shader, callback) end
+
+---Begin blending with a mode; nested calls stack
+---@param self Window
+---@param mode BlendModeName
+---@return nil
+function Window:begin_blend_mode(mode) end
+
+---End the innermost blend mode, restoring the one it replaced
+---@param self Window
+---@return nil
+function Window:end_blend_mode() end
+
+---Run callback with a blend mode, restoring the previous mode even if the callback errors
+---@param self Window
+---@param mode BlendModeName
+---@param callback fun(window: Window)
+---@return nil
+function Window:with_blend_mode(mode, callback) end
+
+---Set the OpenGL blend factors and equation used by the "custom" blend mode
+---@param self Window
+---@param src_factor integer
+---@param dst_factor integer
+---@param equation integer
+---@return nil
+function Window:set_blend_factors(src_factor, dst_factor, equation) end
+
+---Clip drawing to an area given as x, y, width, height or a rectangle; nested areas are clipped to their parent
+---@param self Window
+---@param x integer|Rectangle
+---@param y? integer
+---@param width? integer
+---@param height? integer
+---@return nil
+function Window:begin_scissor_mode(x, y, width, height) end
+
+---End the innermost scissor mode, restoring the area it replaced
+---@param self Window
+---@return nil
+function Window:end_scissor_mode() end
+
+---Run callback clipped to an area, given like begin_scissor_mode followed by the callback
+---@param self Window
+---@param x integer|Rectangle
+---@param ... integer|fun(window: Window)
+---@return nil
+function Window:with_scissor_mode(x, ...) end
+
+---Draw a texture, x and y may also be given as one Vector2
+---@param self Window
+---@param texture Texture
+---@param x number
+---@param y number
+---@param tint Color
+---@return nil
+function Window:draw_texture(texture, x, y, tint) end
+
+---Draw a texture with position defined as Vector2
+---@param self Window
+---@param texture Texture
+---@param position Vector2
+---@param tint Color
+---@return nil
+function Window:draw_texture_v(texture, position, tint) end
+
+---Draw a texture with extended parameters, position may also be given as two numbers
+---@param self Window
+---@param texture Texture
+---@param position Vector2
+---@param rotation number
+---@param scale number
+---@param tint Color
+---@return nil
+function Window:draw_texture_ex(texture, position, rotation, scale, tint) end
+
+---Draw a part of a texture defined by a rectangle, Vector2 arguments may also be given as two numbers and Rectangle arguments as four numbers
+---@param self Window
+---@param texture Texture
+---@param source Rectangle
+---@param position Vector2
+---@param tint Color
+---@return nil
+function Window:draw_texture_rec(texture, source, position, tint) end
+
+---Draw a part of a texture defined by a rectangle with 'pro' parameters, Vector2 arguments may also be given as two numbers and Rectangle arguments as four numbers
+---@param self Window
+---@param texture Texture
+---@param source Rectangle
+---@param dest Rectangle
+---@param origin Vector2
+---@param rotation number
+---@param tint Color
+---@return nil
+function Window:draw_texture_pro(texture, source, dest, origin, rotation, tint) end
+
+---Drawing surface of one frame, passed to the draw_frame callback. Offers the
+---window's draw_*, clear_background and mode methods, and errors once its frame has ended
+---@class DrawContext: Window
+local DrawContext = {}
+
+---Check if the frame this context belongs to is still in progress
+---@param self DrawContext
+---@return boolean
+function DrawContext:is_valid() end
+
+---Configuration flags for init_window, all default to false
+---@class WindowOptions
+---@field resizable? boolean Allow the window to be resized
+---@field vsync? boolean Wait for vertical sync
+---@field msaa_4x? boolean Enable 4x multisample anti-aliasing
+---@field fullscreen? boolean Start in fullscreen mode
+---@field undecorated? boolean Hide the window decorations
+---@field highdpi? boolean Support high DPI displays
+---@field transparent? boolean Allow a transparent framebuffer
+---@field hidden? boolean Start with the window hidden
+---@field always_run? boolean Keep running while minimized
+---@field strict? boolean Turn strict mode on, see raylib.set_strict
+
 ---Initialize window and OpenGL context
 ---@param width integer Window width
 ---@param height integer Window height
 ---@param title string Window title
+---@param options? WindowOptions Window configuration flags
 ---@return Window
-function raylib.init_window(width, height, title) end
+function raylib.init_window(width, height, title, options) end
 
 ---Create a color from RGBA values
 ---@param r integer Red (0-255)
@@ -427,6 +1778,130 @@ function raylib.color(r, g, b, a) end
 ---@param callback fun(window: Window) Drawing callback function
 function raylib.draw(window, callback) end
 
+---Callbacks and timing for raylib.run, every field is optional
+---@class RunOptions
+---@field load? fun(window: Window) Called once before the first frame, and again on restart
+---@field update? fun(dt: number) Called every frame with the frame time in seconds
+---@field fixed_update? fun(step: number) Called tick_rate times per second with the fixed step in seconds
+---@field draw? fun(ctx: DrawContext, alpha: number) Called inside the frame; alpha is how far (0-1) time is between the last and the next fixed step
+---@field quit? fun() Called once the loop ends
+---@field on_error? fun(err: string, phase: RunPhase): "continue"|"restart"|nil Handle a callback error; return "continue" to keep running, "restart" to call load again or nil to stop. Without a handler the crash screen is shown
+---@field crash_screen? boolean Show errors without an on_error handler on a crash screen with the traceback, offering restart, copy and quit keys; false raises them instead (default true)
+---@field tick_rate? number Fixed steps per second (default 60)
+---@field max_steps? integer Most fixed steps run in one frame, extra time is dropped (default 5)
+---@field hot_reload? boolean Re-execute Lua files in the script's directory when they change, keeping the window open (default false)
+---@field on_reload? fun(old_state: any): any Called instead of load after the main script is reloaded, with the state of the previous run; return a value to replace the state
+---@field state? any Game state handed to on_reload after a reload
+
+---@alias RunPhase "load"|"update"|"fixed_update"|"draw"|"quit"
+
+---Run the game loop until the window closes, with fixed timestep updates and interpolated drawing
+---@param window Window
+---@param callbacks RunOptions
+---@return nil
+function raylib.run(window, callbacks) end
+
+---Raise errors for drawing outside a frame, unbalanced begin_drawing/end_drawing and modes left active when the frame ends
+---@param strict boolean
+---@return nil
+function raylib.set_strict(strict) end
+
+---Check if strict mode is on
+---@return boolean
+function raylib.is_strict() end
+
+---Load a font into GPU memory (size defaults to 32, codepoints to ASCII); needs an open window
+---@param path string
+---@param size? integer
+---@param codepoints? Codepoints
+---@return Font
+function raylib.load_font(path, size, codepoints) end
+
+---Lay out text like draw_text_boxed without drawing it, for measuring and hit-testing
+---@param font Font|nil
+---@param text string
+---@param rect Rectangle
+---@param font_size number
+---@param spacing number
+---@param options? TextLayoutOptions
+---@return TextLayout
+function raylib.layout_text(font, text, rect, font_size, spacing, options) end
+
+---Load a font from file data, file_type is the extension such as ".ttf"; needs an open window
+---@param file_type string
+---@param data string
+---@param size? integer
+---@param codepoints? Codepoints
+---@return Font
+function raylib.load_font_from_memory(file_type, data, size, codepoints) end
+
+---Load image from file into CPU memory
+---@param path string
+---@return Image
+function raylib.load_image(path) end
+
+---Generate image: plain color
+---@param width integer
+---@param height integer
+---@param color Color
+---@return Image
+function raylib.gen_image_color(width, height, color) end
+
+---Generate image: checked
+---@param width integer
+---@param height integer
+---@param checks_x integer
+---@param checks_y integer
+---@param col1 Color
+---@param col2 Color
+---@return Image
+function raylib.gen_image_checked(width, height, checks_x, checks_y, col1, col2) end
+
+---Generate image: linear gradient, direction in degrees [0..360]
+---@param width integer
+---@param height integer
+---@param direction integer
+---@param start Color
+---@param end Color
+---@return Image
+function raylib.gen_image_gradient_linear(width, height, direction, start, end) end
+
+---Generate image: radial gradient
+---@param width integer
+---@param height integer
+---@param density number
+---@param inner Color
+---@param outer Color
+---@return Image
+function raylib.gen_image_gradient_radial(width, height, density, inner, outer) end
+
+---Generate image: white noise
+---@param width integer
+---@param height integer
+---@param factor number
+---@return Image
+function raylib.gen_image_white_noise(width, height, factor) end
+
+---Generate image: perlin noise
+---@param width integer
+---@param height integer
+---@param offset_x integer
+---@param offset_y integer
+---@param scale number
+---@return Image
+function raylib.gen_image_perlin_noise(width, height, offset_x, offset_y, scale) end
+
+---Generate image: cellular algorithm, bigger tile_size means bigger cells
+---@param width integer
+---@param height integer
+---@param tile_size integer
+---@return Image
+function raylib.gen_image_cellular(width, height, tile_size) end
+
+---Initialize audio device and context
+---@return AudioDevice
+function raylib.init_audio_device() end
+
 ---Get a random value
 ---@return number
 function raylib.get_random_value(min, max) end
@@ -456,9 +1931,169 @@ function raylib.get_random_value(min, max) end
 ---@field BEIGE Color Beige (211, 176, 131, 255)
 raylib.colors = {}
 
----Key string constants for keyboard input
----Supported keys: "SPACE", "ESCAPE", "ENTER", "TAB", "BACKSPACE",
----"UP", "DOWN", "LEFT", "RIGHT", "A"-"Z", "0"-"9",
----"F1"-"F12", "SHIFT", "CTRL", "ALT"
+---Key name such as "SPACE" or "KEY_SPACE" (case-insensitive), or a raylib key code
+---@alias Key string|integer
+
+---Mouse button name such as "LEFT" or "MOUSE_BUTTON_LEFT", or a raylib button code
+---@alias MouseButtonName string|integer
+
+---Raylib key codes by name
+---@class Keys
+---@field NULL integer
+---@field APOSTROPHE integer
+---@field COMMA integer
+---@field MINUS integer
+---@field PERIOD integer
+---@field SLASH integer
+---@field ZERO integer
+---@field ONE integer
+---@field TWO integer
+---@field THREE integer
+---@field FOUR integer
+---@field FIVE integer
+---@field SIX integer
+---@field SEVEN integer
+---@field EIGHT integer
+---@field NINE integer
+---@field SEMICOLON integer
+---@field EQUAL integer
+---@field A integer
+---@field B integer
+---@field C integer
+---@field D integer
+---@field E integer
+---@field F integer
+---@field G integer
+---@field H integer
+---@field I integer
+---@field J integer
+---@field K integer
+---@field L integer
+---@field M integer
+---@field N integer
+---@field O integer
+---@field P integer
+---@field Q integer
+---@field R integer
+---@field S integer
+---@field T integer
+---@field U integer
+---@field V integer
+---@field W integer
+---@field X integer
+---@field Y integer
+---@field Z integer
+---@field LEFT_BRACKET integer
+---@field BACKSLASH integer
+---@field RIGHT_BRACKET integer
+---@field GRAVE integer
+---@field SPACE integer
+---@field ESCAPE integer
+---@field ENTER integer
+---@field TAB integer
+---@field BACKSPACE integer
+---@field INSERT integer
+---@field DELETE integer
+---@field RIGHT integer
+---@field LEFT integer
+---@field DOWN integer
+---@field UP integer
+---@field PAGE_UP integer
+---@field PAGE_DOWN integer
+---@field HOME integer
+---@field END integer
+---@field CAPS_LOCK integer
+---@field SCROLL_LOCK integer
+---@field NUM_LOCK integer
+---@field PRINT_SCREEN integer
+---@field PAUSE integer
+---@field F1 integer
+---@field F2 integer
+---@field F3 integer
+---@field F4 integer
+---@field F5 integer
+---@field F6 integer
+---@field F7 integer
+---@field F8 integer
+---@field F9 integer
+---@field F10 integer
+---@field F11 integer
+---@field F12 integer
+---@field LEFT_SHIFT integer
+---@field LEFT_CONTROL integer
+---@field LEFT_ALT integer
+---@field LEFT_SUPER integer
+---@field RIGHT_SHIFT integer
+---@field RIGHT_CONTROL integer
+---@field RIGHT_ALT integer
+---@field RIGHT_SUPER integer
+---@field KB_MENU integer
+---@field KP_0 integer
+---@field KP_1 integer
+---@field KP_2 integer
+---@field KP_3 integer
+---@field KP_4 integer
+---@field KP_5 integer
+---@field KP_6 integer
+---@field KP_7 integer
+---@field KP_8 integer
+---@field KP_9 integer
+---@field KP_DECIMAL integer
+---@field KP_DIVIDE integer
+---@field KP_MULTIPLY integer
+---@field KP_SUBTRACT integer
+---@field KP_ADD integer
+---@field KP_ENTER integer
+---@field KP_EQUAL integer
+---@field BACK integer
+---@field MENU integer
+---@field VOLUME_UP integer
+---@field VOLUME_DOWN integer
+raylib.keys = {}
+
+---Raylib mouse button codes by name
+---@class MouseButtons
+---@field LEFT integer
+---@field RIGHT integer
+---@field MIDDLE integer
+---@field SIDE integer
+---@field EXTRA integer
+---@field FORWARD integer
+---@field BACK integer
+raylib.mouse_buttons = {}
+
+---Gamepad button name such as "RIGHT_FACE_DOWN", or a raylib button code
+---@alias GamepadButtonName string|integer
+
+---Gamepad axis name such as "LEFT_X", or a raylib axis code
+---@alias GamepadAxisName string|integer
+
+---Raylib gamepad button codes by name
+---@class GamepadButtons
+---@field UNKNOWN integer
+---@field LEFT_FACE_UP integer
+---@field RIGHT_FACE_UP integer
+---@field MIDDLE_LEFT integer
+---@field MIDDLE integer
+---@field MIDDLE_RIGHT integer
+---@field LEFT_THUMB integer
+---@field RIGHT_THUMB integer
+raylib.gamepad_buttons = {}
+
+---Raylib gamepad axis codes by name
+---@class GamepadAxes
+---@field LEFT_X integer
+---@field LEFT_Y integer
+---@field RIGHT_X integer
+---@field RIGHT_Y integer
+---@field LEFT_TRIGGER integer
+---@field RIGHT_TRIGGER integer
+raylib.gamepad_axes = {}
+
+---Fake gamepad state for window:set_gamepad_override
+---@class GamepadOverrideState
+---@field name? string Name reported by get_gamepad_name
+---@field buttons? table<GamepadButtonName, boolean> Buttons held down
+---@field axes? table<GamepadAxisName, number> Axis values (-1 to 1)
 
 return raylib
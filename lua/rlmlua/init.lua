@@ -3,12 +3,11 @@
 
 local raylib_lua = require("raylib_lua")
 
-local rlm = {}
+-- The helpers are implemented natively (see `src/rlm.rs`); raylib_lua carries
+-- a copy of the rlm_lua table so only one shared library needs installing
+local rlm = raylib_lua.rlm
 
--- Re-export helper functions from the raylib_lua module
--- These create Vector2, Vector3, and Rectangle userdata types
-rlm.vec2 = raylib_lua.vec2
-rlm.vec3 = raylib_lua.vec3
-rlm.rect = raylib_lua.rect
+-- Reference to the raylib module for convenience
+rlm.raylib = require("raylib")
 
 return rlm
@@ -76,13 +76,23 @@ function rlmlua.vec2_lerp(a, b, t) end
 ---@return Rectangle
 function rlmlua.rect(x, y, width, height) end
 
----Create a 2D camera---@param offset Vector2|nil Camera offset
+---Create a 2D camera
+---@param offset Vector2|nil Camera offset
 ---@param target Vector2|nil Camera target
 ---@param rotation number|nil Camera rotation
 ---@param zoom number|nil Camera zoom
 ---@return Camera2D
 function rlmlua.camera2d(offset, target, rotation, zoom) end
 
+---Create a 3D camera
+---@param position Vector3 Camera position
+---@param target Vector3 Camera target
+---@param up Vector3|nil Camera up vector, defaults to +Y
+---@param fovy number|nil Field of view in degrees, defaults to 45
+---@param projection CameraProjection|nil Defaults to "perspective"
+---@return Camera3D
+function rlmlua.camera3d(position, target, up, fovy, projection) end
+
 ---Check if point is inside rectangle
 ---@param rect Rectangle
 ---@param x number Point X coordinate
@@ -186,54 +196,298 @@ function rlmlua.random_choice(tbl) end
 rlmlua.ease = {}
 
 ---@param t number Input (0-1)
----@return number Output (0-1)
+---@return number Output (0-1, back and elastic overshoot)
 function rlmlua.ease.linear(t) end
 
 ---@param t number Input (0-1)
----@return number Output (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_sine(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.out_sine(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_out_sine(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
 function rlmlua.ease.in_quad(t) end
 
 ---@param t number Input (0-1)
----@return number Output (0-1)
+---@return number Output (0-1, back and elastic overshoot)
 function rlmlua.ease.out_quad(t) end
 
 ---@param t number Input (0-1)
----@return number Output (0-1)
+---@return number Output (0-1, back and elastic overshoot)
 function rlmlua.ease.in_out_quad(t) end
 
 ---@param t number Input (0-1)
----@return number Output (0-1)
+---@return number Output (0-1, back and elastic overshoot)
 function rlmlua.ease.in_cubic(t) end
 
 ---@param t number Input (0-1)
----@return number Output (0-1)
+---@return number Output (0-1, back and elastic overshoot)
 function rlmlua.ease.out_cubic(t) end
 
 ---@param t number Input (0-1)
----@return number Output (0-1)
+---@return number Output (0-1, back and elastic overshoot)
 function rlmlua.ease.in_out_cubic(t) end
 
 ---@param t number Input (0-1)
----@return number Output (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_quart(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.out_quart(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_out_quart(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_quint(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.out_quint(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_out_quint(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_expo(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.out_expo(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_out_expo(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_circ(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.out_circ(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_out_circ(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_back(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.out_back(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_out_back(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
 function rlmlua.ease.in_elastic(t) end
 
 ---@param t number Input (0-1)
----@return number Output (0-1)
+---@return number Output (0-1, back and elastic overshoot)
 function rlmlua.ease.out_elastic(t) end
 
 ---@param t number Input (0-1)
----@return number Output (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_out_elastic(t) end
+
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
 function rlmlua.ease.in_bounce(t) end
 
 ---@param t number Input (0-1)
----@return number Output (0-1)
+---@return number Output (0-1, back and elastic overshoot)
 function rlmlua.ease.out_bounce(t) end
 
+---@param t number Input (0-1)
+---@return number Output (0-1, back and elastic overshoot)
+function rlmlua.ease.in_out_bounce(t) end
+
+---Countdown timer advanced with update(dt)
+---@class Timer
+---@field duration number Timer duration in seconds
+---@field elapsed number Time elapsed so far in seconds
+local Timer = {}
+
+---Advance the timer, returns true once it has finished
+---@param self Timer
+---@param dt number
+---@return boolean
+function Timer:update(dt) end
+
+---Fraction of the duration elapsed (0-1)
+---@param self Timer
+---@return number
+function Timer:progress() end
+
+---Check if the timer has finished
+---@param self Timer
+---@return boolean
+function Timer:is_done() end
+
+---Restart the timer from zero
+---@param self Timer
+---@return nil
+function Timer:reset() end
+
 ---Create a simple timer
 ---@param duration number Timer duration in seconds
----@return table timer Timer object with update(), progress(), reset() methods
+---@return Timer
 function rlmlua.timer(duration) end
 
+---Easing name from rlmlua.ease or a function mapping progress to eased progress
+---@alias EasingName string|fun(t: number): number
+
+---Animates fields of a table towards goal values
+---@class Tween
+---@field target table Table whose fields are animated
+---@field duration number Length of one run in seconds
+---@field elapsed number Time elapsed in the current run
+---@field yoyo boolean Play every repeat in the opposite direction
+---@field repeats integer Extra runs after the first, -1 repeats forever
+---@field on_complete fun(target: table, tween: Tween)|nil Called once when the tween finishes
+local Tween = {}
+
+---Advance the tween and write the new values, returns true on the update it finishes
+---@param self Tween
+---@param dt number
+---@return boolean
+function Tween:update(dt) end
+
+---Check if the tween has finished
+---@param self Tween
+---@return boolean
+function Tween:is_done() end
+
+---Fraction of the current run elapsed (0-1), before easing
+---@param self Tween
+---@return number
+function Tween:progress() end
+
+---Restart from the start values
+---@param self Tween
+---@return nil
+function Tween:reset() end
+
+---Tween number, Vector2, Vector3 ({x, y} and {x, y, z} tables too) and color fields of a table towards goal values
+---@param target table Table holding the fields to animate
+---@param goals table Goal value per field name
+---@param duration number Length of one run in seconds
+---@param easing? EasingName Easing curve (default "linear")
+---@param options? {yoyo?: boolean, repeats?: integer, on_complete?: fun(target: table, tween: Tween)}
+---@return Tween
+function rlmlua.tween(target, goals, duration, easing, options) end
+
+---Single-line text field; cursor and selection are character offsets from 0
+---@class TextInput
+---@field text string Current text
+---@field cursor integer Number of characters before the caret
+---@field length integer Number of characters in the text
+---@field max_length integer|nil Maximum number of characters
+local TextInput = {}
+
+---Read typed characters and editing keys for this frame, returns true if the text changed
+---@param self TextInput
+---@return boolean
+function TextInput:update() end
+
+---Insert text at the caret, replacing the selection
+---@param self TextInput
+---@param text string
+---@return boolean
+function TextInput:insert(text) end
+
+---Delete the selection or the character before the caret
+---@param self TextInput
+---@return boolean
+function TextInput:backspace() end
+
+---Delete the selection or the character after the caret
+---@param self TextInput
+---@return boolean
+function TextInput:delete() end
+
+---Move the caret left, extending the selection if select is true
+---@param self TextInput
+---@param select? boolean
+---@return nil
+function TextInput:move_left(select) end
+
+---Move the caret right, extending the selection if select is true
+---@param self TextInput
+---@param select? boolean
+---@return nil
+function TextInput:move_right(select) end
+
+---Move the caret to the start
+---@param self TextInput
+---@param select? boolean
+---@return nil
+function TextInput:move_home(select) end
+
+---Move the caret to the end
+---@param self TextInput
+---@param select? boolean
+---@return nil
+function TextInput:move_end(select) end
+
+---Select the whole text
+---@param self TextInput
+---@return nil
+function TextInput:select_all() end
+
+---Start and end offsets of the selection, or nil
+---@param self TextInput
+---@return integer|nil, integer|nil
+function TextInput:get_selection() end
+
+---Get the selected text
+---@param self TextInput
+---@return string
+function TextInput:selected_text() end
+
+---Copy the selection to the clipboard
+---@param self TextInput
+---@return nil
+function TextInput:copy() end
+
+---Move the selection to the clipboard
+---@param self TextInput
+---@return boolean
+function TextInput:cut() end
+
+---Insert the clipboard text at the caret
+---@param self TextInput
+---@return boolean
+function TextInput:paste() end
+
+---Remove all text
+---@param self TextInput
+---@return nil
+function TextInput:clear() end
+
+---Create a text field that edits text from keyboard input
+---@param text? string Initial text
+---@param max_length? integer Maximum number of characters
+---@return TextInput
+function rlmlua.text_input(text, max_length) end
+
 ---Version string
 rlmlua._VERSION = "0.1.0"
 
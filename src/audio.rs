@@ -0,0 +1,542 @@
+use mlua::prelude::*;
+use raylib::core::audio::RaylibAudio;
+use raylib::ffi;
+use std::ffi::CString;
+
+use crate::LivenessToken;
+
+fn c_path(path: &str) -> LuaResult<CString> {
+    CString::new(path).map_err(LuaError::external)
+}
+
+/// Audio device returned by `rl.init_audio_device()`.
+///
+/// Closing the device (explicitly, through `__close` or when it is collected)
+/// invalidates every `Sound` and `Music` it loaded. `Wave` data lives in CPU
+/// memory and stays usable.
+pub struct LuaAudioDevice {
+    audio: Option<RaylibAudio>,
+    alive: LivenessToken,
+}
+
+impl LuaAudioDevice {
+    fn audio(&self) -> LuaResult<&RaylibAudio> {
+        self.audio
+            .as_ref()
+            .ok_or_else(|| LuaError::runtime("Audio device has been closed"))
+    }
+
+    fn close(&mut self) {
+        self.alive.invalidate();
+        // Dropping the handle closes the device
+        self.audio = None;
+    }
+
+    fn load_sound(&self, path: &str) -> LuaResult<LuaSound> {
+        self.audio()?;
+        let c_path = c_path(path)?;
+        let sound = unsafe { ffi::LoadSound(c_path.as_ptr()) };
+        if sound.stream.buffer.is_null() {
+            return Err(LuaError::runtime(format!(
+                "Failed to load sound '{}'",
+                path
+            )));
+        }
+        Ok(LuaSound::new(sound, self.alive.clone()))
+    }
+
+    fn load_sound_from_wave(&self, wave: &LuaWave) -> LuaResult<LuaSound> {
+        self.audio()?;
+        let sound = unsafe { ffi::LoadSoundFromWave(*wave.wave()?) };
+        if sound.stream.buffer.is_null() {
+            return Err(LuaError::runtime("Failed to load sound from wave"));
+        }
+        Ok(LuaSound::new(sound, self.alive.clone()))
+    }
+
+    fn load_music(&self, path: &str) -> LuaResult<LuaMusic> {
+        self.audio()?;
+        let c_path = c_path(path)?;
+        let music = unsafe { ffi::LoadMusicStream(c_path.as_ptr()) };
+        if music.stream.buffer.is_null() {
+            return Err(LuaError::runtime(format!(
+                "Failed to load music stream '{}'",
+                path
+            )));
+        }
+        Ok(LuaMusic::new(music, self.alive.clone()))
+    }
+}
+
+impl Drop for LuaAudioDevice {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+impl LuaUserData for LuaAudioDevice {
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("is_ready", |_, this, ()| {
+            Ok(this
+                .audio
+                .as_ref()
+                .is_some_and(|audio| audio.is_audio_device_ready()))
+        });
+
+        methods.add_method("get_master_volume", |_, this, ()| {
+            Ok(this.audio()?.get_master_volume())
+        });
+
+        methods.add_method("set_master_volume", |_, this, volume: f32| {
+            this.audio()?.set_master_volume(volume);
+            Ok(())
+        });
+
+        methods.add_method("load_sound", |_, this, path: String| this.load_sound(&path));
+
+        methods.add_method(
+            "load_sound_from_wave",
+            |_, this, wave: LuaUserDataRef<LuaWave>| this.load_sound_from_wave(&wave),
+        );
+
+        methods.add_method("load_music", |_, this, path: String| this.load_music(&path));
+
+        methods.add_method("load_wave", |_, this, path: String| {
+            this.audio()?;
+            load_wave(&path)
+        });
+
+        methods.add_method_mut("close", |_, this, ()| {
+            this.close();
+            Ok(())
+        });
+
+        methods.add_meta_method_mut(LuaMetaMethod::Close, |_, this, _: LuaValue| {
+            this.close();
+            Ok(())
+        });
+    }
+}
+
+/// Sound effect fully loaded into an audio buffer.
+pub struct LuaSound {
+    sound: Option<ffi::Sound>,
+    device: LivenessToken,
+}
+
+impl LuaSound {
+    fn new(sound: ffi::Sound, device: LivenessToken) -> Self {
+        LuaSound {
+            sound: Some(sound),
+            device,
+        }
+    }
+
+    /// Copy out the raw sound, failing if it can no longer be played.
+    fn sound(&self) -> LuaResult<ffi::Sound> {
+        match self.sound {
+            Some(_) if !self.device.is_alive() => Err(LuaError::runtime(
+                "Sound belongs to an audio device that has been closed",
+            )),
+            Some(sound) => Ok(sound),
+            None => Err(LuaError::runtime("Sound has already been unloaded")),
+        }
+    }
+
+    fn unload(&mut self) {
+        let Some(sound) = self.sound.take() else {
+            return;
+        };
+        // CloseAudioDevice does not free the buffer, but it destroys the lock
+        // UnloadSound takes, so after it the buffer is leaked instead
+        if self.device.is_alive() {
+            unsafe { ffi::UnloadSound(sound) };
+        }
+    }
+}
+
+impl Drop for LuaSound {
+    fn drop(&mut self) {
+        self.unload();
+    }
+}
+
+impl LuaUserData for LuaSound {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("frame_count", |_, this| Ok(this.sound()?.frameCount));
+    }
+
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("play", |_, this, ()| {
+            unsafe { ffi::PlaySound(this.sound()?) };
+            Ok(())
+        });
+
+        methods.add_method("stop", |_, this, ()| {
+            unsafe { ffi::StopSound(this.sound()?) };
+            Ok(())
+        });
+
+        methods.add_method("pause", |_, this, ()| {
+            unsafe { ffi::PauseSound(this.sound()?) };
+            Ok(())
+        });
+
+        methods.add_method("resume", |_, this, ()| {
+            unsafe { ffi::ResumeSound(this.sound()?) };
+            Ok(())
+        });
+
+        methods.add_method("is_playing", |_, this, ()| {
+            Ok(unsafe { ffi::IsSoundPlaying(this.sound()?) })
+        });
+
+        methods.add_method("set_volume", |_, this, volume: f32| {
+            unsafe { ffi::SetSoundVolume(this.sound()?, volume) };
+            Ok(())
+        });
+
+        methods.add_method("set_pitch", |_, this, pitch: f32| {
+            unsafe { ffi::SetSoundPitch(this.sound()?, pitch) };
+            Ok(())
+        });
+
+        methods.add_method("set_pan", |_, this, pan: f32| {
+            unsafe { ffi::SetSoundPan(this.sound()?, pan) };
+            Ok(())
+        });
+
+        methods.add_method("is_valid", |_, this, ()| Ok(this.sound().is_ok()));
+
+        methods.add_method_mut("unload", |_, this, ()| {
+            this.unload();
+            Ok(())
+        });
+
+        methods.add_meta_method_mut(LuaMetaMethod::Close, |_, this, _: LuaValue| {
+            this.unload();
+            Ok(())
+        });
+    }
+}
+
+/// Music stream decoded on the fly; `update()` must be called every frame.
+pub struct LuaMusic {
+    music: Option<ffi::Music>,
+    device: LivenessToken,
+}
+
+impl LuaMusic {
+    fn new(music: ffi::Music, device: LivenessToken) -> Self {
+        LuaMusic {
+            music: Some(music),
+            device,
+        }
+    }
+
+    /// Copy out the raw stream, failing if it can no longer be played.
+    fn music(&self) -> LuaResult<ffi::Music> {
+        match self.music {
+            Some(_) if !self.device.is_alive() => Err(LuaError::runtime(
+                "Music belongs to an audio device that has been closed",
+            )),
+            Some(music) => Ok(music),
+            None => Err(LuaError::runtime("Music has already been unloaded")),
+        }
+    }
+
+    fn unload(&mut self) {
+        let Some(music) = self.music.take() else {
+            return;
+        };
+        // As for sounds, the stream buffer outlives the device but can no
+        // longer be unloaded safely, so it is leaked
+        if self.device.is_alive() {
+            unsafe { ffi::UnloadMusicStream(music) };
+        }
+    }
+}
+
+impl Drop for LuaMusic {
+    fn drop(&mut self) {
+        self.unload();
+    }
+}
+
+impl LuaUserData for LuaMusic {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("frame_count", |_, this| Ok(this.music()?.frameCount));
+        fields.add_field_method_get("looping", |_, this| Ok(this.music()?.looping));
+        fields.add_field_method_set("looping", |_, this, looping: bool| {
+            this.music()?;
+            if let Some(music) = this.music.as_mut() {
+                music.looping = looping;
+            }
+            Ok(())
+        });
+    }
+
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("play", |_, this, ()| {
+            unsafe { ffi::PlayMusicStream(this.music()?) };
+            Ok(())
+        });
+
+        methods.add_method("update", |_, this, ()| {
+            unsafe { ffi::UpdateMusicStream(this.music()?) };
+            Ok(())
+        });
+
+        methods.add_method("stop", |_, this, ()| {
+            unsafe { ffi::StopMusicStream(this.music()?) };
+            Ok(())
+        });
+
+        methods.add_method("pause", |_, this, ()| {
+            unsafe { ffi::PauseMusicStream(this.music()?) };
+            Ok(())
+        });
+
+        methods.add_method("resume", |_, this, ()| {
+            unsafe { ffi::ResumeMusicStream(this.music()?) };
+            Ok(())
+        });
+
+        methods.add_method("is_playing", |_, this, ()| {
+            Ok(unsafe { ffi::IsMusicStreamPlaying(this.music()?) })
+        });
+
+        methods.add_method("seek", |_, this, position: f32| {
+            unsafe { ffi::SeekMusicStream(this.music()?, position) };
+            Ok(())
+        });
+
+        methods.add_method("set_volume", |_, this, volume: f32| {
+            unsafe { ffi::SetMusicVolume(this.music()?, volume) };
+            Ok(())
+        });
+
+        methods.add_method("set_pitch", |_, this, pitch: f32| {
+            unsafe { ffi::SetMusicPitch(this.music()?, pitch) };
+            Ok(())
+        });
+
+        methods.add_method("set_pan", |_, this, pan: f32| {
+            unsafe { ffi::SetMusicPan(this.music()?, pan) };
+            Ok(())
+        });
+
+        methods.add_method("get_time_length", |_, this, ()| {
+            Ok(unsafe { ffi::GetMusicTimeLength(this.music()?) })
+        });
+
+        methods.add_method("get_time_played", |_, this, ()| {
+            Ok(unsafe { ffi::GetMusicTimePlayed(this.music()?) })
+        });
+
+        methods.add_method("is_valid", |_, this, ()| Ok(this.music().is_ok()));
+
+        methods.add_method_mut("unload", |_, this, ()| {
+            this.unload();
+            Ok(())
+        });
+
+        methods.add_meta_method_mut(LuaMetaMethod::Close, |_, this, _: LuaValue| {
+            this.unload();
+            Ok(())
+        });
+    }
+}
+
+/// Raw wave samples in CPU memory.
+pub struct LuaWave {
+    wave: Option<ffi::Wave>,
+}
+
+impl LuaWave {
+    fn wave(&self) -> LuaResult<&ffi::Wave> {
+        self.wave
+            .as_ref()
+            .ok_or_else(|| LuaError::runtime("Wave has already been unloaded"))
+    }
+
+    fn unload(&mut self) {
+        if let Some(wave) = self.wave.take() {
+            unsafe { ffi::UnloadWave(wave) };
+        }
+    }
+}
+
+impl Drop for LuaWave {
+    fn drop(&mut self) {
+        self.unload();
+    }
+}
+
+impl LuaUserData for LuaWave {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("frame_count", |_, this| Ok(this.wave()?.frameCount));
+        fields.add_field_method_get("sample_rate", |_, this| Ok(this.wave()?.sampleRate));
+        fields.add_field_method_get("sample_size", |_, this| Ok(this.wave()?.sampleSize));
+        fields.add_field_method_get("channels", |_, this| Ok(this.wave()?.channels));
+    }
+
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("copy", |_, this, ()| {
+            let wave = unsafe { ffi::WaveCopy(*this.wave()?) };
+            Ok(LuaWave { wave: Some(wave) })
+        });
+
+        methods.add_method_mut("crop", |_, this, (init_frame, final_frame): (i32, i32)| {
+            let frames = this.wave()?.frameCount as i32;
+            if init_frame < 0 || final_frame > frames || init_frame >= final_frame {
+                return Err(LuaError::runtime(format!(
+                    "Invalid crop range {}..{} for a wave of {} frames",
+                    init_frame, final_frame, frames
+                )));
+            }
+            if let Some(wave) = this.wave.as_mut() {
+                unsafe { ffi::WaveCrop(wave, init_frame, final_frame) };
+            }
+            Ok(())
+        });
+
+        methods.add_method_mut(
+            "format",
+            |_, this, (sample_rate, sample_size, channels): (i32, i32, i32)| {
+                this.wave()?;
+                if let Some(wave) = this.wave.as_mut() {
+                    unsafe { ffi::WaveFormat(wave, sample_rate, sample_size, channels) };
+                }
+                Ok(())
+            },
+        );
+
+        methods.add_method("export", |_, this, path: String| {
+            let c_path = c_path(&path)?;
+            if !unsafe { ffi::ExportWave(*this.wave()?, c_path.as_ptr()) } {
+                return Err(LuaError::runtime(format!(
+                    "Failed to export wave to '{}'",
+                    path
+                )));
+            }
+            Ok(())
+        });
+
+        methods.add_method_mut("unload", |_, this, ()| {
+            this.unload();
+            Ok(())
+        });
+
+        methods.add_meta_method_mut(LuaMetaMethod::Close, |_, this, _: LuaValue| {
+            this.unload();
+            Ok(())
+        });
+    }
+}
+
+fn load_wave(path: &str) -> LuaResult<LuaWave> {
+    let c_path = c_path(path)?;
+    let wave = unsafe { ffi::LoadWave(c_path.as_ptr()) };
+    if wave.data.is_null() {
+        return Err(LuaError::runtime(format!("Failed to load wave '{}'", path)));
+    }
+    Ok(LuaWave { wave: Some(wave) })
+}
+
+/// Registry table holding the open device weakly, so a hot reload can hand it
+/// back without keeping it from being collected.
+const AUDIO_DEVICE: &str = "rlmlua.AudioDevice";
+
+fn device_slot(lua: &Lua) -> LuaResult<LuaTable> {
+    if let Some(slot) = lua.named_registry_value::<Option<LuaTable>>(AUDIO_DEVICE)? {
+        return Ok(slot);
+    }
+    let slot = lua.create_table()?;
+    let metatable = lua.create_table()?;
+    metatable.set("__mode", "v")?;
+    slot.set_metatable(Some(metatable))?;
+    lua.set_named_registry_value(AUDIO_DEVICE, &slot)?;
+    Ok(slot)
+}
+
+/// `rl.init_audio_device()`. While a hot reload re-executes the main script
+/// the device it already opened is returned, like `init_window` does.
+fn init_audio_device(lua: &Lua, _: ()) -> LuaResult<LuaAnyUserData> {
+    let slot = device_slot(lua)?;
+    if crate::hot_reload::is_reloading(lua)
+        && let Some(device) = slot.get::<Option<LuaAnyUserData>>(1)?
+        && device.borrow::<LuaAudioDevice>()?.alive.is_alive()
+    {
+        return Ok(device);
+    }
+    let audio = RaylibAudio::init_audio_device()
+        .map_err(|_| LuaError::runtime("Audio device is already initialized"))?;
+    if !audio.is_audio_device_ready() {
+        return Err(LuaError::runtime("Failed to initialize audio device"));
+    }
+    let device = lua.create_userdata(LuaAudioDevice {
+        audio: Some(audio),
+        alive: LivenessToken::new(),
+    })?;
+    slot.set(1, &device)?;
+    Ok(device)
+}
+
+/// Register audio device functions
+pub fn register_audio_functions(lua: &Lua, exports: &LuaTable) -> LuaResult<()> {
+    exports.set("init_audio_device", lua.create_function(init_audio_device)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Closed devices never reach raylib, so these never open a real one
+    fn closed_device() -> LuaAudioDevice {
+        let mut device = LuaAudioDevice {
+            audio: None,
+            alive: LivenessToken::new(),
+        };
+        device.close();
+        device
+    }
+
+    #[test]
+    fn closed_devices_refuse_to_load() {
+        let device = closed_device();
+        assert!(!device.alive.is_alive());
+        let err = device.load_sound("missing.wav").err().unwrap();
+        assert!(err.to_string().contains("Audio device has been closed"));
+    }
+
+    #[test]
+    fn closing_the_device_invalidates_sounds_and_music() {
+        let token = LivenessToken::new();
+        let mut sound = LuaSound::new(unsafe { std::mem::zeroed() }, token.clone());
+        let mut music = LuaMusic::new(unsafe { std::mem::zeroed() }, token.clone());
+        assert!(sound.sound().is_ok());
+        assert!(music.music().is_ok());
+
+        token.invalidate();
+        let err = sound.sound().err().unwrap();
+        assert!(
+            err.to_string()
+                .contains("audio device that has been closed")
+        );
+        let err = music.music().err().unwrap();
+        assert!(
+            err.to_string()
+                .contains("audio device that has been closed")
+        );
+
+        // Leaked rather than unloaded, then reported as unloaded
+        sound.unload();
+        music.unload();
+        let err = sound.sound().err().unwrap();
+        assert!(err.to_string().contains("Sound has already been unloaded"));
+        let err = music.music().err().unwrap();
+        assert!(err.to_string().contains("Music has already been unloaded"));
+    }
+}
@@ -0,0 +1,403 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::cell::Cell;
+
+use crate::frame::with_scoped_mode;
+use crate::{LuaRaylib, LuaVector2, LuaVector3, in_frame};
+
+/// Camera mode currently applied to the frame in progress.
+#[derive(Clone, Copy, PartialEq)]
+enum CameraMode {
+    Mode2D,
+    Mode3D,
+}
+
+impl CameraMode {
+    fn name(self) -> &'static str {
+        match self {
+            CameraMode::Mode2D => "2D",
+            CameraMode::Mode3D => "3D",
+        }
+    }
+}
+
+thread_local! {
+    static CAMERA_MODE: Cell<Option<CameraMode>> = const { Cell::new(None) };
+}
+
+/// 2D camera with mutable offset, target, rotation and zoom.
+#[derive(Clone, Copy)]
+pub struct LuaCamera2D {
+    pub offset: LuaVector2,
+    pub target: LuaVector2,
+    pub rotation: f32,
+    pub zoom: f32,
+}
+
+impl From<LuaCamera2D> for ffi::Camera2D {
+    fn from(value: LuaCamera2D) -> Self {
+        ffi::Camera2D {
+            offset: value.offset.into(),
+            target: value.target.into(),
+            rotation: value.rotation,
+            zoom: value.zoom,
+        }
+    }
+}
+
+impl LuaUserData for LuaCamera2D {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("offset", |_, this| Ok(this.offset));
+        fields.add_field_method_set("offset", |_, this, val: LuaVector2| {
+            this.offset = val;
+            Ok(())
+        });
+        fields.add_field_method_get("target", |_, this| Ok(this.target));
+        fields.add_field_method_set("target", |_, this, val: LuaVector2| {
+            this.target = val;
+            Ok(())
+        });
+        fields.add_field_method_get("rotation", |_, this| Ok(this.rotation));
+        fields.add_field_method_set("rotation", |_, this, val: f32| {
+            this.rotation = val;
+            Ok(())
+        });
+        fields.add_field_method_get("zoom", |_, this| Ok(this.zoom));
+        fields.add_field_method_set("zoom", |_, this, val: f32| {
+            this.zoom = val;
+            Ok(())
+        });
+    }
+}
+
+/// Create a 2D camera, every argument is optional
+pub fn camera2d(
+    _lua: &Lua,
+    (offset, target, rotation, zoom): (
+        Option<LuaVector2>,
+        Option<LuaVector2>,
+        Option<f32>,
+        Option<f32>,
+    ),
+) -> LuaResult<LuaCamera2D> {
+    let origin = LuaVector2 { x: 0.0, y: 0.0 };
+    Ok(LuaCamera2D {
+        offset: offset.unwrap_or(origin),
+        target: target.unwrap_or(origin),
+        rotation: rotation.unwrap_or(0.0),
+        zoom: zoom.unwrap_or(1.0),
+    })
+}
+
+fn begin_camera_mode(mode: CameraMode, func: &str) -> LuaResult<()> {
+    if !in_frame() {
+        return Err(LuaError::runtime(format!(
+            "{} must be called between begin_drawing and end_drawing",
+            func
+        )));
+    }
+    if let Some(active) = CAMERA_MODE.get() {
+        return Err(LuaError::runtime(format!(
+            "{} called while {} mode is already active",
+            func,
+            active.name()
+        )));
+    }
+    CAMERA_MODE.set(Some(mode));
+    Ok(())
+}
+
+fn end_camera_mode(mode: CameraMode, func: &str) -> LuaResult<()> {
+    if CAMERA_MODE.get() != Some(mode) {
+        return Err(LuaError::runtime(format!(
+            "{} called without a matching begin_mode_{}",
+            func,
+            mode.name().to_lowercase()
+        )));
+    }
+    CAMERA_MODE.set(None);
+    Ok(())
+}
+
+fn begin_mode_2d(camera: LuaCamera2D) -> LuaResult<()> {
+    begin_camera_mode(CameraMode::Mode2D, "begin_mode_2d")?;
+    unsafe { ffi::BeginMode2D(camera.into()) };
+    Ok(())
+}
+
+fn end_mode_2d() -> LuaResult<()> {
+    end_camera_mode(CameraMode::Mode2D, "end_mode_2d")?;
+    unsafe { ffi::EndMode2D() };
+    Ok(())
+}
+
+fn begin_mode_3d(camera: LuaCamera3D) -> LuaResult<()> {
+    begin_camera_mode(CameraMode::Mode3D, "begin_mode_3d")?;
+    unsafe { ffi::BeginMode3D(camera.into()) };
+    Ok(())
+}
+
+fn end_mode_3d() -> LuaResult<()> {
+    end_camera_mode(CameraMode::Mode3D, "end_mode_3d")?;
+    unsafe { ffi::EndMode3D() };
+    Ok(())
+}
+
+/// Name of the camera mode applied to the frame in progress, if any.
+pub fn active_camera_mode() -> Option<&'static str> {
+    CAMERA_MODE.get().map(CameraMode::name)
+}
+
+/// Leave the active camera mode if the frame ends while it is still applied.
+pub fn reset_camera_mode() {
+    match CAMERA_MODE.take() {
+        Some(CameraMode::Mode2D) => unsafe { ffi::EndMode2D() },
+        Some(CameraMode::Mode3D) => unsafe { ffi::EndMode3D() },
+        None => {}
+    }
+}
+
+/// Apply or leave 2D mode without raylib, for tests of code that checks the
+/// active camera mode.
+#[cfg(test)]
+pub fn set_test_camera_mode(active: bool) {
+    CAMERA_MODE.set(active.then_some(CameraMode::Mode2D));
+}
+
+/// 3D camera with mutable position, target, up vector, field of view and
+/// projection.
+#[derive(Clone, Copy)]
+pub struct LuaCamera3D {
+    pub position: LuaVector3,
+    pub target: LuaVector3,
+    pub up: LuaVector3,
+    pub fovy: f32,
+    pub projection: CameraProjection,
+}
+
+impl From<LuaCamera3D> for ffi::Camera3D {
+    fn from(value: LuaCamera3D) -> Self {
+        ffi::Camera3D {
+            position: value.position.into(),
+            target: value.target.into(),
+            up: value.up.into(),
+            fovy: value.fovy,
+            projection: value.projection as i32,
+        }
+    }
+}
+
+fn str_to_projection(name: &str) -> LuaResult<CameraProjection> {
+    match name.to_lowercase().as_str() {
+        "perspective" => Ok(CameraProjection::CAMERA_PERSPECTIVE),
+        "orthographic" => Ok(CameraProjection::CAMERA_ORTHOGRAPHIC),
+        _ => Err(LuaError::runtime(format!(
+            "Unknown camera projection '{}', expected \"perspective\" or \"orthographic\"",
+            name
+        ))),
+    }
+}
+
+fn projection_to_str(projection: CameraProjection) -> &'static str {
+    match projection {
+        CameraProjection::CAMERA_PERSPECTIVE => "perspective",
+        CameraProjection::CAMERA_ORTHOGRAPHIC => "orthographic",
+    }
+}
+
+fn str_to_camera_mode(name: &str) -> LuaResult<ffi::CameraMode> {
+    match name.to_lowercase().as_str() {
+        "custom" => Ok(ffi::CameraMode::CAMERA_CUSTOM),
+        "free" => Ok(ffi::CameraMode::CAMERA_FREE),
+        "orbital" => Ok(ffi::CameraMode::CAMERA_ORBITAL),
+        "first_person" => Ok(ffi::CameraMode::CAMERA_FIRST_PERSON),
+        "third_person" => Ok(ffi::CameraMode::CAMERA_THIRD_PERSON),
+        _ => Err(LuaError::runtime(format!(
+            "Unknown camera mode '{}', expected one of custom, free, orbital, first_person, third_person",
+            name
+        ))),
+    }
+}
+
+impl LuaUserData for LuaCamera3D {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("position", |_, this| Ok(this.position));
+        fields.add_field_method_set("position", |_, this, val: LuaVector3| {
+            this.position = val;
+            Ok(())
+        });
+        fields.add_field_method_get("target", |_, this| Ok(this.target));
+        fields.add_field_method_set("target", |_, this, val: LuaVector3| {
+            this.target = val;
+            Ok(())
+        });
+        fields.add_field_method_get("up", |_, this| Ok(this.up));
+        fields.add_field_method_set("up", |_, this, val: LuaVector3| {
+            this.up = val;
+            Ok(())
+        });
+        fields.add_field_method_get("fovy", |_, this| Ok(this.fovy));
+        fields.add_field_method_set("fovy", |_, this, val: f32| {
+            this.fovy = val;
+            Ok(())
+        });
+        fields.add_field_method_get("projection", |_, this| {
+            Ok(projection_to_str(this.projection))
+        });
+        fields.add_field_method_set("projection", |_, this, val: String| {
+            this.projection = str_to_projection(&val)?;
+            Ok(())
+        });
+    }
+}
+
+/// Create a 3D camera, `up` defaults to +Y, `fovy` to 45 and the projection to
+/// perspective
+pub fn camera3d(
+    _lua: &Lua,
+    (position, target, up, fovy, projection): (
+        LuaVector3,
+        LuaVector3,
+        Option<LuaVector3>,
+        Option<f32>,
+        Option<String>,
+    ),
+) -> LuaResult<LuaCamera3D> {
+    let projection = match projection {
+        Some(name) => str_to_projection(&name)?,
+        None => CameraProjection::CAMERA_PERSPECTIVE,
+    };
+    Ok(LuaCamera3D {
+        position,
+        target,
+        up: up.unwrap_or(LuaVector3 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        }),
+        fovy: fovy.unwrap_or(45.0),
+        projection,
+    })
+}
+
+/// Camera methods on the window.
+pub fn add_camera_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    methods.add_method(
+        "begin_mode_2d",
+        |_, _this, camera: LuaUserDataRef<LuaCamera2D>| begin_mode_2d(*camera),
+    );
+
+    methods.add_method("end_mode_2d", |_, _this, ()| end_mode_2d());
+
+    methods.add_function(
+        "with_mode_2d",
+        |_, (window, camera, func): (LuaAnyUserData, LuaUserDataRef<LuaCamera2D>, LuaFunction)| {
+            let camera = *camera;
+            with_scoped_mode(window, func, || begin_mode_2d(camera), end_mode_2d)
+        },
+    );
+
+    methods.add_method(
+        "begin_mode_3d",
+        |_, _this, camera: LuaUserDataRef<LuaCamera3D>| begin_mode_3d(*camera),
+    );
+
+    methods.add_method("end_mode_3d", |_, _this, ()| end_mode_3d());
+
+    methods.add_function(
+        "with_mode_3d",
+        |_, (window, camera, func): (LuaAnyUserData, LuaUserDataRef<LuaCamera3D>, LuaFunction)| {
+            let camera = *camera;
+            with_scoped_mode(window, func, || begin_mode_3d(camera), end_mode_3d)
+        },
+    );
+
+    methods.add_method_mut(
+        "update_camera",
+        |_, _this, (mut camera, mode): (LuaUserDataRefMut<LuaCamera3D>, String)| {
+            let mode = str_to_camera_mode(&mode)?;
+            let mut raw: ffi::Camera3D = (*camera).into();
+            unsafe { ffi::UpdateCamera(&mut raw, mode as i32) };
+            camera.position = raw.position.into();
+            camera.target = raw.target.into();
+            camera.up = raw.up.into();
+            Ok(())
+        },
+    );
+
+    methods.add_method(
+        "get_screen_to_world_2d",
+        |_, this, (position, camera): (LuaVector2, LuaUserDataRef<LuaCamera2D>)| {
+            Ok(LuaVector2::from(
+                this.rl.get_screen_to_world2D(position, *camera),
+            ))
+        },
+    );
+
+    methods.add_method(
+        "get_world_to_screen_2d",
+        |_, this, (position, camera): (LuaVector2, LuaUserDataRef<LuaCamera2D>)| {
+            Ok(LuaVector2::from(
+                this.rl.get_world_to_screen2D(position, *camera),
+            ))
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::{abandon_frame, open_test_frame};
+
+    #[test]
+    fn ending_a_mode_needs_a_matching_begin() {
+        let err = end_camera_mode(CameraMode::Mode2D, "end_mode_2d").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("end_mode_2d called without a matching begin_mode_2d")
+        );
+
+        open_test_frame();
+        begin_camera_mode(CameraMode::Mode2D, "begin_mode_2d").unwrap();
+        let err = end_camera_mode(CameraMode::Mode3D, "end_mode_3d").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("end_mode_3d called without a matching begin_mode_3d")
+        );
+        assert_eq!(active_camera_mode(), Some("2D"));
+        end_camera_mode(CameraMode::Mode2D, "end_mode_2d").unwrap();
+        assert_eq!(active_camera_mode(), None);
+        abandon_frame();
+    }
+
+    #[test]
+    fn unknown_camera_modes_list_the_valid_names() {
+        assert!(str_to_camera_mode("Orbital").is_ok());
+        let err = str_to_camera_mode("orbit").unwrap_err();
+        assert!(err.to_string().contains(
+            "Unknown camera mode 'orbit', expected one of custom, free, orbital, first_person, third_person"
+        ));
+    }
+}
+
+// Tests needing a Lua state, which only links when Lua is built in:
+// `cargo test --no-default-features --features runner`
+#[cfg(all(test, not(feature = "module")))]
+mod lua_tests {
+    use super::*;
+
+    #[test]
+    fn camera2d_defaults_to_the_identity_view() {
+        let lua = Lua::new();
+        let camera = camera2d(&lua, (None, None, None, None)).unwrap();
+        assert_eq!((camera.offset.x, camera.offset.y), (0.0, 0.0));
+        assert_eq!((camera.target.x, camera.target.y), (0.0, 0.0));
+        assert_eq!(camera.rotation, 0.0);
+        assert_eq!(camera.zoom, 1.0);
+
+        let target = LuaVector2 { x: 5.0, y: 6.0 };
+        let camera = camera2d(&lua, (None, Some(target), None, Some(2.0))).unwrap();
+        assert_eq!((camera.target.x, camera.target.y), (5.0, 6.0));
+        assert_eq!(camera.zoom, 2.0);
+    }
+}
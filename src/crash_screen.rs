@@ -0,0 +1,150 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+
+use crate::draw::{draw_default_text, text_line};
+use crate::input::set_clipboard_text;
+
+const TITLE_SIZE: f32 = 30.0;
+const TEXT_SIZE: f32 = 20.0;
+const MARGIN: f32 = 20.0;
+const FOOTER: &str = "R: restart    C: copy to clipboard    Q: quit    Up/Down: scroll";
+
+/// What the player chose on the crash screen.
+pub enum CrashAction {
+    Restart,
+    Quit,
+}
+
+/// Full-window report of an error raised by an `rl.run` callback, showing the
+/// message and Lua traceback until the player restarts or quits.
+pub struct CrashScreen {
+    title: String,
+    lines: Vec<String>,
+    scroll: usize,
+    copied: bool,
+}
+
+impl CrashScreen {
+    pub fn new(phase: &str, err: &LuaError) -> Self {
+        // The default font has no tab glyph, and tracebacks indent with tabs
+        let details = err.to_string().replace('\t', "    ");
+        CrashScreen {
+            title: format!("Lua error in {}", phase),
+            lines: details.lines().map(str::to_string).collect(),
+            scroll: 0,
+            copied: false,
+        }
+    }
+
+    /// Error and traceback as copied to the clipboard.
+    fn report(&self) -> String {
+        format!("{}\n\n{}", self.title, self.lines.join("\n"))
+    }
+
+    /// Apply this frame's keys; returns the action once one is chosen.
+    pub fn update(&mut self) -> LuaResult<Option<CrashAction>> {
+        let pressed = |key: KeyboardKey| unsafe {
+            ffi::IsKeyPressed(key as i32) || ffi::IsKeyPressedRepeat(key as i32)
+        };
+        if pressed(KeyboardKey::KEY_R) {
+            return Ok(Some(CrashAction::Restart));
+        }
+        if pressed(KeyboardKey::KEY_Q) {
+            return Ok(Some(CrashAction::Quit));
+        }
+        if pressed(KeyboardKey::KEY_C) {
+            set_clipboard_text(&self.report().replace('\0', ""))?;
+            self.copied = true;
+        }
+
+        let wheel = unsafe { ffi::GetMouseWheelMove() };
+        if pressed(KeyboardKey::KEY_UP) || wheel > 0.0 {
+            self.scroll = self.scroll.saturating_sub(1);
+        }
+        if pressed(KeyboardKey::KEY_DOWN) || wheel < 0.0 {
+            self.scroll = (self.scroll + 1).min(self.lines.len().saturating_sub(1));
+        }
+        Ok(None)
+    }
+
+    /// Draw the report over the whole window.
+    pub fn draw(&self) {
+        let width = unsafe { ffi::GetScreenWidth() } as f32;
+        let height = unsafe { ffi::GetScreenHeight() } as f32;
+        let footer_top = height - TEXT_SIZE - MARGIN * 2.0;
+        unsafe { ffi::ClearBackground(Color::new(24, 24, 28, 255).into()) };
+
+        let title_position = ffi::Vector2 {
+            x: MARGIN,
+            y: MARGIN,
+        };
+        draw_default_text(
+            &text_line(&self.title),
+            title_position,
+            TITLE_SIZE,
+            Color::new(255, 96, 96, 255).into(),
+        );
+
+        let top = MARGIN * 2.0 + TITLE_SIZE;
+        for (i, line) in self.lines.iter().skip(self.scroll).enumerate() {
+            let y = top + TEXT_SIZE * i as f32;
+            if y + TEXT_SIZE > footer_top {
+                break;
+            }
+            let position = ffi::Vector2 { x: MARGIN, y };
+            draw_default_text(
+                &text_line(line),
+                position,
+                TEXT_SIZE,
+                Color::RAYWHITE.into(),
+            );
+        }
+
+        unsafe {
+            ffi::DrawRectangleRec(
+                ffi::Rectangle {
+                    x: 0.0,
+                    y: footer_top,
+                    width,
+                    height: height - footer_top,
+                },
+                Color::new(48, 48, 56, 255).into(),
+            )
+        };
+        let footer = if self.copied {
+            format!("{}    (copied)", FOOTER)
+        } else {
+            FOOTER.to_string()
+        };
+        let footer_position = ffi::Vector2 {
+            x: MARGIN,
+            y: footer_top + MARGIN,
+        };
+        draw_default_text(
+            &text_line(&footer),
+            footer_position,
+            TEXT_SIZE,
+            Color::LIGHTGRAY.into(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_keeps_the_traceback_without_tabs() {
+        let err = LuaError::runtime(
+            "main.lua:3: boom\nstack traceback:\n\t[C]: in function 'error'\n\tmain.lua:3: in function 'update'",
+        );
+        let screen = CrashScreen::new("update", &err);
+        assert_eq!(screen.lines.len(), 4);
+        assert_eq!(screen.lines[2], "    [C]: in function 'error'");
+        assert!(
+            screen
+                .report()
+                .starts_with("Lua error in update\n\nruntime error: main.lua:3: boom\n")
+        );
+    }
+}
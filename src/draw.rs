@@ -0,0 +1,326 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::ffi::{CStr, CString};
+
+use crate::shapes::LuaPoints;
+use crate::{LuaColor, LuaRaylib, LuaRectangle, LuaVector2, with_draw_handle};
+
+/// Arguments of a draw call, read left to right.
+///
+/// Positions and sizes may be given as two numbers or as one `Vector2` or
+/// `{x, y}` table, so `draw_circle(x, y, radius, color)` and
+/// `draw_circle(center, radius, color)` share one parser. Everything is read
+/// as floats so sub-pixel positions are kept.
+pub struct DrawArgs<'a> {
+    lua: &'a Lua,
+    function: &'static str,
+    args: LuaMultiValue,
+}
+
+impl<'a> DrawArgs<'a> {
+    pub fn new(lua: &'a Lua, function: &'static str, args: LuaMultiValue) -> Self {
+        DrawArgs {
+            lua,
+            function,
+            args,
+        }
+    }
+
+    fn next(&mut self) -> LuaValue {
+        self.args.pop_front().unwrap_or(LuaValue::Nil)
+    }
+
+    fn error(&self, name: &str, expected: &str) -> LuaError {
+        LuaError::runtime(format!("{}: {} must be {}", self.function, name, expected))
+    }
+
+    /// Read any other argument type.
+    pub fn value<T: FromLua>(&mut self, name: &str, expected: &str) -> LuaResult<T> {
+        let value = self.next();
+        T::from_lua(value, self.lua).map_err(|_| self.error(name, expected))
+    }
+
+    pub fn number(&mut self, name: &str) -> LuaResult<f32> {
+        self.value(name, "a number")
+    }
+
+    pub fn color(&mut self, name: &str) -> LuaResult<ffi::Color> {
+        Ok(self.value::<LuaColor>(name, "a color")?.into())
+    }
+
+    /// Read a position or size, from two numbers or from a single vector.
+    pub fn vector(&mut self, name: &str) -> LuaResult<ffi::Vector2> {
+        match self.args.front() {
+            Some(LuaValue::Integer(_) | LuaValue::Number(_)) => {
+                let x = self.number(name)?;
+                let y = self.number(name)?;
+                Ok(ffi::Vector2 { x, y })
+            }
+            _ => Ok(self
+                .value::<LuaVector2>(name, "a Vector2, an {x, y} table or two numbers")?
+                .into()),
+        }
+    }
+
+    /// Read an array of points, keeping the error that names a bad point.
+    pub fn points(&mut self, name: &str) -> LuaResult<LuaPoints> {
+        match self.next() {
+            value @ LuaValue::Table(_) => LuaPoints::from_lua(value, self.lua),
+            _ => Err(self.error(name, "an array of Vector2 or {x, y} tables")),
+        }
+    }
+
+    /// Read a rectangle, from a position and size or from a single rectangle.
+    pub fn rectangle(&mut self, name: &str) -> LuaResult<ffi::Rectangle> {
+        match self.args.front() {
+            Some(LuaValue::Table(table)) if table.contains_key("width")? => {
+                Ok(self.value::<LuaRectangle>(name, "a rectangle")?.into())
+            }
+            Some(LuaValue::UserData(ud)) if ud.is::<LuaRectangle>() => {
+                Ok(self.value::<LuaRectangle>(name, "a rectangle")?.into())
+            }
+            _ => {
+                let position = self.vector(name)?;
+                let size = self.vector(name)?;
+                Ok(ffi::Rectangle {
+                    x: position.x,
+                    y: position.y,
+                    width: size.x,
+                    height: size.y,
+                })
+            }
+        }
+    }
+}
+
+/// Font size and spacing raylib's `DrawText` uses for the default font.
+fn default_text_metrics(size: f32) -> (f32, f32) {
+    const DEFAULT_FONT_SIZE: f32 = 10.0;
+    let size = size.max(DEFAULT_FONT_SIZE);
+    (size, (size / DEFAULT_FONT_SIZE).floor())
+}
+
+/// `line` as a C string for drawing, with any NUL bytes left out.
+pub fn text_line(line: &str) -> CString {
+    CString::new(line.replace('\0', "")).unwrap_or_default()
+}
+
+/// Draw `text` with the default font the way `draw_text` does.
+pub fn draw_default_text(text: &CStr, position: ffi::Vector2, size: f32, color: ffi::Color) {
+    let (size, spacing) = default_text_metrics(size);
+    unsafe {
+        ffi::DrawTextEx(
+            ffi::GetFontDefault(),
+            text.as_ptr(),
+            position,
+            size,
+            spacing,
+            color,
+        )
+    };
+}
+
+/// Basic drawing methods on the window: clearing, text, pixels, lines,
+/// rectangles, circles, triangles and rings.
+///
+/// Positions are floats and may be passed as numbers or vectors, see
+/// `DrawArgs`.
+pub fn add_draw_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    methods.add_method("clear_background", |_, _this, color: LuaColor| {
+        with_draw_handle("clear_background", |d| {
+            d.clear_background(Color::from(color))
+        })?;
+        Ok(())
+    });
+
+    methods.add_method("draw_text", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_text", args);
+        let text: String = args.value("text", "a string")?;
+        let position = args.vector("position")?;
+        let size = args.number("size")?;
+        let color = args.color("color")?;
+        let text = CString::new(text).map_err(|e| LuaError::runtime(e.to_string()))?;
+        with_draw_handle("draw_text", |_| {
+            draw_default_text(&text, position, size, color)
+        })?;
+        Ok(())
+    });
+
+    for name in ["draw_pixel", "draw_pixel_v"] {
+        methods.add_method(name, move |lua, _this, args: LuaMultiValue| {
+            let mut args = DrawArgs::new(lua, name, args);
+            let position = args.vector("position")?;
+            let color = args.color("color")?;
+            with_draw_handle(name, |_| unsafe { ffi::DrawPixelV(position, color) })?;
+            Ok(())
+        });
+    }
+
+    for name in ["draw_line", "draw_line_v"] {
+        methods.add_method(name, move |lua, _this, args: LuaMultiValue| {
+            let mut args = DrawArgs::new(lua, name, args);
+            let start = args.vector("start_pos")?;
+            let end = args.vector("end_pos")?;
+            let color = args.color("color")?;
+            with_draw_handle(name, |_| unsafe { ffi::DrawLineV(start, end, color) })?;
+            Ok(())
+        });
+    }
+
+    methods.add_method("draw_line_ex", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_line_ex", args);
+        let start = args.vector("start_pos")?;
+        let end = args.vector("end_pos")?;
+        let thick = args.number("thick")?;
+        let color = args.color("color")?;
+        with_draw_handle("draw_line_ex", |_| unsafe {
+            ffi::DrawLineEx(start, end, thick, color)
+        })?;
+        Ok(())
+    });
+
+    for name in ["draw_rectangle", "draw_rectangle_v", "draw_rectangle_rec"] {
+        methods.add_method(name, move |lua, _this, args: LuaMultiValue| {
+            let mut args = DrawArgs::new(lua, name, args);
+            let rect = args.rectangle("rect")?;
+            let color = args.color("color")?;
+            with_draw_handle(name, |_| unsafe { ffi::DrawRectangleRec(rect, color) })?;
+            Ok(())
+        });
+    }
+
+    methods.add_method("draw_rectangle_lines", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_rectangle_lines", args);
+        let rect = args.rectangle("rect")?;
+        let color = args.color("color")?;
+        with_draw_handle("draw_rectangle_lines", |_| unsafe {
+            ffi::DrawRectangleLinesEx(rect, 1.0, color)
+        })?;
+        Ok(())
+    });
+
+    for name in ["draw_circle", "draw_circle_v"] {
+        methods.add_method(name, move |lua, _this, args: LuaMultiValue| {
+            let mut args = DrawArgs::new(lua, name, args);
+            let center = args.vector("center")?;
+            let radius = args.number("radius")?;
+            let color = args.color("color")?;
+            with_draw_handle(name, |_| unsafe { ffi::DrawCircleV(center, radius, color) })?;
+            Ok(())
+        });
+    }
+
+    methods.add_method("draw_circle_lines", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_circle_lines", args);
+        let center = args.vector("center")?;
+        let radius = args.number("radius")?;
+        let color = args.color("color")?;
+        with_draw_handle("draw_circle_lines", |_| unsafe {
+            ffi::DrawCircleLinesV(center, radius, color)
+        })?;
+        Ok(())
+    });
+
+    methods.add_method("draw_triangle", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_triangle", args);
+        let v1 = args.vector("v1")?;
+        let v2 = args.vector("v2")?;
+        let v3 = args.vector("v3")?;
+        let color = args.color("color")?;
+        with_draw_handle("draw_triangle", |_| unsafe {
+            ffi::DrawTriangle(v1, v2, v3, color)
+        })?;
+        Ok(())
+    });
+
+    methods.add_method("draw_ring", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_ring", args);
+        let center = args.vector("center")?;
+        let inner_radius = args.number("inner_radius")?;
+        let outer_radius = args.number("outer_radius")?;
+        let start_angle = args.number("start_angle")?;
+        let end_angle = args.number("end_angle")?;
+        let segments = args.value("segments", "an integer")?;
+        let color = args.color("color")?;
+        with_draw_handle("draw_ring", |_| unsafe {
+            ffi::DrawRing(
+                center,
+                inner_radius,
+                outer_radius,
+                start_angle,
+                end_angle,
+                segments,
+                color,
+            )
+        })?;
+        Ok(())
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_font_text_matches_raylib_draw_text() {
+        // DrawText clamps to the 10px default size and spaces by size / 10
+        assert_eq!(default_text_metrics(4.0), (10.0, 1.0));
+        assert_eq!(default_text_metrics(20.0), (20.0, 2.0));
+        assert_eq!(default_text_metrics(25.0), (25.0, 2.0));
+    }
+}
+
+// Tests needing a Lua state, which only links when Lua is built in:
+// `cargo test --no-default-features --features runner`
+#[cfg(all(test, not(feature = "module")))]
+mod lua_tests {
+    use super::*;
+
+    fn draw_args<'a>(lua: &'a Lua, chunk: &str) -> DrawArgs<'a> {
+        let args: LuaMultiValue = lua.load(chunk).eval().unwrap();
+        DrawArgs::new(lua, "draw_test", args)
+    }
+
+    #[test]
+    fn vectors_are_read_from_two_numbers_or_one_table() {
+        let lua = Lua::new();
+        let mut args = draw_args(&lua, "return 1.5, 2.25, {x = 3, y = 4.5}, 5");
+        let v = args.vector("a").unwrap();
+        assert_eq!((v.x, v.y), (1.5, 2.25));
+        let v = args.vector("b").unwrap();
+        assert_eq!((v.x, v.y), (3.0, 4.5));
+        assert_eq!(args.number("radius").unwrap(), 5.0);
+    }
+
+    #[test]
+    fn rectangles_are_read_from_numbers_vectors_or_one_table() {
+        let lua = Lua::new();
+        let mut args = draw_args(
+            &lua,
+            "return 1, 2, 3.5, 4, {x = 5, y = 6, width = 7, height = 8}, {x = 1, y = 2}, 3, 4",
+        );
+        let r = args.rectangle("a").unwrap();
+        assert_eq!((r.x, r.y, r.width, r.height), (1.0, 2.0, 3.5, 4.0));
+        let r = args.rectangle("b").unwrap();
+        assert_eq!((r.x, r.y, r.width, r.height), (5.0, 6.0, 7.0, 8.0));
+        let r = args.rectangle("c").unwrap();
+        assert_eq!((r.x, r.y, r.width, r.height), (1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn bad_arguments_name_the_function_and_argument() {
+        let lua = Lua::new();
+        let mut args = draw_args(&lua, "return 'left', 2");
+        let err = args.vector("position").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("draw_test: position must be a Vector2")
+        );
+        let mut args = draw_args(&lua, "return 1");
+        let err = args.vector("position").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("draw_test: position must be a number")
+        );
+    }
+}
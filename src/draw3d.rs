@@ -0,0 +1,113 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+
+use crate::{LuaColor, LuaRaylib, LuaVector2, LuaVector3, with_draw_handle};
+
+/// Run a raw 3D draw call, but only while a frame is being drawn.
+fn draw_3d<F: FnOnce()>(function: &str, f: F) -> LuaResult<()> {
+    with_draw_handle(function, |_| f())
+}
+
+/// 3D primitive drawing methods on the window.
+///
+/// These are meant to be called inside `begin_mode_3d`/`end_mode_3d`; like the
+/// 2D drawing methods they do nothing outside of a frame, or error in strict
+/// mode.
+pub fn add_draw3d_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    methods.add_method(
+        "draw_cube",
+        |_,
+         _this,
+         (position, width, height, length, color): (LuaVector3, f32, f32, f32, LuaColor)| {
+            draw_3d("draw_cube", || unsafe {
+                ffi::DrawCube(position.into(), width, height, length, color.into())
+            })?;
+            Ok(())
+        },
+    );
+
+    methods.add_method(
+        "draw_cube_wires",
+        |_,
+         _this,
+         (position, width, height, length, color): (LuaVector3, f32, f32, f32, LuaColor)| {
+            draw_3d("draw_cube_wires", || unsafe {
+                ffi::DrawCubeWires(position.into(), width, height, length, color.into())
+            })?;
+            Ok(())
+        },
+    );
+
+    methods.add_method(
+        "draw_sphere",
+        |_, _this, (center, radius, color): (LuaVector3, f32, LuaColor)| {
+            draw_3d("draw_sphere", || unsafe {
+                ffi::DrawSphere(center.into(), radius, color.into())
+            })?;
+            Ok(())
+        },
+    );
+
+    methods.add_method(
+        "draw_cylinder",
+        |_,
+         _this,
+         (position, radius_top, radius_bottom, height, slices, color): (
+            LuaVector3,
+            f32,
+            f32,
+            f32,
+            i32,
+            LuaColor,
+        )| {
+            draw_3d("draw_cylinder", || unsafe {
+                ffi::DrawCylinder(
+                    position.into(),
+                    radius_top,
+                    radius_bottom,
+                    height,
+                    slices,
+                    color.into(),
+                )
+            })?;
+            Ok(())
+        },
+    );
+
+    methods.add_method(
+        "draw_plane",
+        |_, _this, (center, size, color): (LuaVector3, LuaVector2, LuaColor)| {
+            draw_3d("draw_plane", || unsafe {
+                ffi::DrawPlane(center.into(), size.into(), color.into())
+            })?;
+            Ok(())
+        },
+    );
+
+    methods.add_method("draw_grid", |_, _this, (slices, spacing): (i32, f32)| {
+        draw_3d("draw_grid", || unsafe { ffi::DrawGrid(slices, spacing) })?;
+        Ok(())
+    });
+
+    methods.add_method(
+        "draw_line_3d",
+        |_, _this, (start, end, color): (LuaVector3, LuaVector3, LuaColor)| {
+            draw_3d("draw_line_3d", || unsafe {
+                ffi::DrawLine3D(start.into(), end.into(), color.into())
+            })?;
+            Ok(())
+        },
+    );
+
+    methods.add_method(
+        "draw_ray",
+        |_, _this, (position, direction, color): (LuaVector3, LuaVector3, LuaColor)| {
+            let ray = ffi::Ray {
+                position: position.into(),
+                direction: direction.into(),
+            };
+            draw_3d("draw_ray", || unsafe { ffi::DrawRay(ray, color.into()) })?;
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,297 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::cell::RefCell;
+
+use crate::frame::with_scoped_mode;
+use crate::{LuaRaylib, LuaRectangle, in_frame};
+
+/// Blend mode names accepted by `begin_blend_mode`.
+const BLEND_MODES: &[(&str, BlendMode)] = &[
+    ("alpha", BlendMode::BLEND_ALPHA),
+    ("additive", BlendMode::BLEND_ADDITIVE),
+    ("multiplied", BlendMode::BLEND_MULTIPLIED),
+    ("add_colors", BlendMode::BLEND_ADD_COLORS),
+    ("subtract_colors", BlendMode::BLEND_SUBTRACT_COLORS),
+    ("alpha_premultiply", BlendMode::BLEND_ALPHA_PREMULTIPLY),
+    ("custom", BlendMode::BLEND_CUSTOM),
+    ("custom_separate", BlendMode::BLEND_CUSTOM_SEPARATE),
+];
+
+thread_local! {
+    // Blend modes and scissor areas entered during the frame, innermost last
+    static BLEND_STACK: RefCell<Vec<BlendMode>> = const { RefCell::new(Vec::new()) };
+    static SCISSOR_STACK: RefCell<Vec<ScissorArea>> = const { RefCell::new(Vec::new()) };
+}
+
+fn str_to_blend_mode(name: &str) -> LuaResult<BlendMode> {
+    let lower = name.to_lowercase();
+    BLEND_MODES
+        .iter()
+        .find(|(n, _)| *n == lower)
+        .map(|(_, mode)| *mode)
+        .ok_or_else(|| {
+            LuaError::runtime(format!(
+                "Unknown blend mode '{}', expected one of: {}",
+                name,
+                BLEND_MODES
+                    .iter()
+                    .map(|(n, _)| *n)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        })
+}
+
+/// Screen area drawing is clipped to, in whole pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ScissorArea {
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+}
+
+impl ScissorArea {
+    /// The part of this area that lies inside `outer`, so nested scopes
+    /// never draw outside their parent.
+    fn clip_to(self, outer: ScissorArea) -> ScissorArea {
+        let x = self.x.max(outer.x);
+        let y = self.y.max(outer.y);
+        let right = (self.x + self.width).min(outer.x + outer.width);
+        let bottom = (self.y + self.height).min(outer.y + outer.height);
+        ScissorArea {
+            x,
+            y,
+            width: (right - x).max(0),
+            height: (bottom - y).max(0),
+        }
+    }
+
+    fn apply(self) {
+        unsafe { ffi::BeginScissorMode(self.x, self.y, self.width, self.height) };
+    }
+}
+
+impl From<LuaRectangle> for ScissorArea {
+    fn from(rect: LuaRectangle) -> Self {
+        ScissorArea {
+            x: rect.x as i32,
+            y: rect.y as i32,
+            width: rect.width as i32,
+            height: rect.height as i32,
+        }
+    }
+}
+
+/// Read a scissor area given as a rectangle or as `x, y, width, height`.
+fn parse_scissor_area(lua: &Lua, args: &mut LuaMultiValue) -> LuaResult<ScissorArea> {
+    match args.front() {
+        Some(LuaValue::Integer(_) | LuaValue::Number(_)) => {
+            let mut next = |name: &str| -> LuaResult<i32> {
+                let value = args.pop_front().unwrap_or(LuaValue::Nil);
+                i32::from_lua(value, lua).map_err(|_| {
+                    LuaError::runtime(format!("begin_scissor_mode: {} must be a number", name))
+                })
+            };
+            Ok(ScissorArea {
+                x: next("x")?,
+                y: next("y")?,
+                width: next("width")?,
+                height: next("height")?,
+            })
+        }
+        _ => {
+            let rect = args.pop_front().unwrap_or(LuaValue::Nil);
+            Ok(LuaRectangle::from_lua(rect, lua)?.into())
+        }
+    }
+}
+
+fn require_frame(func: &str) -> LuaResult<()> {
+    if !in_frame() {
+        return Err(LuaError::runtime(format!(
+            "{} must be called between begin_drawing and end_drawing",
+            func
+        )));
+    }
+    Ok(())
+}
+
+fn begin_blend_mode(mode: BlendMode) -> LuaResult<()> {
+    require_frame("begin_blend_mode")?;
+    BLEND_STACK.with(|stack| stack.borrow_mut().push(mode));
+    unsafe { ffi::BeginBlendMode(mode as i32) };
+    Ok(())
+}
+
+fn end_blend_mode() -> LuaResult<()> {
+    let previous = BLEND_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        stack.pop().map(|_| stack.last().copied())
+    });
+    match previous {
+        None => Err(LuaError::runtime(
+            "end_blend_mode called without a matching begin_blend_mode",
+        )),
+        Some(Some(mode)) => {
+            unsafe { ffi::BeginBlendMode(mode as i32) };
+            Ok(())
+        }
+        Some(None) => {
+            unsafe { ffi::EndBlendMode() };
+            Ok(())
+        }
+    }
+}
+
+fn begin_scissor_mode(area: ScissorArea) -> LuaResult<()> {
+    require_frame("begin_scissor_mode")?;
+    let area = SCISSOR_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let area = match stack.last() {
+            Some(outer) => area.clip_to(*outer),
+            None => area,
+        };
+        stack.push(area);
+        area
+    });
+    area.apply();
+    Ok(())
+}
+
+fn end_scissor_mode() -> LuaResult<()> {
+    let previous = SCISSOR_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        stack.pop().map(|_| stack.last().copied())
+    });
+    match previous {
+        None => Err(LuaError::runtime(
+            "end_scissor_mode called without a matching begin_scissor_mode",
+        )),
+        Some(Some(area)) => {
+            area.apply();
+            Ok(())
+        }
+        Some(None) => {
+            unsafe { ffi::EndScissorMode() };
+            Ok(())
+        }
+    }
+}
+
+/// Whether a blend mode entered with `begin_blend_mode` is still active.
+pub fn blend_mode_active() -> bool {
+    BLEND_STACK.with(|stack| !stack.borrow().is_empty())
+}
+
+/// Whether a scissor area entered with `begin_scissor_mode` is still active.
+pub fn scissor_mode_active() -> bool {
+    SCISSOR_STACK.with(|stack| !stack.borrow().is_empty())
+}
+
+/// Leave any blend and scissor modes still active when the frame ends.
+pub fn reset_draw_modes() {
+    if !BLEND_STACK.with(|stack| stack.take()).is_empty() {
+        unsafe { ffi::EndBlendMode() };
+    }
+    if !SCISSOR_STACK.with(|stack| stack.take()).is_empty() {
+        unsafe { ffi::EndScissorMode() };
+    }
+}
+
+/// Blend and scissor mode methods on the window.
+pub fn add_draw_mode_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    methods.add_method("begin_blend_mode", |_, _this, mode: String| {
+        begin_blend_mode(str_to_blend_mode(&mode)?)
+    });
+
+    methods.add_method("end_blend_mode", |_, _this, ()| end_blend_mode());
+
+    methods.add_function(
+        "with_blend_mode",
+        |_, (window, mode, func): (LuaAnyUserData, String, LuaFunction)| {
+            let mode = str_to_blend_mode(&mode)?;
+            with_scoped_mode(window, func, || begin_blend_mode(mode), end_blend_mode)
+        },
+    );
+
+    methods.add_method(
+        "set_blend_factors",
+        |_, _this, (src_factor, dst_factor, equation): (i32, i32, i32)| {
+            unsafe { ffi::rlSetBlendFactors(src_factor, dst_factor, equation) };
+            Ok(())
+        },
+    );
+
+    methods.add_method(
+        "begin_scissor_mode",
+        |lua, _this, mut args: LuaMultiValue| {
+            let area = parse_scissor_area(lua, &mut args)?;
+            begin_scissor_mode(area)
+        },
+    );
+
+    methods.add_method("end_scissor_mode", |_, _this, ()| end_scissor_mode());
+
+    // Takes the same area arguments as begin_scissor_mode, then the callback
+    methods.add_function("with_scissor_mode", |lua, mut args: LuaMultiValue| {
+        let window = LuaAnyUserData::from_lua(args.pop_front().unwrap_or(LuaValue::Nil), lua)?;
+        let func = match args.pop_back() {
+            Some(LuaValue::Function(func)) => func,
+            _ => {
+                return Err(LuaError::runtime(
+                    "with_scissor_mode: the last argument must be a function",
+                ));
+            }
+        };
+        let area = parse_scissor_area(lua, &mut args)?;
+        with_scoped_mode(window, func, || begin_scissor_mode(area), end_scissor_mode)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blend_mode_names_are_case_insensitive() {
+        assert_eq!(
+            str_to_blend_mode("Additive").unwrap(),
+            BlendMode::BLEND_ADDITIVE
+        );
+        assert!(str_to_blend_mode("screen").is_err());
+    }
+
+    #[test]
+    fn nested_scissor_areas_are_clipped_to_their_parent() {
+        let outer = ScissorArea {
+            x: 10,
+            y: 10,
+            width: 100,
+            height: 50,
+        };
+        let inner = ScissorArea {
+            x: 80,
+            y: 0,
+            width: 100,
+            height: 30,
+        };
+        assert_eq!(
+            inner.clip_to(outer),
+            ScissorArea {
+                x: 80,
+                y: 10,
+                width: 30,
+                height: 20,
+            }
+        );
+        let outside = ScissorArea {
+            x: 200,
+            y: 200,
+            width: 10,
+            height: 10,
+        };
+        assert_eq!(outside.clip_to(outer).width, 0);
+        assert_eq!(outside.clip_to(outer).height, 0);
+    }
+}
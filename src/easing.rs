@@ -0,0 +1,300 @@
+use mlua::prelude::*;
+use std::f32::consts::PI;
+
+/// Easing curves mapping progress in `[0, 1]` to eased progress.
+///
+/// These are Robert Penner's easing equations; `back` and `elastic` overshoot
+/// the `[0, 1]` range on purpose.
+pub type EaseFn = fn(f32) -> f32;
+
+const BACK_C1: f32 = 1.70158;
+const BACK_C2: f32 = BACK_C1 * 1.525;
+const BACK_C3: f32 = BACK_C1 + 1.0;
+const ELASTIC_C4: f32 = 2.0 * PI / 3.0;
+const ELASTIC_C5: f32 = 2.0 * PI / 4.5;
+
+pub fn linear(t: f32) -> f32 {
+    t
+}
+
+pub fn in_sine(t: f32) -> f32 {
+    1.0 - (t * PI / 2.0).cos()
+}
+
+pub fn out_sine(t: f32) -> f32 {
+    (t * PI / 2.0).sin()
+}
+
+pub fn in_out_sine(t: f32) -> f32 {
+    -((PI * t).cos() - 1.0) / 2.0
+}
+
+pub fn in_quad(t: f32) -> f32 {
+    t * t
+}
+
+pub fn out_quad(t: f32) -> f32 {
+    1.0 - (1.0 - t) * (1.0 - t)
+}
+
+pub fn in_out_quad(t: f32) -> f32 {
+    if t < 0.5 {
+        2.0 * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+    }
+}
+
+pub fn in_cubic(t: f32) -> f32 {
+    t * t * t
+}
+
+pub fn out_cubic(t: f32) -> f32 {
+    1.0 - (1.0 - t).powi(3)
+}
+
+pub fn in_out_cubic(t: f32) -> f32 {
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+    }
+}
+
+pub fn in_quart(t: f32) -> f32 {
+    t.powi(4)
+}
+
+pub fn out_quart(t: f32) -> f32 {
+    1.0 - (1.0 - t).powi(4)
+}
+
+pub fn in_out_quart(t: f32) -> f32 {
+    if t < 0.5 {
+        8.0 * t.powi(4)
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(4) / 2.0
+    }
+}
+
+pub fn in_quint(t: f32) -> f32 {
+    t.powi(5)
+}
+
+pub fn out_quint(t: f32) -> f32 {
+    1.0 - (1.0 - t).powi(5)
+}
+
+pub fn in_out_quint(t: f32) -> f32 {
+    if t < 0.5 {
+        16.0 * t.powi(5)
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(5) / 2.0
+    }
+}
+
+pub fn in_expo(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else {
+        2.0f32.powf(10.0 * t - 10.0)
+    }
+}
+
+pub fn out_expo(t: f32) -> f32 {
+    if t >= 1.0 {
+        1.0
+    } else {
+        1.0 - 2.0f32.powf(-10.0 * t)
+    }
+}
+
+pub fn in_out_expo(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else if t < 0.5 {
+        2.0f32.powf(20.0 * t - 10.0) / 2.0
+    } else {
+        (2.0 - 2.0f32.powf(-20.0 * t + 10.0)) / 2.0
+    }
+}
+
+pub fn in_circ(t: f32) -> f32 {
+    1.0 - (1.0 - t * t).max(0.0).sqrt()
+}
+
+pub fn out_circ(t: f32) -> f32 {
+    (1.0 - (t - 1.0).powi(2)).max(0.0).sqrt()
+}
+
+pub fn in_out_circ(t: f32) -> f32 {
+    if t < 0.5 {
+        (1.0 - (1.0 - (2.0 * t).powi(2)).max(0.0).sqrt()) / 2.0
+    } else {
+        ((1.0 - (-2.0 * t + 2.0).powi(2)).max(0.0).sqrt() + 1.0) / 2.0
+    }
+}
+
+pub fn in_back(t: f32) -> f32 {
+    BACK_C3 * t * t * t - BACK_C1 * t * t
+}
+
+pub fn out_back(t: f32) -> f32 {
+    1.0 + BACK_C3 * (t - 1.0).powi(3) + BACK_C1 * (t - 1.0).powi(2)
+}
+
+pub fn in_out_back(t: f32) -> f32 {
+    if t < 0.5 {
+        (2.0 * t).powi(2) * ((BACK_C2 + 1.0) * 2.0 * t - BACK_C2) / 2.0
+    } else {
+        ((2.0 * t - 2.0).powi(2) * ((BACK_C2 + 1.0) * (t * 2.0 - 2.0) + BACK_C2) + 2.0) / 2.0
+    }
+}
+
+pub fn in_elastic(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t.clamp(0.0, 1.0);
+    }
+    -(2.0f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * ELASTIC_C4).sin()
+}
+
+pub fn out_elastic(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t.clamp(0.0, 1.0);
+    }
+    2.0f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * ELASTIC_C4).sin() + 1.0
+}
+
+pub fn in_out_elastic(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t.clamp(0.0, 1.0);
+    }
+    let s = ((20.0 * t - 11.125) * ELASTIC_C5).sin();
+    if t < 0.5 {
+        -(2.0f32.powf(20.0 * t - 10.0) * s) / 2.0
+    } else {
+        2.0f32.powf(-20.0 * t + 10.0) * s / 2.0 + 1.0
+    }
+}
+
+pub fn out_bounce(t: f32) -> f32 {
+    let n1 = 7.5625;
+    let d1 = 2.75;
+    if t < 1.0 / d1 {
+        n1 * t * t
+    } else if t < 2.0 / d1 {
+        let t = t - 1.5 / d1;
+        n1 * t * t + 0.75
+    } else if t < 2.5 / d1 {
+        let t = t - 2.25 / d1;
+        n1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / d1;
+        n1 * t * t + 0.984375
+    }
+}
+
+pub fn in_bounce(t: f32) -> f32 {
+    1.0 - out_bounce(1.0 - t)
+}
+
+pub fn in_out_bounce(t: f32) -> f32 {
+    if t < 0.5 {
+        (1.0 - out_bounce(1.0 - 2.0 * t)) / 2.0
+    } else {
+        (1.0 + out_bounce(2.0 * t - 1.0)) / 2.0
+    }
+}
+
+/// Every easing curve under the name it is exported as.
+pub const EASINGS: &[(&str, EaseFn)] = &[
+    ("linear", linear),
+    ("in_sine", in_sine),
+    ("out_sine", out_sine),
+    ("in_out_sine", in_out_sine),
+    ("in_quad", in_quad),
+    ("out_quad", out_quad),
+    ("in_out_quad", in_out_quad),
+    ("in_cubic", in_cubic),
+    ("out_cubic", out_cubic),
+    ("in_out_cubic", in_out_cubic),
+    ("in_quart", in_quart),
+    ("out_quart", out_quart),
+    ("in_out_quart", in_out_quart),
+    ("in_quint", in_quint),
+    ("out_quint", out_quint),
+    ("in_out_quint", in_out_quint),
+    ("in_expo", in_expo),
+    ("out_expo", out_expo),
+    ("in_out_expo", in_out_expo),
+    ("in_circ", in_circ),
+    ("out_circ", out_circ),
+    ("in_out_circ", in_out_circ),
+    ("in_back", in_back),
+    ("out_back", out_back),
+    ("in_out_back", in_out_back),
+    ("in_elastic", in_elastic),
+    ("out_elastic", out_elastic),
+    ("in_out_elastic", in_out_elastic),
+    ("in_bounce", in_bounce),
+    ("out_bounce", out_bounce),
+    ("in_out_bounce", in_out_bounce),
+];
+
+/// Look up an easing curve by its exported name.
+pub fn find_easing(name: &str) -> Option<EaseFn> {
+    EASINGS.iter().find(|(n, _)| *n == name).map(|(_, f)| *f)
+}
+
+/// Build the `ease` table of easing functions
+pub fn create_ease_table(lua: &Lua) -> LuaResult<LuaTable> {
+    let ease = lua.create_table()?;
+    for (name, f) in EASINGS {
+        let f = *f;
+        ease.set(*name, lua.create_function(move |_, t: f32| Ok(f(t)))?)?;
+    }
+    Ok(ease)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_curve_starts_at_zero_and_ends_at_one() {
+        for (name, f) in EASINGS {
+            assert!(f(0.0).abs() < 1e-4, "{} starts at {}", name, f(0.0));
+            assert!((f(1.0) - 1.0).abs() < 1e-4, "{} ends at {}", name, f(1.0));
+        }
+    }
+
+    #[test]
+    fn in_out_curves_pass_through_the_midpoint() {
+        for (name, f) in EASINGS.iter().filter(|(n, _)| n.starts_with("in_out_")) {
+            assert!((f(0.5) - 0.5).abs() < 1e-4, "{} at 0.5 is {}", name, f(0.5));
+        }
+    }
+
+    #[test]
+    fn out_curves_mirror_in_curves() {
+        for (name, f_in) in EASINGS
+            .iter()
+            .filter(|(n, _)| n.starts_with("in_") && !n.starts_with("in_out_"))
+        {
+            let out_name = format!("out_{}", &name[3..]);
+            let f_out = find_easing(&out_name).unwrap();
+            for i in 0..=10 {
+                let t = i as f32 / 10.0;
+                assert!(
+                    (f_out(t) - (1.0 - f_in(1.0 - t))).abs() < 1e-4,
+                    "{} does not mirror {} at {}",
+                    out_name,
+                    name,
+                    t
+                );
+            }
+        }
+    }
+}
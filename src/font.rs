@@ -0,0 +1,346 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::ffi::CString;
+
+use crate::draw::DrawArgs;
+use crate::{LivenessToken, LuaRaylib, LuaVector2, current_window, with_draw_handle};
+
+fn c_text(text: &str) -> LuaResult<CString> {
+    CString::new(text).map_err(LuaError::external)
+}
+
+/// Font loaded into GPU memory, or raylib's built-in default font.
+///
+/// Like textures, fonts are unloaded when collected or closed and become
+/// invalid once their window is closed. The default font belongs to raylib and
+/// is never unloaded.
+pub struct LuaFont {
+    font: Option<ffi::Font>,
+    owned: bool,
+    window: LivenessToken,
+}
+
+impl LuaFont {
+    /// The underlying font, failing if it can no longer be used.
+    pub fn font(&self) -> LuaResult<ffi::Font> {
+        match self.font {
+            Some(_) if !self.window.is_alive() => Err(LuaError::runtime(
+                "Font belongs to a window that has been closed",
+            )),
+            Some(font) => Ok(font),
+            None => Err(LuaError::runtime("Font has already been unloaded")),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.font.is_some() && self.window.is_alive()
+    }
+
+    fn unload(&mut self) {
+        if let Some(font) = self.font.take()
+            && self.owned
+            && self.window.is_alive()
+        {
+            unsafe { ffi::UnloadFont(font) };
+        }
+    }
+}
+
+impl Drop for LuaFont {
+    fn drop(&mut self) {
+        self.unload();
+    }
+}
+
+impl LuaUserData for LuaFont {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("base_size", |_, this| Ok(this.font()?.baseSize));
+        fields.add_field_method_get("glyph_count", |_, this| Ok(this.font()?.glyphCount));
+        fields.add_field_method_get("glyph_padding", |_, this| Ok(this.font()?.glyphPadding));
+    }
+
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("is_valid", |_, this, ()| Ok(this.is_valid()));
+
+        methods.add_method_mut("unload", |_, this, ()| {
+            this.unload();
+            Ok(())
+        });
+
+        methods.add_meta_method_mut(LuaMetaMethod::Close, |_, this, _: LuaValue| {
+            this.unload();
+            Ok(())
+        });
+    }
+}
+
+/// Codepoints to load, given as a string of characters or a list of
+/// codepoints.
+fn parse_codepoints(codepoints: Option<LuaValue>) -> LuaResult<Option<Vec<i32>>> {
+    match codepoints {
+        None | Some(LuaValue::Nil) => Ok(None),
+        Some(LuaValue::String(s)) => Ok(Some(s.to_str()?.chars().map(|c| c as i32).collect())),
+        Some(LuaValue::Table(t)) => Ok(Some(
+            t.sequence_values::<LuaValue>()
+                .map(|value| parse_codepoint(value?))
+                .collect::<LuaResult<_>>()?,
+        )),
+        Some(other) => Err(LuaError::runtime(format!(
+            "codepoints must be a string or a list of integers, got {}",
+            other.type_name()
+        ))),
+    }
+}
+
+/// Check that `n` names a Unicode scalar value.
+fn valid_codepoint(n: i64) -> LuaResult<i32> {
+    u32::try_from(n)
+        .ok()
+        .and_then(char::from_u32)
+        .map(|c| c as i32)
+        .ok_or_else(|| LuaError::runtime(format!("{} is not a valid codepoint", n)))
+}
+
+/// A single codepoint given as a whole number or a one-character string.
+fn parse_codepoint(value: LuaValue) -> LuaResult<i32> {
+    match value {
+        LuaValue::Integer(i) => valid_codepoint(i),
+        LuaValue::Number(n) if n.fract() == 0.0 => valid_codepoint(n as i64),
+        LuaValue::Number(n) => Err(LuaError::runtime(format!(
+            "Expected a whole number codepoint, got {}",
+            n
+        ))),
+        LuaValue::String(s) => {
+            let s = s.to_str()?;
+            let mut chars = s.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(c as i32),
+                _ => Err(LuaError::runtime(format!(
+                    "Expected a single character, got '{}'",
+                    &*s
+                ))),
+            }
+        }
+        other => Err(LuaError::runtime(format!(
+            "Expected a codepoint or a single character, got {}",
+            other.type_name()
+        ))),
+    }
+}
+
+/// Wrap a freshly loaded font, failing if raylib could not load it.
+fn loaded_font(font: ffi::Font, source: &str, function: &str) -> LuaResult<LuaFont> {
+    if !unsafe { ffi::IsFontValid(font) } {
+        return Err(LuaError::runtime(format!(
+            "Failed to load font from {}",
+            source
+        )));
+    }
+    Ok(LuaFont {
+        font: Some(font),
+        owned: true,
+        window: current_window(function)?,
+    })
+}
+
+fn load_font(
+    _lua: &Lua,
+    (path, size, codepoints): (String, Option<i32>, Option<LuaValue>),
+) -> LuaResult<LuaFont> {
+    current_window("load_font")?;
+    let c_path = c_text(&path)?;
+    let mut codepoints = parse_codepoints(codepoints)?;
+    let (ptr, count) = match codepoints.as_mut() {
+        Some(cps) => (cps.as_mut_ptr(), cps.len() as i32),
+        None => (std::ptr::null_mut(), 0),
+    };
+    let font = unsafe { ffi::LoadFontEx(c_path.as_ptr(), size.unwrap_or(32), ptr, count) };
+    loaded_font(font, &format!("'{}'", path), "load_font")
+}
+
+fn load_font_from_memory(
+    _lua: &Lua,
+    (file_type, data, size, codepoints): (String, LuaString, Option<i32>, Option<LuaValue>),
+) -> LuaResult<LuaFont> {
+    current_window("load_font_from_memory")?;
+    let c_type = c_text(&file_type)?;
+    let data = data.as_bytes();
+    let mut codepoints = parse_codepoints(codepoints)?;
+    let (ptr, count) = match codepoints.as_mut() {
+        Some(cps) => (cps.as_mut_ptr(), cps.len() as i32),
+        None => (std::ptr::null_mut(), 0),
+    };
+    let font = unsafe {
+        ffi::LoadFontFromMemory(
+            c_type.as_ptr(),
+            data.as_ptr(),
+            data.len() as i32,
+            size.unwrap_or(32),
+            ptr,
+            count,
+        )
+    };
+    loaded_font(
+        font,
+        &format!("{} data", file_type),
+        "load_font_from_memory",
+    )
+}
+
+/// Register the module-level font loaders.
+pub fn register_font_functions(lua: &Lua, exports: &LuaTable) -> LuaResult<()> {
+    exports.set("load_font", lua.create_function(load_font)?)?;
+    exports.set(
+        "load_font_from_memory",
+        lua.create_function(load_font_from_memory)?,
+    )?;
+    Ok(())
+}
+
+/// Font access, text drawing and text measuring methods on the window.
+pub fn add_font_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    methods.add_method("get_font_default", |_, this, ()| {
+        Ok(LuaFont {
+            font: Some(unsafe { ffi::GetFontDefault() }),
+            owned: false,
+            window: this.alive.clone(),
+        })
+    });
+
+    methods.add_method("draw_text_ex", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_text_ex", args);
+        let font: LuaUserDataRef<LuaFont> = args.value("font", "a Font")?;
+        let text: String = args.value("text", "a string")?;
+        let position = args.vector("position")?;
+        let font_size = args.number("font_size")?;
+        let spacing = args.number("spacing")?;
+        let tint = args.color("tint")?;
+        let font = font.font()?;
+        let text = c_text(&text)?;
+        with_draw_handle("draw_text_ex", |_| unsafe {
+            ffi::DrawTextEx(font, text.as_ptr(), position, font_size, spacing, tint)
+        })?;
+        Ok(())
+    });
+
+    methods.add_method("draw_text_pro", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_text_pro", args);
+        let font: LuaUserDataRef<LuaFont> = args.value("font", "a Font")?;
+        let text: String = args.value("text", "a string")?;
+        let position = args.vector("position")?;
+        let origin = args.vector("origin")?;
+        let rotation = args.number("rotation")?;
+        let font_size = args.number("font_size")?;
+        let spacing = args.number("spacing")?;
+        let tint = args.color("tint")?;
+        let font = font.font()?;
+        let text = c_text(&text)?;
+        with_draw_handle("draw_text_pro", |_| unsafe {
+            ffi::DrawTextPro(
+                font,
+                text.as_ptr(),
+                position,
+                origin,
+                rotation,
+                font_size,
+                spacing,
+                tint,
+            )
+        })?;
+        Ok(())
+    });
+
+    methods.add_method("draw_text_codepoint", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_text_codepoint", args);
+        let font: LuaUserDataRef<LuaFont> = args.value("font", "a Font")?;
+        let codepoint = parse_codepoint(args.value("codepoint", "a codepoint")?)?;
+        let position = args.vector("position")?;
+        let font_size = args.number("font_size")?;
+        let tint = args.color("tint")?;
+        let font = font.font()?;
+        with_draw_handle("draw_text_codepoint", |_| unsafe {
+            ffi::DrawTextCodepoint(font, codepoint, position, font_size, tint)
+        })?;
+        Ok(())
+    });
+
+    methods.add_method(
+        "measure_text",
+        |_, _this, (text, font_size): (String, i32)| {
+            let text = c_text(&text)?;
+            Ok(unsafe { ffi::MeasureText(text.as_ptr(), font_size) })
+        },
+    );
+
+    methods.add_method(
+        "measure_text_ex",
+        |_,
+         _this,
+         (font, text, font_size, spacing): (LuaUserDataRef<LuaFont>, String, f32, f32)| {
+            let font = font.font()?;
+            let text = c_text(&text)?;
+            let size = unsafe { ffi::MeasureTextEx(font, text.as_ptr(), font_size, spacing) };
+            Ok(LuaVector2::from(size))
+        },
+    );
+
+    methods.add_method("set_text_line_spacing", |_, _this, spacing: i32| {
+        unsafe { ffi::SetTextLineSpacing(spacing) };
+        Ok(())
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codepoints_must_be_unicode_scalar_values() {
+        assert_eq!(valid_codepoint(65).unwrap(), 65);
+        assert_eq!(valid_codepoint(0x10FFFF).unwrap(), 0x10FFFF);
+        for invalid in [-1, 0xD800, 0x110000, 1 << 32] {
+            let err = valid_codepoint(invalid).unwrap_err();
+            assert!(err.to_string().contains("is not a valid codepoint"));
+        }
+    }
+}
+
+// Tests needing a Lua state, which only links when Lua is built in:
+// `cargo test --no-default-features --features runner`
+#[cfg(all(test, not(feature = "module")))]
+mod lua_tests {
+    use super::*;
+
+    #[test]
+    fn codepoints_accept_whole_numbers_and_characters() {
+        let lua = Lua::new();
+        let parse = |code: &str| parse_codepoint(lua.load(code).eval().unwrap());
+        assert_eq!(parse("return 65").unwrap(), 65);
+        assert_eq!(parse("return 65.0").unwrap(), 65);
+        assert_eq!(parse("return 'é'").unwrap(), 'é' as i32);
+        assert!(
+            parse("return 65.5")
+                .unwrap_err()
+                .to_string()
+                .contains("Expected a whole number codepoint, got 65.5")
+        );
+        assert!(
+            parse("return 2^32 + 65")
+                .unwrap_err()
+                .to_string()
+                .contains("4294967361 is not a valid codepoint")
+        );
+        assert!(parse("return 'ab'").is_err());
+        assert!(parse("return true").is_err());
+
+        let list = |code: &str| parse_codepoints(Some(lua.load(code).eval().unwrap()));
+        assert_eq!(list("return nil").unwrap(), None);
+        assert_eq!(list("return 'Aé'").unwrap(), Some(vec![65, 'é' as i32]));
+        assert_eq!(
+            list("return { 65, 66.0, 'C' }").unwrap(),
+            Some(vec![65, 66, 67])
+        );
+        assert!(list("return { 65, -1 }").is_err());
+        assert!(list("return 65").is_err());
+    }
+}
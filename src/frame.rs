@@ -0,0 +1,427 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::cell::{Cell, RefCell};
+
+use crate::LuaRaylib;
+use crate::camera::{active_camera_mode, reset_camera_mode};
+use crate::draw_modes::{blend_mode_active, reset_draw_modes, scissor_mode_active};
+use crate::render_texture::{reset_texture_mode, texture_mode_active};
+use crate::shader::{reset_shader_mode, shader_mode_active};
+
+thread_local! {
+    // Number of the frame in progress, if any
+    static FRAME: Cell<Option<u64>> = const { Cell::new(None) };
+    static FRAME_COUNT: Cell<u64> = const { Cell::new(0) };
+    // Where the frame in progress was opened, for strict mode errors
+    static FRAME_ORIGIN: RefCell<String> = const { RefCell::new(String::new()) };
+    static STRICT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Turn strict mode on or off. In strict mode drawing outside a frame,
+/// unbalanced `begin_drawing`/`end_drawing` and modes left active when the
+/// frame ends raise errors instead of being ignored.
+pub fn set_strict(strict: bool) {
+    STRICT.set(strict);
+}
+
+fn is_strict() -> bool {
+    STRICT.get()
+}
+
+/// `file:line` of the Lua code calling the running function.
+fn caller_location(lua: &Lua) -> String {
+    lua.inspect_stack(1, |debug| {
+        let file = debug.source().short_src.unwrap_or("?".into()).into_owned();
+        match debug.current_line() {
+            Some(line) => format!("{}:{}", file, line),
+            None => file,
+        }
+    })
+    .unwrap_or_else(|| "?".to_string())
+}
+
+fn frame_origin() -> String {
+    FRAME_ORIGIN.with(|origin| origin.borrow().clone())
+}
+
+/// Stand-in for raylib's draw handle while a frame is open. raylib tracks the
+/// frame itself, so this only gives access to the `RaylibDraw` methods. The
+/// private field keeps `with_draw_handle` the only place one is made.
+pub struct Frame(());
+
+impl RaylibDraw for Frame {}
+
+/// Whether a frame is open, between `begin_drawing` and `end_drawing` or
+/// inside `draw_frame`.
+pub fn in_frame() -> bool {
+    FRAME.get().is_some()
+}
+
+/// Run `f` with the frame in progress, if there is one. Outside a frame the
+/// draw call `function` is skipped, or is an error in strict mode.
+pub fn with_draw_handle<F: FnOnce(&mut Frame)>(function: &str, f: F) -> LuaResult<()> {
+    if in_frame() {
+        f(&mut Frame(()));
+    } else if is_strict() {
+        return Err(LuaError::runtime(format!(
+            "{} called outside a frame, draw between begin_drawing and end_drawing or inside draw_frame",
+            function
+        )));
+    }
+    Ok(())
+}
+
+/// Open a frame for `function`, or return the one already open. Opening a
+/// second frame is an error in strict mode.
+fn begin_frame(lua: &Lua, function: &str) -> LuaResult<u64> {
+    if let Some(frame) = FRAME.get() {
+        if is_strict() {
+            return Err(LuaError::runtime(format!(
+                "{} called while the frame opened at {} is still open, call end_drawing first",
+                function,
+                frame_origin()
+            )));
+        }
+        return Ok(frame);
+    }
+    let frame = FRAME_COUNT.get() + 1;
+    FRAME_COUNT.set(frame);
+    FRAME.set(Some(frame));
+    let origin = format!("{} ({})", caller_location(lua), function);
+    FRAME_ORIGIN.with(|cell| *cell.borrow_mut() = origin);
+    unsafe { ffi::BeginDrawing() };
+    Ok(frame)
+}
+
+/// Modes still active in the frame in progress.
+fn open_modes() -> Vec<String> {
+    let mut open = Vec::new();
+    if let Some(mode) = active_camera_mode() {
+        open.push(format!("{} mode", mode));
+    }
+    for (active, mode) in [
+        (texture_mode_active(), "texture mode"),
+        (shader_mode_active(), "shader mode"),
+        (blend_mode_active(), "blend mode"),
+        (scissor_mode_active(), "scissor mode"),
+    ] {
+        if active {
+            open.push(mode.to_string());
+        }
+    }
+    open
+}
+
+/// Leave every mode still active and close the frame in progress.
+fn close_frame() {
+    if FRAME.take().is_none() {
+        return;
+    }
+    reset_camera_mode();
+    reset_draw_modes();
+    reset_shader_mode();
+    reset_texture_mode();
+    // The window may have been closed while the frame was open
+    if unsafe { ffi::IsWindowReady() } {
+        unsafe { ffi::EndDrawing() };
+    }
+}
+
+/// Close a frame that a failed callback left open, so the error can be
+/// handled with a fresh one.
+pub fn abandon_frame() {
+    close_frame();
+}
+
+/// Mark a frame as open without raylib, for tests of code that has to
+/// close it.
+#[cfg(test)]
+pub fn open_test_frame() {
+    FRAME.set(Some(FRAME_COUNT.get() + 1));
+}
+
+/// Close the frame in progress for `function`. In strict mode, ending without
+/// a frame or with modes still active is reported once the frame is closed.
+fn end_frame(function: &str) -> LuaResult<()> {
+    if !is_strict() {
+        close_frame();
+        return Ok(());
+    }
+    if !in_frame() {
+        return Err(LuaError::runtime(format!(
+            "{} called without a matching begin_drawing",
+            function
+        )));
+    }
+    let open = open_modes();
+    close_frame();
+    if open.is_empty() {
+        return Ok(());
+    }
+    Err(LuaError::runtime(format!(
+        "{} called with {} still active, end every mode before the frame ends (frame opened at {})",
+        function,
+        open.join(", "),
+        frame_origin()
+    )))
+}
+
+/// Closes the frame when dropped, so a `draw_frame` callback that errors or
+/// panics cannot leave it open.
+struct FrameGuard;
+
+impl Drop for FrameGuard {
+    fn drop(&mut self) {
+        close_frame();
+    }
+}
+
+/// Open a frame, run `f` with its drawing context and close the frame again,
+/// even if `f` errors or panics.
+pub fn draw_frame<R>(
+    lua: &Lua,
+    window: LuaAnyUserData,
+    f: impl FnOnce(LuaDrawContext) -> LuaResult<R>,
+) -> LuaResult<R> {
+    if in_frame() {
+        return Err(LuaError::runtime(format!(
+            "draw_frame called while the frame opened at {} is still open, call end_drawing first",
+            frame_origin()
+        )));
+    }
+    let frame = begin_frame(lua, "draw_frame")?;
+    let _guard = FrameGuard;
+    let result = f(LuaDrawContext { frame, window });
+    let ended = end_frame("draw_frame");
+    let value = result?;
+    ended?;
+    Ok(value)
+}
+
+/// Open a mode with `begin`, call `func` with the window and leave the mode
+/// again with `end`, even if `func` errors.
+///
+/// `draw_frame` and the `with_*` window functions are registered as functions
+/// taking the window rather than as methods, so the window stays unborrowed
+/// while the callback draws. Anything `begin` captures is dropped before
+/// `func` runs, releasing borrows of the userdata it was given.
+pub fn with_scoped_mode(
+    window: LuaAnyUserData,
+    func: LuaFunction,
+    begin: impl FnOnce() -> LuaResult<()>,
+    end: impl FnOnce() -> LuaResult<()>,
+) -> LuaResult<()> {
+    begin()?;
+    let result = func.call::<()>(window);
+    let ended = end();
+    result?;
+    ended
+}
+
+/// Name of the registry table caching the methods forwarded by `DrawContext`.
+const CONTEXT_METHODS: &str = "rlmlua.draw_context_methods";
+
+/// Whether a window method is available on `DrawContext`.
+fn is_context_method(name: &str) -> bool {
+    let drawing = name == "clear_background"
+        || name.starts_with("draw_")
+        || name.starts_with("begin_")
+        || name.starts_with("end_")
+        || name.starts_with("with_");
+    drawing && !matches!(name, "draw_frame" | "begin_drawing" | "end_drawing")
+}
+
+/// Drawing surface of a single frame, passed to the `draw_frame` callback.
+///
+/// Offers the window's drawing and mode methods, and errors once the frame
+/// it belongs to has ended.
+pub struct LuaDrawContext {
+    frame: u64,
+    window: LuaAnyUserData,
+}
+
+impl LuaDrawContext {
+    fn is_valid(&self) -> bool {
+        FRAME.get() == Some(self.frame)
+    }
+
+    fn window(&self) -> LuaResult<&LuaAnyUserData> {
+        if !self.is_valid() {
+            return Err(LuaError::runtime(
+                "DrawContext used after its frame ended, draw inside the draw_frame callback",
+            ));
+        }
+        Ok(&self.window)
+    }
+}
+
+/// Function calling the window method `name` on the window of the context it
+/// is called on, created once per name.
+fn context_method(lua: &Lua, name: &str) -> LuaResult<LuaFunction> {
+    let cache = match lua.named_registry_value::<Option<LuaTable>>(CONTEXT_METHODS)? {
+        Some(cache) => cache,
+        None => {
+            let cache = lua.create_table()?;
+            lua.set_named_registry_value(CONTEXT_METHODS, &cache)?;
+            cache
+        }
+    };
+    if let Some(method) = cache.raw_get::<Option<LuaFunction>>(name)? {
+        return Ok(method);
+    }
+    let method_name = name.to_string();
+    let method = lua.create_function(
+        move |_, (context, mut args): (LuaUserDataRef<LuaDrawContext>, LuaMultiValue)| {
+            let window = context.window()?.clone();
+            // Release the borrow so scoped modes may pass the context along
+            drop(context);
+            let method: LuaFunction = window.get(method_name.as_str())?;
+            args.push_front(LuaValue::UserData(window));
+            method.call::<LuaMultiValue>(args)
+        },
+    )?;
+    cache.raw_set(name, &method)?;
+    Ok(method)
+}
+
+impl LuaUserData for LuaDrawContext {
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("is_valid", |_, this, ()| Ok(this.is_valid()));
+
+        methods.add_meta_function(
+            LuaMetaMethod::Index,
+            |lua, (context, key): (LuaUserDataRef<LuaDrawContext>, String)| {
+                if !is_context_method(&key) {
+                    return Ok(LuaValue::Nil);
+                }
+                if context.window.get::<LuaValue>(key.as_str())?.is_nil() {
+                    return Ok(LuaValue::Nil);
+                }
+                Ok(LuaValue::Function(context_method(lua, &key)?))
+            },
+        );
+    }
+}
+
+/// Frame methods on the window: `draw_frame` and `begin_drawing`/`end_drawing`.
+pub fn add_frame_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    methods.add_function(
+        "draw_frame",
+        |lua, (window, func): (LuaAnyUserData, LuaFunction)| {
+            draw_frame(lua, window, |context| func.call::<()>(context))
+        },
+    );
+
+    // Outside strict mode, calling it again before end_drawing keeps the frame
+    // already open
+    methods.add_function("begin_drawing", |lua, window: LuaAnyUserData| {
+        let frame = begin_frame(lua, "begin_drawing")?;
+        Ok(LuaDrawContext { frame, window })
+    });
+
+    methods.add_method("end_drawing", |_, _this, ()| end_frame("end_drawing"));
+}
+
+pub fn register_frame_functions(lua: &Lua, exports: &LuaTable) -> LuaResult<()> {
+    exports.set(
+        "set_strict",
+        lua.create_function(|_, strict: bool| {
+            set_strict(strict);
+            Ok(())
+        })?,
+    )?;
+    exports.set("is_strict", lua.create_function(|_, ()| Ok(is_strict()))?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strict_mode_reports_drawing_outside_a_frame() {
+        assert!(with_draw_handle("draw_rectangle", |_| {}).is_ok());
+        assert!(end_frame("end_drawing").is_ok());
+
+        set_strict(true);
+        let err = with_draw_handle("draw_rectangle", |_| {}).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("draw_rectangle called outside a frame")
+        );
+        let err = end_frame("end_drawing").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("end_drawing called without a matching begin_drawing")
+        );
+        set_strict(false);
+    }
+
+    #[test]
+    fn abandoning_closes_the_open_frame() {
+        open_test_frame();
+        assert!(in_frame());
+        abandon_frame();
+        assert!(!in_frame());
+        // Nothing left open, so nothing to close
+        abandon_frame();
+        assert!(!in_frame());
+    }
+
+    #[test]
+    fn context_offers_only_drawing_methods() {
+        assert!(is_context_method("clear_background"));
+        assert!(is_context_method("draw_rectangle"));
+        assert!(is_context_method("with_mode_2d"));
+        assert!(is_context_method("end_scissor_mode"));
+        assert!(!is_context_method("draw_frame"));
+        assert!(!is_context_method("begin_drawing"));
+        assert!(!is_context_method("should_close"));
+    }
+}
+
+// Tests needing a Lua state, which only links when Lua is built in:
+// `cargo test --no-default-features --features runner`
+#[cfg(all(test, not(feature = "module")))]
+mod lua_tests {
+    use super::*;
+
+    #[test]
+    fn scoped_modes_end_even_when_the_callback_fails() {
+        let lua = Lua::new();
+        let window = lua.create_any_userdata(()).unwrap();
+        let fail: LuaFunction = lua
+            .load("return function() error('boom') end")
+            .eval()
+            .unwrap();
+        let ended = Cell::new(false);
+
+        let err = with_scoped_mode(
+            window.clone(),
+            fail.clone(),
+            || Ok(()),
+            || {
+                ended.set(true);
+                Ok(())
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("boom"));
+        assert!(ended.get());
+
+        // A mode that fails to begin runs neither the callback nor end
+        ended.set(false);
+        let err = with_scoped_mode(
+            window,
+            fail,
+            || Err(LuaError::runtime("cannot begin")),
+            || {
+                ended.set(true);
+                Ok(())
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("cannot begin"));
+        assert!(!ended.get());
+    }
+}
@@ -0,0 +1,262 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::ffi::CString;
+
+use crate::LuaRaylib;
+use crate::input::{
+    GAMEPAD_AXES, GAMEPAD_BUTTONS, LuaGamepadAxis, LuaGamepadButton, gamepad_button_name,
+};
+
+/// Fake state for one gamepad, installed with `window:set_gamepad_override`.
+///
+/// Each call to `set_state` counts as a new frame, so a button is "pressed"
+/// when it is down now but was not down in the previous state.
+#[derive(Default)]
+struct GamepadOverride {
+    name: Option<String>,
+    down: [bool; GAMEPAD_BUTTONS.len()],
+    was_down: [bool; GAMEPAD_BUTTONS.len()],
+    axes: [f32; GAMEPAD_AXES.len()],
+}
+
+impl GamepadOverride {
+    fn set_state(
+        &mut self,
+        name: Option<String>,
+        down: [bool; GAMEPAD_BUTTONS.len()],
+        axes: [f32; GAMEPAD_AXES.len()],
+    ) {
+        self.name = name;
+        self.was_down = self.down;
+        self.down = down;
+        self.axes = axes;
+    }
+
+    fn is_down(&self, button: GamepadButton) -> bool {
+        self.down[button as usize]
+    }
+
+    fn is_pressed(&self, button: GamepadButton) -> bool {
+        self.down[button as usize] && !self.was_down[button as usize]
+    }
+
+    fn is_released(&self, button: GamepadButton) -> bool {
+        !self.down[button as usize] && self.was_down[button as usize]
+    }
+
+    fn axis(&self, axis: GamepadAxis) -> f32 {
+        self.axes[axis as usize]
+    }
+
+    /// A button that went down in the latest state, if any.
+    fn last_pressed(&self) -> Option<GamepadButton> {
+        GAMEPAD_BUTTONS
+            .iter()
+            .rev()
+            .map(|(_, button)| *button)
+            .find(|button| self.is_pressed(*button))
+    }
+}
+
+thread_local! {
+    static GAMEPAD_OVERRIDES: RefCell<BTreeMap<i32, GamepadOverride>> =
+        const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Run `f` against the override for `gamepad`, if one is installed.
+fn with_override<R>(gamepad: i32, f: impl FnOnce(&GamepadOverride) -> R) -> Option<R> {
+    GAMEPAD_OVERRIDES.with(|overrides| overrides.borrow().get(&gamepad).map(f))
+}
+
+/// Read `{name = ..., buttons = {NAME = true}, axes = {NAME = value}}`.
+fn parse_override_state(
+    lua: &Lua,
+    state: &LuaTable,
+) -> LuaResult<(
+    Option<String>,
+    [bool; GAMEPAD_BUTTONS.len()],
+    [f32; GAMEPAD_AXES.len()],
+)> {
+    let mut down = [false; GAMEPAD_BUTTONS.len()];
+    if let Some(buttons) = state.get::<Option<LuaTable>>("buttons")? {
+        for pair in buttons.pairs::<LuaValue, LuaValue>() {
+            let (button, value) = pair?;
+            let button = LuaGamepadButton::from_lua(button, lua)?;
+            let LuaValue::Boolean(is_down) = value else {
+                return Err(LuaError::runtime(format!(
+                    "set_gamepad_override: buttons.{} must be true or false, got {}",
+                    gamepad_button_name(button.0),
+                    value.type_name()
+                )));
+            };
+            down[button.0 as usize] = is_down;
+        }
+    }
+
+    let mut axes = [0.0; GAMEPAD_AXES.len()];
+    if let Some(values) = state.get::<Option<LuaTable>>("axes")? {
+        for pair in values.pairs::<LuaValue, f32>() {
+            let (axis, value) = pair?;
+            let axis = LuaGamepadAxis::from_lua(axis, lua)?;
+            axes[axis.0 as usize] = value.clamp(-1.0, 1.0);
+        }
+    }
+
+    Ok((state.get("name")?, down, axes))
+}
+
+/// Gamepad query methods on the window.
+///
+/// Every query checks the override layer first, so game logic can be driven
+/// from scripted state without a controller attached.
+pub fn add_gamepad_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    methods.add_method("is_gamepad_available", |_, this, gamepad: i32| {
+        Ok(with_override(gamepad, |_| true)
+            .unwrap_or_else(|| this.rl.is_gamepad_available(gamepad)))
+    });
+
+    methods.add_method(
+        "get_gamepad_name",
+        |_, this, gamepad: i32| match with_override(gamepad, |o| o.name.clone()) {
+            Some(name) => Ok(Some(name.unwrap_or_else(|| "Override".to_string()))),
+            None => Ok(this.rl.get_gamepad_name(gamepad)),
+        },
+    );
+
+    methods.add_method(
+        "is_gamepad_button_pressed",
+        |_, this, (gamepad, button): (i32, LuaGamepadButton)| {
+            Ok(with_override(gamepad, |o| o.is_pressed(button.0))
+                .unwrap_or_else(|| this.rl.is_gamepad_button_pressed(gamepad, button.0)))
+        },
+    );
+
+    methods.add_method(
+        "is_gamepad_button_down",
+        |_, this, (gamepad, button): (i32, LuaGamepadButton)| {
+            Ok(with_override(gamepad, |o| o.is_down(button.0))
+                .unwrap_or_else(|| this.rl.is_gamepad_button_down(gamepad, button.0)))
+        },
+    );
+
+    methods.add_method(
+        "is_gamepad_button_released",
+        |_, this, (gamepad, button): (i32, LuaGamepadButton)| {
+            Ok(with_override(gamepad, |o| o.is_released(button.0))
+                .unwrap_or_else(|| this.rl.is_gamepad_button_released(gamepad, button.0)))
+        },
+    );
+
+    methods.add_method(
+        "is_gamepad_button_up",
+        |_, this, (gamepad, button): (i32, LuaGamepadButton)| {
+            Ok(with_override(gamepad, |o| !o.is_down(button.0))
+                .unwrap_or_else(|| this.rl.is_gamepad_button_up(gamepad, button.0)))
+        },
+    );
+
+    methods.add_method(
+        "get_gamepad_axis_movement",
+        |_, this, (gamepad, axis): (i32, LuaGamepadAxis)| {
+            Ok(with_override(gamepad, |o| o.axis(axis.0))
+                .unwrap_or_else(|| this.rl.get_gamepad_axis_movement(gamepad, axis.0)))
+        },
+    );
+
+    methods.add_method("get_gamepad_axis_count", |_, this, gamepad: i32| {
+        Ok(with_override(gamepad, |_| GAMEPAD_AXES.len() as i32)
+            .unwrap_or_else(|| this.rl.get_gamepad_axis_count(gamepad)))
+    });
+
+    methods.add_method("get_gamepad_button_pressed", |_, this, ()| {
+        let overridden = GAMEPAD_OVERRIDES.with(|overrides| {
+            overrides
+                .borrow()
+                .values()
+                .find_map(GamepadOverride::last_pressed)
+        });
+        Ok(overridden
+            .or_else(|| this.rl.get_gamepad_button_pressed())
+            .map(gamepad_button_name))
+    });
+
+    methods.add_method("set_gamepad_mappings", |_, _this, mappings: String| {
+        let mappings = CString::new(mappings).map_err(|_| {
+            LuaError::runtime("set_gamepad_mappings: mappings must not contain NUL bytes")
+        })?;
+        Ok(unsafe { ffi::SetGamepadMappings(mappings.as_ptr()) })
+    });
+
+    methods.add_method(
+        "set_gamepad_override",
+        |lua, _this, (gamepad, state): (i32, LuaTable)| {
+            let (name, down, axes) = parse_override_state(lua, &state)?;
+            GAMEPAD_OVERRIDES.with(|overrides| {
+                overrides
+                    .borrow_mut()
+                    .entry(gamepad)
+                    .or_default()
+                    .set_state(name, down, axes)
+            });
+            Ok(())
+        },
+    );
+
+    methods.add_method(
+        "clear_gamepad_override",
+        |_, _this, gamepad: Option<i32>| {
+            GAMEPAD_OVERRIDES.with(|overrides| {
+                let mut overrides = overrides.borrow_mut();
+                match gamepad {
+                    Some(gamepad) => {
+                        overrides.remove(&gamepad);
+                    }
+                    None => overrides.clear(),
+                }
+            });
+            Ok(())
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buttons(down: &[GamepadButton]) -> [bool; GAMEPAD_BUTTONS.len()] {
+        let mut state = [false; GAMEPAD_BUTTONS.len()];
+        for button in down {
+            state[*button as usize] = true;
+        }
+        state
+    }
+
+    #[test]
+    fn override_derives_pressed_and_released_from_consecutive_states() {
+        let jump = GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_DOWN;
+        let mut pad = GamepadOverride::default();
+
+        pad.set_state(None, buttons(&[jump]), [0.0; GAMEPAD_AXES.len()]);
+        assert!(pad.is_pressed(jump) && pad.is_down(jump));
+        assert_eq!(pad.last_pressed(), Some(jump));
+
+        pad.set_state(None, buttons(&[jump]), [0.0; GAMEPAD_AXES.len()]);
+        assert!(!pad.is_pressed(jump) && pad.is_down(jump));
+        assert_eq!(pad.last_pressed(), None);
+
+        pad.set_state(None, buttons(&[]), [0.0; GAMEPAD_AXES.len()]);
+        assert!(pad.is_released(jump) && !pad.is_down(jump));
+    }
+
+    #[test]
+    fn override_reports_axes() {
+        let mut pad = GamepadOverride::default();
+        let mut axes = [0.0; GAMEPAD_AXES.len()];
+        axes[GamepadAxis::GAMEPAD_AXIS_LEFT_X as usize] = -0.5;
+        pad.set_state(Some("Test pad".to_string()), buttons(&[]), axes);
+        assert_eq!(pad.axis(GamepadAxis::GAMEPAD_AXIS_LEFT_X), -0.5);
+        assert_eq!(pad.axis(GamepadAxis::GAMEPAD_AXIS_RIGHT_Y), 0.0);
+    }
+}
@@ -0,0 +1,422 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::draw::{draw_default_text, text_line};
+use crate::run::RunOptions;
+
+/// Seconds between two scans of the script directory.
+const POLL_INTERVAL: f64 = 0.25;
+
+/// Set while the main script is re-executed, so `init_window` and
+/// `init_audio_device` hand back what is already open and `rl.run` hands over
+/// its callbacks instead of looping.
+struct Reloading {
+    window: LuaAnyUserData,
+    options: Option<RunOptions>,
+}
+
+/// Raised by `rl.run` to stop the re-executed main script once it has handed
+/// over its callbacks, so code after the loop (like `window:close()`) is
+/// not run.
+#[derive(Debug)]
+struct Handoff;
+
+impl fmt::Display for Handoff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rl.run called while reloading the main script")
+    }
+}
+
+impl std::error::Error for Handoff {}
+
+/// The open window while the main script is re-executed.
+pub fn reloading_window(lua: &Lua) -> Option<LuaAnyUserData> {
+    lua.app_data_ref::<Reloading>()
+        .map(|reloading| reloading.window.clone())
+}
+
+/// Whether the main script is being re-executed.
+pub fn is_reloading(lua: &Lua) -> bool {
+    lua.app_data_ref::<Reloading>().is_some()
+}
+
+/// Keep the callbacks of the `rl.run` call made by the re-executed main
+/// script, returning the error that ends the script.
+pub fn hand_over(lua: &Lua, options: RunOptions) -> LuaError {
+    if let Some(mut reloading) = lua.app_data_mut::<Reloading>() {
+        reloading.options = Some(options);
+    }
+    LuaError::external(Handoff)
+}
+
+/// Last modification time of every `.lua` file under `dir`, skipping hidden
+/// files and directories.
+fn scan(dir: &Path, files: &mut HashMap<PathBuf, SystemTime>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let path = entry.path();
+        if metadata.is_dir() {
+            scan(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "lua")
+            && let Ok(modified) = metadata.modified()
+        {
+            files.insert(path, modified);
+        }
+    }
+}
+
+/// Files that are new in `after` or were modified since `before`, in path
+/// order.
+fn changed_files(
+    before: &HashMap<PathBuf, SystemTime>,
+    after: &HashMap<PathBuf, SystemTime>,
+) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(*modified))
+        .map(|(path, _)| path.clone())
+        .collect();
+    changed.sort();
+    changed
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (std::fs::canonicalize(a), std::fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Name of the loaded module `require` found at `path`, if any.
+fn loaded_module(lua: &Lua, path: &Path) -> LuaResult<Option<String>> {
+    let package: LuaTable = lua.globals().get("package")?;
+    let loaded: LuaTable = package.get("loaded")?;
+    let search_path: String = package.get("path")?;
+    let searchpath: LuaFunction = package.get("searchpath")?;
+    for pair in loaded.pairs::<LuaValue, LuaValue>() {
+        let LuaValue::String(name) = pair?.0 else {
+            continue;
+        };
+        let name = name.to_str()?.to_string();
+        let found: Option<String> = searchpath.call((name.as_str(), search_path.as_str()))?;
+        if found.is_some_and(|found| same_file(Path::new(&found), path)) {
+            return Ok(Some(name));
+        }
+    }
+    Ok(None)
+}
+
+fn load_file(lua: &Lua, path: &Path) -> LuaResult<LuaFunction> {
+    let source = std::fs::read(path)
+        .map_err(|e| LuaError::runtime(format!("cannot read {}: {}", path.display(), e)))?;
+    lua.load(source)
+        .set_name(format!("@{}", path.display()))
+        .into_function()
+}
+
+/// Re-execute the module `name` from `path`. When the module is a table the
+/// loaded table is updated in place, so code holding on to it sees the new
+/// functions.
+fn reload_module(lua: &Lua, name: &str, path: &Path) -> LuaResult<()> {
+    let chunk = load_file(lua, path)?;
+    let module: LuaValue = chunk.call((name, path.display().to_string()))?;
+    let package: LuaTable = lua.globals().get("package")?;
+    let loaded: LuaTable = package.get("loaded")?;
+    match (loaded.get::<LuaValue>(name)?, module) {
+        (LuaValue::Table(old), LuaValue::Table(new)) => {
+            for pair in new.pairs::<LuaValue, LuaValue>() {
+                let (key, value) = pair?;
+                old.raw_set(key, value)?;
+            }
+        }
+        (_, LuaValue::Nil) => loaded.set(name, true)?,
+        (_, module) => loaded.set(name, module)?,
+    }
+    Ok(())
+}
+
+/// Watches the directory of the script that called `rl.run` and re-executes
+/// Lua files as they change, keeping the window open.
+pub struct HotReload {
+    script: PathBuf,
+    dir: PathBuf,
+    files: HashMap<PathBuf, SystemTime>,
+    next_poll: f64,
+    error: Option<String>,
+}
+
+impl HotReload {
+    /// Watch the directory of the Lua file calling the running function.
+    pub fn for_caller(lua: &Lua) -> LuaResult<Self> {
+        let source = lua
+            .inspect_stack(1, |debug| debug.source().source.map(|s| s.into_owned()))
+            .flatten();
+        let script = match source.as_deref().and_then(|s| s.strip_prefix('@')) {
+            Some(path) => PathBuf::from(path),
+            None => {
+                return Err(LuaError::runtime(
+                    "run: hot_reload needs rl.run to be called from a script file",
+                ));
+            }
+        };
+        let dir = match script.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        let mut files = HashMap::new();
+        scan(&dir, &mut files);
+        Ok(HotReload {
+            script,
+            dir,
+            files,
+            next_poll: 0.0,
+            error: None,
+        })
+    }
+
+    /// Message of the last failed reload, shown until a reload succeeds.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn set_error(&mut self, err: LuaError) {
+        self.error = Some(err.to_string());
+    }
+
+    /// Files changed since the last poll, scanning at most every
+    /// `POLL_INTERVAL` seconds.
+    fn poll(&mut self) -> Vec<PathBuf> {
+        let now = unsafe { ffi::GetTime() };
+        if now < self.next_poll {
+            return Vec::new();
+        }
+        self.next_poll = now + POLL_INTERVAL;
+        let mut files = HashMap::new();
+        scan(&self.dir, &mut files);
+        let changed = changed_files(&self.files, &files);
+        self.files = files;
+        changed
+    }
+
+    /// Re-execute the files changed since the last poll: changed modules
+    /// first, then the main script. Returns the callbacks the main script
+    /// passed to `rl.run` when it was re-executed. Failures are kept for the
+    /// overlay and the previous code keeps running.
+    pub fn reload(&mut self, lua: &Lua, window: &LuaAnyUserData) -> Option<RunOptions> {
+        let changed = self.poll();
+        if changed.is_empty() {
+            return None;
+        }
+        let mut errors = Vec::new();
+        let mut main_changed = false;
+        for path in &changed {
+            if same_file(path, &self.script) {
+                main_changed = true;
+                continue;
+            }
+            let reloaded = loaded_module(lua, path).and_then(|name| match name {
+                Some(name) => reload_module(lua, &name, path),
+                None => Ok(()),
+            });
+            if let Err(err) = reloaded {
+                errors.push(err.to_string());
+            }
+        }
+        let mut options = None;
+        if main_changed {
+            match self.rerun_script(lua, window) {
+                Ok(new_options) => options = Some(new_options),
+                Err(err) => errors.push(err.to_string()),
+            }
+        }
+        self.error = (!errors.is_empty()).then(|| errors.join("\n"));
+        options
+    }
+
+    /// Run the main script again, up to its `rl.run` call.
+    fn rerun_script(&self, lua: &Lua, window: &LuaAnyUserData) -> LuaResult<RunOptions> {
+        let chunk = load_file(lua, &self.script)?;
+        // Pass the script arguments again, as the runner did
+        let args: Vec<LuaValue> = match lua.globals().get::<Option<LuaTable>>("arg")? {
+            Some(arg) => arg.sequence_values().collect::<LuaResult<_>>()?,
+            None => Vec::new(),
+        };
+
+        lua.set_app_data(Reloading {
+            window: window.clone(),
+            options: None,
+        });
+        let result = chunk.call::<()>(LuaMultiValue::from_iter(args));
+        let options = lua
+            .remove_app_data::<Reloading>()
+            .and_then(|reloading| reloading.options);
+
+        match (result, options) {
+            (Err(err), Some(options)) if err.downcast_ref::<Handoff>().is_some() => Ok(options),
+            (Err(err), _) => Err(err),
+            (Ok(()), _) => Err(LuaError::runtime(format!(
+                "{} finished without calling rl.run",
+                self.script.display()
+            ))),
+        }
+    }
+}
+
+/// Draw `message` in a banner over the top of the frame.
+pub fn draw_error_overlay(title: &str, message: &str) {
+    const SIZE: f32 = 20.0;
+    const MARGIN: f32 = 10.0;
+    let lines: Vec<&str> = std::iter::once(title).chain(message.lines()).collect();
+    let height = MARGIN * 2.0 + SIZE * lines.len() as f32;
+    unsafe {
+        ffi::DrawRectangleRec(
+            ffi::Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: ffi::GetScreenWidth() as f32,
+                height,
+            },
+            Color::new(120, 16, 16, 230).into(),
+        )
+    };
+    for (i, line) in lines.iter().enumerate() {
+        let position = ffi::Vector2 {
+            x: MARGIN,
+            y: MARGIN + SIZE * i as f32,
+        };
+        draw_default_text(&text_line(line), position, SIZE, Color::WHITE.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn new_and_modified_files_count_as_changed() {
+        let then = SystemTime::UNIX_EPOCH;
+        let now = then + Duration::from_secs(5);
+        let before = HashMap::from([
+            (PathBuf::from("main.lua"), then),
+            (PathBuf::from("player.lua"), then),
+        ]);
+        let after = HashMap::from([
+            (PathBuf::from("main.lua"), then),
+            (PathBuf::from("player.lua"), now),
+            (PathBuf::from("enemy.lua"), now),
+        ]);
+        assert_eq!(
+            changed_files(&before, &after),
+            vec![PathBuf::from("enemy.lua"), PathBuf::from("player.lua")]
+        );
+        assert!(changed_files(&after, &after).is_empty());
+    }
+}
+
+// Tests needing a Lua state, which only links when Lua is built in:
+// `cargo test --no-default-features --features runner`
+#[cfg(all(test, not(feature = "module")))]
+mod lua_tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rlmlua-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn reloading_a_module_updates_its_table_in_place() {
+        let lua = Lua::new();
+        let dir = temp_dir("reload-module");
+        let path = dir.join("player.lua");
+        std::fs::write(
+            &path,
+            "return { speed = 2, jump = function() return 'new' end }",
+        )
+        .unwrap();
+        let old: LuaTable = lua
+            .load("package.loaded.player = { speed = 1, lives = 3 } return package.loaded.player")
+            .eval()
+            .unwrap();
+
+        reload_module(&lua, "player", &path).unwrap();
+        let loaded: LuaTable = lua.load("return package.loaded.player").eval().unwrap();
+        assert_eq!(loaded, old);
+        assert_eq!(old.get::<i64>("speed").unwrap(), 2);
+        assert_eq!(old.get::<i64>("lives").unwrap(), 3);
+        let jump: LuaFunction = old.get("jump").unwrap();
+        assert_eq!(jump.call::<String>(()).unwrap(), "new");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn loaded_modules_are_found_by_path() {
+        let lua = Lua::new();
+        let dir = temp_dir("loaded-module");
+        let path = dir.join("enemy.lua");
+        std::fs::write(&path, "return {}").unwrap();
+        let package: LuaTable = lua.globals().get("package").unwrap();
+        package
+            .set("path", format!("{}/?.lua", dir.display()))
+            .unwrap();
+
+        assert_eq!(loaded_module(&lua, &path).unwrap(), None);
+        lua.load("require('enemy')").exec().unwrap();
+        assert_eq!(
+            loaded_module(&lua, &path).unwrap().as_deref(),
+            Some("enemy")
+        );
+        assert_eq!(loaded_module(&lua, &dir.join("boss.lua")).unwrap(), None);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn rerunning_the_script_hands_over_its_callbacks() {
+        let lua = Lua::new();
+        let rl = lua.create_table().unwrap();
+        crate::run::register_run_functions(&lua, &rl).unwrap();
+        lua.globals().set("rl", rl).unwrap();
+        let dir = temp_dir("rerun-script");
+        let script = dir.join("main.lua");
+        std::fs::write(
+            &script,
+            "rl.run(window, { update = function() end })\nafter_run = true",
+        )
+        .unwrap();
+        let window = lua.create_any_userdata(()).unwrap();
+        lua.globals().set("window", &window).unwrap();
+        let watcher = HotReload {
+            script: script.clone(),
+            dir: dir.clone(),
+            files: HashMap::new(),
+            next_poll: 0.0,
+            error: None,
+        };
+
+        assert!(watcher.rerun_script(&lua, &window).is_ok());
+        assert!(!is_reloading(&lua));
+        assert_eq!(
+            lua.globals().get::<Option<bool>>("after_run").unwrap(),
+            None
+        );
+
+        std::fs::write(&script, "after_run = true").unwrap();
+        let err = watcher.rerun_script(&lua, &window).err().unwrap();
+        assert!(err.to_string().contains("finished without calling rl.run"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
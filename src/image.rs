@@ -0,0 +1,287 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::ffi::CString;
+
+use crate::texture::LuaTexture;
+use crate::{LuaColor, LuaRaylib, LuaRectangle};
+
+/// Fail unless both image dimensions are positive.
+fn check_size(width: i32, height: i32) -> LuaResult<()> {
+    if width <= 0 || height <= 0 {
+        return Err(LuaError::runtime(format!(
+            "Invalid image size {}x{}",
+            width, height
+        )));
+    }
+    Ok(())
+}
+
+/// Fail unless a generator's count or tile size is positive; raylib divides
+/// by these.
+fn check_positive(name: &str, value: i32) -> LuaResult<()> {
+    if value <= 0 {
+        return Err(LuaError::runtime(format!(
+            "{} must be positive, got {}",
+            name, value
+        )));
+    }
+    Ok(())
+}
+
+/// CPU-side image that can be edited pixel by pixel and uploaded later.
+pub struct LuaImage {
+    pub image: Image,
+}
+
+impl LuaImage {
+    fn check_bounds(&self, x: i32, y: i32) -> LuaResult<()> {
+        if x < 0 || y < 0 || x >= self.image.width() || y >= self.image.height() {
+            return Err(LuaError::runtime(format!(
+                "Pixel ({}, {}) is outside of the {}x{} image",
+                x,
+                y,
+                self.image.width(),
+                self.image.height()
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl LuaUserData for LuaImage {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("width", |_, this| Ok(this.image.width()));
+        fields.add_field_method_get("height", |_, this| Ok(this.image.height()));
+        fields.add_field_method_get("mipmaps", |_, this| Ok(this.image.mipmaps()));
+        fields.add_field_method_get("format", |_, this| Ok(this.image.format));
+    }
+
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method_mut("get_pixel", |_, this, (x, y): (i32, i32)| {
+            this.check_bounds(x, y)?;
+            Ok(LuaColor::from(this.image.get_color(x, y)))
+        });
+
+        methods.add_method_mut(
+            "set_pixel",
+            |_, this, (x, y, color): (i32, i32, LuaColor)| {
+                this.check_bounds(x, y)?;
+                this.image.draw_pixel(x, y, color);
+                Ok(())
+            },
+        );
+
+        methods.add_method_mut("crop", |_, this, rect: LuaRectangle| {
+            this.image.crop(rect);
+            Ok(())
+        });
+
+        methods.add_method_mut("resize", |_, this, (width, height): (i32, i32)| {
+            check_size(width, height)?;
+            this.image.resize(width, height);
+            Ok(())
+        });
+
+        methods.add_method_mut("flip", |_, this, direction: String| {
+            match direction.to_lowercase().as_str() {
+                "horizontal" => this.image.flip_horizontal(),
+                "vertical" => this.image.flip_vertical(),
+                _ => {
+                    return Err(LuaError::runtime(format!(
+                        "Unknown flip direction '{}', expected \"horizontal\" or \"vertical\"",
+                        direction
+                    )));
+                }
+            }
+            Ok(())
+        });
+
+        methods.add_method_mut("rotate", |_, this, degrees: i32| {
+            this.image.rotate(degrees);
+            Ok(())
+        });
+
+        methods.add_method_mut("color_tint", |_, this, color: LuaColor| {
+            this.image.color_tint(color);
+            Ok(())
+        });
+
+        methods.add_method("copy", |_, this, ()| {
+            Ok(LuaImage {
+                image: this.image.clone(),
+            })
+        });
+
+        methods.add_method("export", |_, this, path: String| {
+            let c_path = CString::new(path.as_str()).map_err(LuaError::external)?;
+            if !unsafe { ffi::ExportImage(*this.image, c_path.as_ptr()) } {
+                return Err(LuaError::runtime(format!(
+                    "Failed to export image to '{}'",
+                    path
+                )));
+            }
+            Ok(())
+        });
+
+        methods.add_method(
+            "to_texture",
+            |_, this, mut window: LuaUserDataRefMut<LuaRaylib>| {
+                window.load_texture_from_image(&this.image)
+            },
+        );
+    }
+}
+
+impl LuaRaylib {
+    /// Upload an image to the GPU as a texture owned by this window.
+    pub fn load_texture_from_image(&mut self, image: &Image) -> LuaResult<LuaTexture> {
+        self.check_open("load_texture_from_image")?;
+        let texture = self
+            .rl
+            .load_texture_from_image(&self.thread, image)
+            .map_err(|e| LuaError::runtime(e.to_string()))?;
+        Ok(LuaTexture::new(texture, self.alive.clone()))
+    }
+}
+
+/// Image loading method on the window.
+pub fn add_image_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    methods.add_method_mut(
+        "load_texture_from_image",
+        |_, this, image: LuaUserDataRef<LuaImage>| this.load_texture_from_image(&image.image),
+    );
+}
+
+fn load_image(_lua: &Lua, path: String) -> LuaResult<LuaImage> {
+    let image = Image::load_image(&path).map_err(|e| LuaError::runtime(e.to_string()))?;
+    Ok(LuaImage { image })
+}
+
+fn gen_image_color(
+    _lua: &Lua,
+    (width, height, color): (i32, i32, LuaColor),
+) -> LuaResult<LuaImage> {
+    check_size(width, height)?;
+    Ok(LuaImage {
+        image: Image::gen_image_color(width, height, color),
+    })
+}
+
+fn gen_image_checked(
+    _lua: &Lua,
+    (width, height, checks_x, checks_y, col1, col2): (i32, i32, i32, i32, LuaColor, LuaColor),
+) -> LuaResult<LuaImage> {
+    check_size(width, height)?;
+    check_positive("checks_x", checks_x)?;
+    check_positive("checks_y", checks_y)?;
+    Ok(LuaImage {
+        image: Image::gen_image_checked(width, height, checks_x, checks_y, col1, col2),
+    })
+}
+
+fn gen_image_gradient_linear(
+    _lua: &Lua,
+    (width, height, direction, start, end): (i32, i32, i32, LuaColor, LuaColor),
+) -> LuaResult<LuaImage> {
+    check_size(width, height)?;
+    Ok(LuaImage {
+        image: Image::gen_image_gradient_linear(width, height, direction, start.into(), end.into()),
+    })
+}
+
+fn gen_image_gradient_radial(
+    _lua: &Lua,
+    (width, height, density, inner, outer): (i32, i32, f32, LuaColor, LuaColor),
+) -> LuaResult<LuaImage> {
+    check_size(width, height)?;
+    Ok(LuaImage {
+        image: Image::gen_image_gradient_radial(width, height, density, inner, outer),
+    })
+}
+
+fn gen_image_white_noise(
+    _lua: &Lua,
+    (width, height, factor): (i32, i32, f32),
+) -> LuaResult<LuaImage> {
+    check_size(width, height)?;
+    Ok(LuaImage {
+        image: Image::gen_image_white_noise(width, height, factor),
+    })
+}
+
+fn gen_image_perlin_noise(
+    _lua: &Lua,
+    (width, height, offset_x, offset_y, scale): (i32, i32, i32, i32, f32),
+) -> LuaResult<LuaImage> {
+    check_size(width, height)?;
+    let image = unsafe {
+        Image::from_raw(ffi::GenImagePerlinNoise(
+            width, height, offset_x, offset_y, scale,
+        ))
+    };
+    Ok(LuaImage { image })
+}
+
+fn gen_image_cellular(
+    _lua: &Lua,
+    (width, height, tile_size): (i32, i32, i32),
+) -> LuaResult<LuaImage> {
+    check_size(width, height)?;
+    check_positive("tile_size", tile_size)?;
+    Ok(LuaImage {
+        image: Image::gen_image_cellular(width, height, tile_size),
+    })
+}
+
+/// Register image loading and generation functions
+pub fn register_image_functions(lua: &Lua, exports: &LuaTable) -> LuaResult<()> {
+    exports.set("load_image", lua.create_function(load_image)?)?;
+    exports.set("gen_image_color", lua.create_function(gen_image_color)?)?;
+    exports.set("gen_image_checked", lua.create_function(gen_image_checked)?)?;
+    exports.set(
+        "gen_image_gradient_linear",
+        lua.create_function(gen_image_gradient_linear)?,
+    )?;
+    exports.set(
+        "gen_image_gradient_radial",
+        lua.create_function(gen_image_gradient_radial)?,
+    )?;
+    exports.set(
+        "gen_image_white_noise",
+        lua.create_function(gen_image_white_noise)?,
+    )?;
+    exports.set(
+        "gen_image_perlin_noise",
+        lua.create_function(gen_image_perlin_noise)?,
+    )?;
+    exports.set(
+        "gen_image_cellular",
+        lua.create_function(gen_image_cellular)?,
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generators_reject_sizes_raylib_cannot_allocate() {
+        assert!(check_size(64, 32).is_ok());
+        assert!(check_size(0, 32).is_err());
+        assert!(check_size(64, -1).is_err());
+        let err = check_size(-4, 8).unwrap_err();
+        assert!(err.to_string().contains("Invalid image size -4x8"));
+    }
+
+    #[test]
+    fn divisors_must_be_positive() {
+        assert!(check_positive("checks_x", 1).is_ok());
+        assert!(check_positive("checks_x", 0).is_err());
+        let err = check_positive("tile_size", -2).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("tile_size must be positive, got -2")
+        );
+    }
+}
@@ -0,0 +1,501 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::ffi::{CStr, CString};
+
+/// Every raylib keyboard key under its name without the `KEY_` prefix.
+pub const KEYS: &[(&str, KeyboardKey)] = &[
+    ("NULL", KeyboardKey::KEY_NULL),
+    ("APOSTROPHE", KeyboardKey::KEY_APOSTROPHE),
+    ("COMMA", KeyboardKey::KEY_COMMA),
+    ("MINUS", KeyboardKey::KEY_MINUS),
+    ("PERIOD", KeyboardKey::KEY_PERIOD),
+    ("SLASH", KeyboardKey::KEY_SLASH),
+    ("ZERO", KeyboardKey::KEY_ZERO),
+    ("ONE", KeyboardKey::KEY_ONE),
+    ("TWO", KeyboardKey::KEY_TWO),
+    ("THREE", KeyboardKey::KEY_THREE),
+    ("FOUR", KeyboardKey::KEY_FOUR),
+    ("FIVE", KeyboardKey::KEY_FIVE),
+    ("SIX", KeyboardKey::KEY_SIX),
+    ("SEVEN", KeyboardKey::KEY_SEVEN),
+    ("EIGHT", KeyboardKey::KEY_EIGHT),
+    ("NINE", KeyboardKey::KEY_NINE),
+    ("SEMICOLON", KeyboardKey::KEY_SEMICOLON),
+    ("EQUAL", KeyboardKey::KEY_EQUAL),
+    ("A", KeyboardKey::KEY_A),
+    ("B", KeyboardKey::KEY_B),
+    ("C", KeyboardKey::KEY_C),
+    ("D", KeyboardKey::KEY_D),
+    ("E", KeyboardKey::KEY_E),
+    ("F", KeyboardKey::KEY_F),
+    ("G", KeyboardKey::KEY_G),
+    ("H", KeyboardKey::KEY_H),
+    ("I", KeyboardKey::KEY_I),
+    ("J", KeyboardKey::KEY_J),
+    ("K", KeyboardKey::KEY_K),
+    ("L", KeyboardKey::KEY_L),
+    ("M", KeyboardKey::KEY_M),
+    ("N", KeyboardKey::KEY_N),
+    ("O", KeyboardKey::KEY_O),
+    ("P", KeyboardKey::KEY_P),
+    ("Q", KeyboardKey::KEY_Q),
+    ("R", KeyboardKey::KEY_R),
+    ("S", KeyboardKey::KEY_S),
+    ("T", KeyboardKey::KEY_T),
+    ("U", KeyboardKey::KEY_U),
+    ("V", KeyboardKey::KEY_V),
+    ("W", KeyboardKey::KEY_W),
+    ("X", KeyboardKey::KEY_X),
+    ("Y", KeyboardKey::KEY_Y),
+    ("Z", KeyboardKey::KEY_Z),
+    ("LEFT_BRACKET", KeyboardKey::KEY_LEFT_BRACKET),
+    ("BACKSLASH", KeyboardKey::KEY_BACKSLASH),
+    ("RIGHT_BRACKET", KeyboardKey::KEY_RIGHT_BRACKET),
+    ("GRAVE", KeyboardKey::KEY_GRAVE),
+    ("SPACE", KeyboardKey::KEY_SPACE),
+    ("ESCAPE", KeyboardKey::KEY_ESCAPE),
+    ("ENTER", KeyboardKey::KEY_ENTER),
+    ("TAB", KeyboardKey::KEY_TAB),
+    ("BACKSPACE", KeyboardKey::KEY_BACKSPACE),
+    ("INSERT", KeyboardKey::KEY_INSERT),
+    ("DELETE", KeyboardKey::KEY_DELETE),
+    ("RIGHT", KeyboardKey::KEY_RIGHT),
+    ("LEFT", KeyboardKey::KEY_LEFT),
+    ("DOWN", KeyboardKey::KEY_DOWN),
+    ("UP", KeyboardKey::KEY_UP),
+    ("PAGE_UP", KeyboardKey::KEY_PAGE_UP),
+    ("PAGE_DOWN", KeyboardKey::KEY_PAGE_DOWN),
+    ("HOME", KeyboardKey::KEY_HOME),
+    ("END", KeyboardKey::KEY_END),
+    ("CAPS_LOCK", KeyboardKey::KEY_CAPS_LOCK),
+    ("SCROLL_LOCK", KeyboardKey::KEY_SCROLL_LOCK),
+    ("NUM_LOCK", KeyboardKey::KEY_NUM_LOCK),
+    ("PRINT_SCREEN", KeyboardKey::KEY_PRINT_SCREEN),
+    ("PAUSE", KeyboardKey::KEY_PAUSE),
+    ("F1", KeyboardKey::KEY_F1),
+    ("F2", KeyboardKey::KEY_F2),
+    ("F3", KeyboardKey::KEY_F3),
+    ("F4", KeyboardKey::KEY_F4),
+    ("F5", KeyboardKey::KEY_F5),
+    ("F6", KeyboardKey::KEY_F6),
+    ("F7", KeyboardKey::KEY_F7),
+    ("F8", KeyboardKey::KEY_F8),
+    ("F9", KeyboardKey::KEY_F9),
+    ("F10", KeyboardKey::KEY_F10),
+    ("F11", KeyboardKey::KEY_F11),
+    ("F12", KeyboardKey::KEY_F12),
+    ("LEFT_SHIFT", KeyboardKey::KEY_LEFT_SHIFT),
+    ("LEFT_CONTROL", KeyboardKey::KEY_LEFT_CONTROL),
+    ("LEFT_ALT", KeyboardKey::KEY_LEFT_ALT),
+    ("LEFT_SUPER", KeyboardKey::KEY_LEFT_SUPER),
+    ("RIGHT_SHIFT", KeyboardKey::KEY_RIGHT_SHIFT),
+    ("RIGHT_CONTROL", KeyboardKey::KEY_RIGHT_CONTROL),
+    ("RIGHT_ALT", KeyboardKey::KEY_RIGHT_ALT),
+    ("RIGHT_SUPER", KeyboardKey::KEY_RIGHT_SUPER),
+    ("KB_MENU", KeyboardKey::KEY_KB_MENU),
+    ("KP_0", KeyboardKey::KEY_KP_0),
+    ("KP_1", KeyboardKey::KEY_KP_1),
+    ("KP_2", KeyboardKey::KEY_KP_2),
+    ("KP_3", KeyboardKey::KEY_KP_3),
+    ("KP_4", KeyboardKey::KEY_KP_4),
+    ("KP_5", KeyboardKey::KEY_KP_5),
+    ("KP_6", KeyboardKey::KEY_KP_6),
+    ("KP_7", KeyboardKey::KEY_KP_7),
+    ("KP_8", KeyboardKey::KEY_KP_8),
+    ("KP_9", KeyboardKey::KEY_KP_9),
+    ("KP_DECIMAL", KeyboardKey::KEY_KP_DECIMAL),
+    ("KP_DIVIDE", KeyboardKey::KEY_KP_DIVIDE),
+    ("KP_MULTIPLY", KeyboardKey::KEY_KP_MULTIPLY),
+    ("KP_SUBTRACT", KeyboardKey::KEY_KP_SUBTRACT),
+    ("KP_ADD", KeyboardKey::KEY_KP_ADD),
+    ("KP_ENTER", KeyboardKey::KEY_KP_ENTER),
+    ("KP_EQUAL", KeyboardKey::KEY_KP_EQUAL),
+    ("BACK", KeyboardKey::KEY_BACK),
+    ("MENU", KeyboardKey::KEY_MENU),
+    ("VOLUME_UP", KeyboardKey::KEY_VOLUME_UP),
+    ("VOLUME_DOWN", KeyboardKey::KEY_VOLUME_DOWN),
+];
+
+/// Extra spellings accepted for common keys.
+const KEY_ALIASES: &[(&str, KeyboardKey)] = &[
+    ("ESC", KeyboardKey::KEY_ESCAPE),
+    ("RETURN", KeyboardKey::KEY_ENTER),
+    ("SHIFT", KeyboardKey::KEY_LEFT_SHIFT),
+    ("CTRL", KeyboardKey::KEY_LEFT_CONTROL),
+    ("CONTROL", KeyboardKey::KEY_LEFT_CONTROL),
+    ("ALT", KeyboardKey::KEY_LEFT_ALT),
+    ("0", KeyboardKey::KEY_ZERO),
+    ("1", KeyboardKey::KEY_ONE),
+    ("2", KeyboardKey::KEY_TWO),
+    ("3", KeyboardKey::KEY_THREE),
+    ("4", KeyboardKey::KEY_FOUR),
+    ("5", KeyboardKey::KEY_FIVE),
+    ("6", KeyboardKey::KEY_SIX),
+    ("7", KeyboardKey::KEY_SEVEN),
+    ("8", KeyboardKey::KEY_EIGHT),
+    ("9", KeyboardKey::KEY_NINE),
+];
+
+/// Every raylib mouse button under its name without the `MOUSE_BUTTON_` prefix.
+pub const MOUSE_BUTTONS: &[(&str, MouseButton)] = &[
+    ("LEFT", MouseButton::MOUSE_BUTTON_LEFT),
+    ("RIGHT", MouseButton::MOUSE_BUTTON_RIGHT),
+    ("MIDDLE", MouseButton::MOUSE_BUTTON_MIDDLE),
+    ("SIDE", MouseButton::MOUSE_BUTTON_SIDE),
+    ("EXTRA", MouseButton::MOUSE_BUTTON_EXTRA),
+    ("FORWARD", MouseButton::MOUSE_BUTTON_FORWARD),
+    ("BACK", MouseButton::MOUSE_BUTTON_BACK),
+];
+
+/// Every raylib gamepad button under its name without the `GAMEPAD_BUTTON_` prefix.
+pub const GAMEPAD_BUTTONS: &[(&str, GamepadButton)] = &[
+    ("UNKNOWN", GamepadButton::GAMEPAD_BUTTON_UNKNOWN),
+    ("LEFT_FACE_UP", GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_UP),
+    (
+        "LEFT_FACE_RIGHT",
+        GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_RIGHT,
+    ),
+    (
+        "LEFT_FACE_DOWN",
+        GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_DOWN,
+    ),
+    (
+        "LEFT_FACE_LEFT",
+        GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_LEFT,
+    ),
+    ("RIGHT_FACE_UP", GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_UP),
+    (
+        "RIGHT_FACE_RIGHT",
+        GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_RIGHT,
+    ),
+    (
+        "RIGHT_FACE_DOWN",
+        GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_DOWN,
+    ),
+    (
+        "RIGHT_FACE_LEFT",
+        GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_LEFT,
+    ),
+    (
+        "LEFT_TRIGGER_1",
+        GamepadButton::GAMEPAD_BUTTON_LEFT_TRIGGER_1,
+    ),
+    (
+        "LEFT_TRIGGER_2",
+        GamepadButton::GAMEPAD_BUTTON_LEFT_TRIGGER_2,
+    ),
+    (
+        "RIGHT_TRIGGER_1",
+        GamepadButton::GAMEPAD_BUTTON_RIGHT_TRIGGER_1,
+    ),
+    (
+        "RIGHT_TRIGGER_2",
+        GamepadButton::GAMEPAD_BUTTON_RIGHT_TRIGGER_2,
+    ),
+    ("MIDDLE_LEFT", GamepadButton::GAMEPAD_BUTTON_MIDDLE_LEFT),
+    ("MIDDLE", GamepadButton::GAMEPAD_BUTTON_MIDDLE),
+    ("MIDDLE_RIGHT", GamepadButton::GAMEPAD_BUTTON_MIDDLE_RIGHT),
+    ("LEFT_THUMB", GamepadButton::GAMEPAD_BUTTON_LEFT_THUMB),
+    ("RIGHT_THUMB", GamepadButton::GAMEPAD_BUTTON_RIGHT_THUMB),
+];
+
+/// Every raylib gamepad axis under its name without the `GAMEPAD_AXIS_` prefix.
+pub const GAMEPAD_AXES: &[(&str, GamepadAxis)] = &[
+    ("LEFT_X", GamepadAxis::GAMEPAD_AXIS_LEFT_X),
+    ("LEFT_Y", GamepadAxis::GAMEPAD_AXIS_LEFT_Y),
+    ("RIGHT_X", GamepadAxis::GAMEPAD_AXIS_RIGHT_X),
+    ("RIGHT_Y", GamepadAxis::GAMEPAD_AXIS_RIGHT_Y),
+    ("LEFT_TRIGGER", GamepadAxis::GAMEPAD_AXIS_LEFT_TRIGGER),
+    ("RIGHT_TRIGGER", GamepadAxis::GAMEPAD_AXIS_RIGHT_TRIGGER),
+];
+
+/// Raylib enums that have an integer code on the C side.
+pub trait RaylibCode {
+    fn code(self) -> i32;
+}
+
+impl RaylibCode for KeyboardKey {
+    fn code(self) -> i32 {
+        self as i32
+    }
+}
+
+impl RaylibCode for MouseButton {
+    fn code(self) -> i32 {
+        self as i32
+    }
+}
+
+impl RaylibCode for GamepadButton {
+    fn code(self) -> i32 {
+        self as i32
+    }
+}
+
+impl RaylibCode for GamepadAxis {
+    fn code(self) -> i32 {
+        self as i32
+    }
+}
+
+/// Edit distance between two names, used to suggest close matches.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { prev } else { prev + 1 };
+            prev = row[j + 1];
+            row[j + 1] = cost.min(row[j] + 1).min(prev + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Up to three known names closest to `name`.
+fn close_matches<'a>(name: &str, known: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let limit = (name.len() / 3).max(1);
+    let mut matches: Vec<(usize, &str)> = known
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .collect();
+    matches.sort();
+    matches.into_iter().take(3).map(|(_, name)| name).collect()
+}
+
+/// Look up a name case-insensitively, with or without its raylib C prefix.
+fn lookup<T: Copy>(
+    kind: &str,
+    prefix: &str,
+    table: &str,
+    name: &str,
+    entries: &[&[(&str, T)]],
+) -> LuaResult<T> {
+    let upper = name.trim().to_uppercase();
+    let bare = upper.strip_prefix(prefix).unwrap_or(&upper);
+    let all = || entries.iter().flat_map(|entries| entries.iter());
+    if let Some((_, value)) = all().find(|(n, _)| *n == bare) {
+        return Ok(*value);
+    }
+
+    let matches = close_matches(bare, all().map(|(n, _)| *n));
+    let hint = if matches.is_empty() {
+        format!("see {} for valid names", table)
+    } else {
+        format!("did you mean {}?", matches.join(", "))
+    };
+    Err(LuaError::runtime(format!(
+        "Unknown {} '{}', {}",
+        kind, name, hint
+    )))
+}
+
+/// Look up a raylib integer code in a name table.
+fn lookup_code<T: Copy + RaylibCode>(kind: &str, code: i64, entries: &[(&str, T)]) -> LuaResult<T> {
+    entries
+        .iter()
+        .map(|(_, value)| *value)
+        .find(|value| value.code() as i64 == code)
+        .ok_or_else(|| LuaError::runtime(format!("Unknown {} code {}", kind, code)))
+}
+
+/// Integer code from a Lua number, rejecting fractional values.
+fn integer_code(value: &LuaValue) -> Option<i64> {
+    match value {
+        LuaValue::Integer(i) => Some(*i),
+        LuaValue::Number(n) if n.fract() == 0.0 => Some(*n as i64),
+        _ => None,
+    }
+}
+
+/// Parse a name or raylib code against `tables`; codes are looked up in the first table.
+fn parse_named<T: Copy + RaylibCode>(
+    value: &LuaValue,
+    kind: &str,
+    prefix: &str,
+    exported_as: &str,
+    tables: &[&[(&str, T)]],
+) -> LuaResult<T> {
+    if let LuaValue::String(s) = value {
+        return lookup(kind, prefix, exported_as, &s.to_str()?, tables);
+    }
+    match integer_code(value) {
+        Some(code) => lookup_code(kind, code, tables[0]),
+        None => Err(LuaError::runtime(format!(
+            "Expected a {} name or code, got {}",
+            kind,
+            value.type_name()
+        ))),
+    }
+}
+
+/// Keyboard key given by name (`"SPACE"`, `"KEY_SPACE"`, `"space"`) or raylib key code.
+#[derive(Clone, Copy)]
+pub struct LuaKey(pub KeyboardKey);
+
+impl FromLua for LuaKey {
+    fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
+        parse_named(&value, "key", "KEY_", "rl.keys", &[KEYS, KEY_ALIASES]).map(LuaKey)
+    }
+}
+
+/// Mouse button given by name (`"LEFT"`, `"MOUSE_BUTTON_LEFT"`) or raylib button code.
+#[derive(Clone, Copy)]
+pub struct LuaMouseButton(pub MouseButton);
+
+impl FromLua for LuaMouseButton {
+    fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
+        parse_named(
+            &value,
+            "mouse button",
+            "MOUSE_BUTTON_",
+            "rl.mouse_buttons",
+            &[MOUSE_BUTTONS],
+        )
+        .map(LuaMouseButton)
+    }
+}
+
+/// Gamepad button given by name (`"RIGHT_FACE_DOWN"`) or raylib button code.
+#[derive(Clone, Copy)]
+pub struct LuaGamepadButton(pub GamepadButton);
+
+impl FromLua for LuaGamepadButton {
+    fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
+        parse_named(
+            &value,
+            "gamepad button",
+            "GAMEPAD_BUTTON_",
+            "rl.gamepad_buttons",
+            &[GAMEPAD_BUTTONS],
+        )
+        .map(LuaGamepadButton)
+    }
+}
+
+/// Gamepad axis given by name (`"LEFT_X"`) or raylib axis code.
+#[derive(Clone, Copy)]
+pub struct LuaGamepadAxis(pub GamepadAxis);
+
+impl FromLua for LuaGamepadAxis {
+    fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
+        parse_named(
+            &value,
+            "gamepad axis",
+            "GAMEPAD_AXIS_",
+            "rl.gamepad_axes",
+            &[GAMEPAD_AXES],
+        )
+        .map(LuaGamepadAxis)
+    }
+}
+
+/// Name a gamepad button is exported under, as returned by `get_gamepad_button_pressed`.
+pub fn gamepad_button_name(button: GamepadButton) -> &'static str {
+    GAMEPAD_BUTTONS
+        .iter()
+        .find(|(_, b)| *b == button)
+        .map_or("UNKNOWN", |(name, _)| name)
+}
+
+/// Name a raylib key code is exported under, as returned by `get_key_pressed`.
+pub fn key_name(code: i32) -> Option<&'static str> {
+    KEYS.iter()
+        .find(|(_, key)| key.code() == code)
+        .map(|(name, _)| *name)
+}
+
+/// Next character from raylib's typed character queue, if any.
+pub fn char_pressed() -> Option<char> {
+    let codepoint = unsafe { ffi::GetCharPressed() };
+    if codepoint > 0 {
+        char::from_u32(codepoint as u32)
+    } else {
+        None
+    }
+}
+
+/// Current clipboard contents; empty when the clipboard holds no text.
+pub fn clipboard_text() -> String {
+    let text = unsafe { ffi::GetClipboardText() };
+    if text.is_null() {
+        return String::new();
+    }
+    unsafe { CStr::from_ptr(text) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+pub fn set_clipboard_text(text: &str) -> LuaResult<()> {
+    let text = CString::new(text)
+        .map_err(|_| LuaError::runtime("set_clipboard_text: text must not contain NUL bytes"))?;
+    unsafe { ffi::SetClipboardText(text.as_ptr()) };
+    Ok(())
+}
+
+/// Build a name to raylib code table such as `rl.keys`.
+pub fn create_code_table<T: Copy + RaylibCode>(
+    lua: &Lua,
+    entries: &[(&str, T)],
+) -> LuaResult<LuaTable> {
+    let table = lua.create_table()?;
+    for (name, value) in entries {
+        table.set(*name, value.code())?;
+    }
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(name: &str) -> LuaResult<KeyboardKey> {
+        lookup("key", "KEY_", "rl.keys", name, &[KEYS, KEY_ALIASES])
+    }
+
+    #[test]
+    fn key_names_accept_case_prefix_and_aliases() {
+        assert_eq!(key("space").unwrap(), KeyboardKey::KEY_SPACE);
+        assert_eq!(key("KEY_SPACE").unwrap(), KeyboardKey::KEY_SPACE);
+        assert_eq!(key("kp_enter").unwrap(), KeyboardKey::KEY_KP_ENTER);
+        assert_eq!(key("esc").unwrap(), KeyboardKey::KEY_ESCAPE);
+        assert_eq!(key("7").unwrap(), KeyboardKey::KEY_SEVEN);
+    }
+
+    #[test]
+    fn unknown_key_suggests_close_matches() {
+        let message = key("SPCE").unwrap_err().to_string();
+        assert!(message.contains("'SPCE'"), "{}", message);
+        assert!(message.contains("did you mean SPACE"), "{}", message);
+
+        let message = key("NOT_A_KEY_AT_ALL").unwrap_err().to_string();
+        assert!(message.contains("see rl.keys"), "{}", message);
+    }
+
+    #[test]
+    fn codes_must_be_known() {
+        assert_eq!(
+            lookup_code("key", 32, KEYS).unwrap(),
+            KeyboardKey::KEY_SPACE
+        );
+        assert!(lookup_code("key", 999, KEYS).is_err());
+        assert_eq!(
+            lookup_code("mouse button", 1, MOUSE_BUTTONS).unwrap(),
+            MouseButton::MOUSE_BUTTON_RIGHT
+        );
+        assert_eq!(
+            lookup_code("gamepad axis", 5, GAMEPAD_AXES).unwrap(),
+            GamepadAxis::GAMEPAD_AXIS_RIGHT_TRIGGER
+        );
+    }
+
+    #[test]
+    fn names_are_unique() {
+        let mut names: Vec<&str> = KEYS.iter().chain(KEY_ALIASES).map(|(n, _)| *n).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), KEYS.len() + KEY_ALIASES.len());
+    }
+}
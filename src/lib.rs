@@ -1,18 +1,121 @@
 use mlua::prelude::*;
 // use raylib::collision::*;
 use raylib::prelude::*;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+mod audio;
+mod camera;
+mod crash_screen;
+mod draw;
+mod draw3d;
+mod draw_modes;
+mod easing;
+mod font;
+mod frame;
+mod gamepad;
+mod hot_reload;
+mod image;
+mod input;
+mod render_texture;
+mod rlm;
+mod run;
+mod shader;
+mod shapes;
+mod text_input;
+mod text_layout;
+mod texture;
+mod tween;
+mod vector2;
+mod vector3;
+mod window;
+
+use audio::register_audio_functions;
+use camera::add_camera_methods;
+use draw::add_draw_methods;
+use draw_modes::add_draw_mode_methods;
+use draw3d::add_draw3d_methods;
+use font::{add_font_methods, register_font_functions};
+use frame::{add_frame_methods, in_frame, register_frame_functions, set_strict, with_draw_handle};
+use gamepad::add_gamepad_methods;
+use image::{add_image_methods, register_image_functions};
+use input::{
+    GAMEPAD_AXES, GAMEPAD_BUTTONS, KEYS, LuaKey, LuaMouseButton, MOUSE_BUTTONS, char_pressed,
+    clipboard_text, create_code_table, key_name, set_clipboard_text,
+};
+use render_texture::add_render_texture_methods;
+use rlm::create_rlm_table;
+use run::register_run_functions;
+use shader::add_shader_methods;
+use shapes::add_shape_methods;
+use text_layout::{add_text_layout_methods, register_text_layout_functions};
+use texture::add_texture_methods;
+pub use vector2::LuaVector2;
+pub use vector3::LuaVector3;
+use window::{WindowOptions, add_window_methods};
+
+/// Shared flag telling resources whether what created them (the window, the
+/// audio device or a render texture) is still open.
+#[derive(Clone)]
+pub struct LivenessToken(Rc<Cell<bool>>);
+
+impl LivenessToken {
+    fn new() -> Self {
+        LivenessToken(Rc::new(Cell::new(true)))
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.0.get()
+    }
+
+    fn invalidate(&self) {
+        self.0.set(false);
+    }
+}
 
-// Thread-local storage for the current draw handle
+// Liveness token of the window opened by `init_window`
 thread_local! {
-    static DRAW_HANDLE: RefCell<Option<*mut RaylibDrawHandle<'static>>> = RefCell::new(None);
+    static CURRENT_WINDOW: RefCell<Option<LivenessToken>> = const { RefCell::new(None) };
 }
 
-#[allow(dead_code)]
-struct LuaRaylib<'l> {
+/// Token of the open window, for GPU loaders that are module functions.
+pub fn current_window(function: &str) -> LuaResult<LivenessToken> {
+    match CURRENT_WINDOW.with(|current| current.borrow().clone()) {
+        Some(window) if window.is_alive() => Ok(window),
+        Some(_) => Err(window_closed(function)),
+        None => Err(LuaError::runtime(format!(
+            "{} needs an open window, call rl.init_window first",
+            function
+        ))),
+    }
+}
+
+fn window_closed(function: &str) -> LuaError {
+    LuaError::runtime(format!("{}: window has been closed", function))
+}
+
+struct LuaRaylib {
     rl: RaylibHandle,
     thread: RaylibThread,
-    dh: Option<RaylibDrawHandle<'l>>,
+    alive: LivenessToken,
+}
+
+impl LuaRaylib {
+    /// Fail once the window is closed, so loaders never create GPU resources
+    /// without a GL context.
+    fn check_open(&self, function: &str) -> LuaResult<()> {
+        if !self.alive.is_alive() {
+            return Err(window_closed(function));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for LuaRaylib {
+    fn drop(&mut self) {
+        // Runs before `rl` is dropped and closes the window
+        self.alive.invalidate();
+    }
 }
 
 pub fn close_window() {
@@ -21,8 +124,8 @@ pub fn close_window() {
     }
 }
 
-impl<'l> LuaUserData for LuaRaylib<'l> {
-    fn add_methods<'lua, M: LuaUserDataMethods<Self>>(methods: &mut M) {
+impl LuaUserData for LuaRaylib {
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
         methods.add_method_mut("get_random_value", |_, this, (min, max): (i32, i32)| {
             Ok(this.rl.get_random_value::<i32>(min..max))
         });
@@ -31,8 +134,11 @@ impl<'l> LuaUserData for LuaRaylib<'l> {
             Ok(this.rl.window_should_close())
         });
 
-        methods.add_method_mut("close", |_, _this, ()| {
-            close_window();
+        methods.add_method_mut("close", |_, this, ()| {
+            if this.alive.is_alive() {
+                this.alive.invalidate();
+                close_window();
+            }
             Ok(())
         });
 
@@ -55,281 +161,56 @@ impl<'l> LuaUserData for LuaRaylib<'l> {
             Ok(this.rl.get_screen_height())
         });
 
-        // Drawing Functions
-
-        methods.add_method_mut("draw_frame", |_, this, func: LuaFunction| {
-            let mut d = this.rl.begin_drawing(&this.thread);
-
-            // SAFETY: We guarantee the pointer is only valid for the duration of this frame
-            let d_static: *mut RaylibDrawHandle<'static> = unsafe { std::mem::transmute(&mut d) };
-            DRAW_HANDLE.with(|cell| cell.replace(Some(d_static)));
-
-            func.call::<()>(())?;
-
-            DRAW_HANDLE.with(|cell| cell.replace(None));
+        // Drawing
+        add_frame_methods(methods);
+        add_draw_methods(methods);
 
+        // Exit key configuration
+        methods.add_method_mut("set_exit_key", |_, this, key: LuaKey| {
+            this.rl.set_exit_key(Some(key.0));
             Ok(())
         });
 
-        // Direct drawing API (begin/end style)
-        methods.add_method_mut("begin_drawing", |_, this, ()| {
-            // Store the draw handle in the struct
-            let d = this.rl.begin_drawing(&this.thread);
-            // SAFETY: We transmute the lifetime to static and store it in thread-local
-            let d_static: *mut RaylibDrawHandle<'static> =
-                unsafe { std::mem::transmute(Box::into_raw(Box::new(d))) };
-            DRAW_HANDLE.with(|cell| cell.replace(Some(d_static)));
-            Ok(())
-        });
+        // Input - Keyboard
 
-        methods.add_method_mut("end_drawing", |_, _this, ()| {
-            // Clean up the draw handle
-            DRAW_HANDLE.with(|cell| {
-                if let Some(d) = cell.replace(None) {
-                    unsafe {
-                        // Reconstruct the box and let it drop
-                        let _ = Box::from_raw(d);
-                    }
-                }
-            });
-            Ok(())
+        methods.add_method("is_key_pressed", |_, this, key: LuaKey| {
+            Ok(this.rl.is_key_pressed(key.0))
         });
 
-        methods.add_method_mut("clear_background", |_, _this, color: LuaColor| {
-            DRAW_HANDLE.with(|cell| {
-                if let Some(d) = *cell.borrow() {
-                    unsafe {
-                        (*d).clear_background(<LuaColor as Into<Color>>::into(color));
-                    }
-                }
-            });
-            Ok(())
+        methods.add_method("is_key_down", |_, this, key: LuaKey| {
+            Ok(this.rl.is_key_down(key.0))
         });
 
-        methods.add_method_mut(
-            "draw_text",
-            |_, _this, (text, x, y, size, color): (String, i32, i32, i32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_text(
-                                &text,
-                                x,
-                                y,
-                                size,
-                                <LuaColor as Into<Color>>::into(color),
-                            );
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-
-        methods.add_method_mut(
-            "draw_rectangle",
-            |_, _this, (x, y, width, height, color): (i32, i32, i32, i32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_rectangle(
-                                x,
-                                y,
-                                width,
-                                height,
-                                <LuaColor as Into<Color>>::into(color),
-                            );
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-
-        methods.add_method_mut(
-            "draw_rectangle_rec",
-            |_, _this, (rect, color): (LuaRectangle, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_rectangle_rec(
-                                <LuaRectangle as Into<Rectangle>>::into(rect),
-                                <LuaColor as Into<Color>>::into(color),
-                            );
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-
-        methods.add_method_mut(
-            "draw_circle",
-            |_, _this, (x, y, radius, color): (i32, i32, f32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_circle(x, y, radius, <LuaColor as Into<Color>>::into(color));
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-
-        methods.add_method_mut(
-            "draw_triangle",
-            |_, _this, (v1, v2, v3, color): (LuaVector2, LuaVector2, LuaVector2, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_triangle(v1, v2, v3, <LuaColor as Into<Color>>::into(color));
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-
-        methods.add_method_mut(
-            "draw_line",
-            |_, _this, (x1, y1, x2, y2, color): (i32, i32, i32, i32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_line(x1, y1, x2, y2, <LuaColor as Into<Color>>::into(color));
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-
-        methods.add_method_mut(
-            "draw_line_ex",
-            |_, _this, (start_pos, end_pos, thick, color): (LuaVector2, LuaVector2, f32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_line_ex(
-                                start_pos, end_pos, thick, <LuaColor as Into<Color>>::into(color)
-                            );
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-
-        methods.add_method_mut(
-            "draw_pixel",
-            |_, _this, (x, y, color): (i32, i32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_pixel(x, y, <LuaColor as Into<Color>>::into(color));
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-
-        methods.add_method_mut(
-            "draw_ring",
-            |_,
-             _this,
-             (center, inner_radius, outer_radius, start_angle, end_angle, segments, color): (
-                LuaVector2,
-                f32,
-                f32,
-                f32,
-                f32,
-                i32,
-                LuaColor,
-            )| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_ring(
-                                center,
-                                inner_radius,
-                                outer_radius,
-                                start_angle,
-                                end_angle,
-                                segments,
-                                <LuaColor as Into<Color>>::into(color),
-                            );
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
+        methods.add_method("is_key_released", |_, this, key: LuaKey| {
+            Ok(this.rl.is_key_released(key.0))
+        });
 
-        methods.add_method_mut(
-            "draw_rectangle_lines",
-            |_, _this, (x, y, width, height, color): (i32, i32, i32, i32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_rectangle_lines(
-                                x,
-                                y,
-                                width,
-                                height,
-                                <LuaColor as Into<Color>>::into(color),
-                            );
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
+        methods.add_method("is_key_up", |_, this, key: LuaKey| {
+            Ok(this.rl.is_key_up(key.0))
+        });
 
-        methods.add_method_mut(
-            "draw_circle_lines",
-            |_, _this, (x, y, radius, color): (i32, i32, f32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_circle_lines(
-                                x,
-                                y,
-                                radius,
-                                <LuaColor as Into<Color>>::into(color),
-                            );
+        // Key codes without a name (e.g. platform-specific keys) are skipped
+        methods.add_method("get_key_pressed", |_, _this, ()| {
+            loop {
+                match unsafe { ffi::GetKeyPressed() } {
+                    0 => return Ok(None),
+                    code => {
+                        if let Some(name) = key_name(code) {
+                            return Ok(Some(name));
                         }
                     }
-                });
-                Ok(())
-            },
-        );
-
-        // Exit key configuration
-        methods.add_method_mut("set_exit_key", |_, _this, key: i32| {
-            unsafe {
-                ffi::SetExitKey(key);
+                }
             }
-            Ok(())
-        });
-
-        // Input - Keyboard
-
-        methods.add_method("is_key_pressed", |_, this, key: String| {
-            Ok(this.rl.is_key_pressed(str_to_key(&key)))
         });
 
-        methods.add_method("is_key_down", |_, this, key: String| {
-            Ok(this.rl.is_key_down(str_to_key(&key)))
+        methods.add_method("get_char_pressed", |_, _this, ()| {
+            Ok(char_pressed().map(String::from))
         });
 
-        methods.add_method("is_key_released", |_, this, key: String| {
-            Ok(this.rl.is_key_released(str_to_key(&key)))
-        });
+        methods.add_method("get_clipboard_text", |_, _this, ()| Ok(clipboard_text()));
 
-        methods.add_method("is_key_up", |_, this, key: String| {
-            Ok(this.rl.is_key_up(str_to_key(&key)))
+        methods.add_method("set_clipboard_text", |_, _this, text: String| {
+            set_clipboard_text(&text)
         });
 
         // Input - Mouse
@@ -348,24 +229,22 @@ impl<'l> LuaUserData for LuaRaylib<'l> {
 
         methods.add_method("get_mouse_y", |_, this, ()| Ok(this.rl.get_mouse_y()));
 
-        methods.add_method("is_mouse_button_pressed", |_, this, button: String| {
-            let mb = str_to_mouse_button(button.as_str());
-            Ok(this.rl.is_mouse_button_pressed(mb))
-        });
+        methods.add_method(
+            "is_mouse_button_pressed",
+            |_, this, button: LuaMouseButton| Ok(this.rl.is_mouse_button_pressed(button.0)),
+        );
 
-        methods.add_method("is_mouse_button_down", |_, this, button: String| {
-            let mb = str_to_mouse_button(button.as_str());
-            Ok(this.rl.is_mouse_button_down(mb))
+        methods.add_method("is_mouse_button_down", |_, this, button: LuaMouseButton| {
+            Ok(this.rl.is_mouse_button_down(button.0))
         });
 
-        methods.add_method("is_mouse_button_released", |_, this, button: String| {
-            let mb = str_to_mouse_button(button.as_str());
-            Ok(this.rl.is_mouse_button_released(mb))
-        });
+        methods.add_method(
+            "is_mouse_button_released",
+            |_, this, button: LuaMouseButton| Ok(this.rl.is_mouse_button_released(button.0)),
+        );
 
-        methods.add_method("is_mouse_button_up", |_, this, button: String| {
-            let mb = str_to_mouse_button(button.as_str());
-            Ok(this.rl.is_mouse_button_up(mb))
+        methods.add_method("is_mouse_button_up", |_, this, button: LuaMouseButton| {
+            Ok(this.rl.is_mouse_button_up(button.0))
         });
 
         methods.add_method("is_cursor_hidden", |_, this, ()| {
@@ -376,53 +255,6 @@ impl<'l> LuaUserData for LuaRaylib<'l> {
             Ok(this.rl.get_mouse_wheel_move())
         });
 
-        // Drawing - circle with vector
-        methods.add_method_mut(
-            "draw_circle_v",
-            |_lua, _this, (center, radius, color): (LuaValue, f32, LuaColor)| {
-                let (x, y) = match &center {
-                    LuaValue::Table(t) => {
-                        let x: f32 = t.get("x")?;
-                        let y: f32 = t.get("y")?;
-                        (x, y)
-                    }
-                    LuaValue::UserData(ud) => {
-                        // Try to get fields directly from the userdata
-                        let x: f32 = ud.get("x")?;
-                        let y: f32 = ud.get("y")?;
-                        (x, y)
-                    }
-                    _ => {
-                        return Err(LuaError::FromLuaConversionError {
-                            from: "value",
-                            to: "Vector2".to_string(),
-                            message: Some("Expected table {x, y} or Vector2 userdata".to_string()),
-                        });
-                    }
-                };
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_circle(
-                                x as i32,
-                                y as i32,
-                                radius,
-                                <LuaColor as Into<Color>>::into(color),
-                            );
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-
-        // Window management
-        methods.add_method("close", |_, _this, ()| {
-            // Window closes automatically when dropped, but we can provide this as a no-op
-            // for API compatibility
-            Ok(())
-        });
-
         // Alias for window_should_close for convenience
         methods.add_method_mut("should_close", |_, this, ()| {
             Ok(this.rl.window_should_close())
@@ -450,156 +282,24 @@ impl<'l> LuaUserData for LuaRaylib<'l> {
         methods.add_method_mut("get_touch_position", |_, this, index: u32| {
             Ok(LuaVector2::from(this.rl.get_touch_position(index)))
         });
-    }
-}
-
-// Lua wrapper for RaylibDrawHandle
-#[allow(dead_code)]
-struct LuaDrawHandle<'a> {
-    handle: &'a mut RaylibDrawHandle<'a>,
-}
-
-impl<'a> LuaUserData for LuaDrawHandle<'a> {
-    fn add_methods<'lua, M: LuaUserDataMethods<Self>>(methods: &mut M) {
-        // Register global drawing functions that use DRAW_HANDLE
-        methods.add_function("clear_background", |_, color: LuaColor| {
-            DRAW_HANDLE.with(|cell| {
-                if let Some(d) = *cell.borrow() {
-                    unsafe {
-                        (*d).clear_background(<LuaColor as Into<Color>>::into(color));
-                    }
-                }
-            });
-            Ok(())
-        });
-        methods.add_function(
-            "draw_text",
-            |_, (text, x, y, size, color): (String, i32, i32, i32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_text(
-                                &text,
-                                x,
-                                y,
-                                size,
-                                <LuaColor as Into<Color>>::into(color),
-                            );
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-        methods.add_function(
-            "draw_rectangle",
-            |_, (x, y, width, height, color): (i32, i32, i32, i32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_rectangle(
-                                x,
-                                y,
-                                width,
-                                height,
-                                <LuaColor as Into<Color>>::into(color),
-                            );
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-        methods.add_function(
-            "draw_rectangle_rec",
-            |_, (rect, color): (LuaRectangle, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_rectangle_rec(
-                                <LuaRectangle as Into<Rectangle>>::into(rect),
-                                <LuaColor as Into<Color>>::into(color),
-                            );
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-        methods.add_function(
-            "draw_circle",
-            |_, (x, y, radius, color): (i32, i32, f32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_circle(x, y, radius, <LuaColor as Into<Color>>::into(color));
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-        methods.add_function(
-            "draw_line",
-            |_, (x1, y1, x2, y2, color): (i32, i32, i32, i32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_line(x1, y1, x2, y2, <LuaColor as Into<Color>>::into(color));
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-        methods.add_function("draw_pixel", |_, (x, y, color): (i32, i32, LuaColor)| {
-            DRAW_HANDLE.with(|cell| {
-                if let Some(d) = *cell.borrow() {
-                    unsafe {
-                        (*d).draw_pixel(x, y, <LuaColor as Into<Color>>::into(color));
-                    }
-                }
-            });
-            Ok(())
-        });
 
-        methods.add_function(
-            "draw_rectangle_lines",
-            |_, (x, y, width, height, color): (i32, i32, i32, i32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_rectangle_lines(
-                                x,
-                                y,
-                                width,
-                                height,
-                                <LuaColor as Into<Color>>::into(color),
-                            );
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
-        methods.add_function(
-            "draw_circle_lines",
-            |_, (x, y, radius, color): (i32, i32, f32, LuaColor)| {
-                DRAW_HANDLE.with(|cell| {
-                    if let Some(d) = *cell.borrow() {
-                        unsafe {
-                            (*d).draw_circle_lines(
-                                x,
-                                y,
-                                radius,
-                                <LuaColor as Into<Color>>::into(color),
-                            );
-                        }
-                    }
-                });
-                Ok(())
-            },
-        );
+        // Textures and images
+        add_shape_methods(methods);
+        add_texture_methods(methods);
+        add_render_texture_methods(methods);
+        add_shader_methods(methods);
+        add_draw_mode_methods(methods);
+        add_image_methods(methods);
+
+        // Cameras
+        add_camera_methods(methods);
+
+        // 3D primitives
+        add_draw3d_methods(methods);
+        add_window_methods(methods);
+        add_gamepad_methods(methods);
+        add_font_methods(methods);
+        add_text_layout_methods(methods);
     }
 }
 
@@ -622,6 +322,17 @@ impl From<raylib::ffi::Color> for LuaColor {
     }
 }
 
+impl From<Color> for LuaColor {
+    fn from(value: Color) -> Self {
+        LuaColor {
+            r: value.r,
+            g: value.g,
+            b: value.b,
+            a: value.a,
+        }
+    }
+}
+
 impl From<LuaColor> for Color {
     fn from(value: LuaColor) -> Self {
         Color::new(value.r, value.g, value.b, value.a)
@@ -639,7 +350,7 @@ impl From<LuaColor> for raylib::ffi::Color {
     }
 }
 
-impl<'lua> FromLua for LuaColor {
+impl FromLua for LuaColor {
     fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
         match value {
             LuaValue::Table(t) => Ok(LuaColor {
@@ -657,104 +368,6 @@ impl<'lua> FromLua for LuaColor {
     }
 }
 
-pub fn str_to_mouse_button(s: &str) -> MouseButton {
-    match s.to_uppercase().as_str() {
-        "LEFT" => MouseButton::MOUSE_BUTTON_LEFT,
-        "RIGHT" => MouseButton::MOUSE_BUTTON_RIGHT,
-        "MIDDLE" => MouseButton::MOUSE_BUTTON_MIDDLE,
-        "SIDE" => MouseButton::MOUSE_BUTTON_SIDE,
-        "EXTRA" => MouseButton::MOUSE_BUTTON_EXTRA,
-        "FORWARD" => MouseButton::MOUSE_BUTTON_FORWARD,
-        "BACK" => MouseButton::MOUSE_BUTTON_BACK,
-        // Maybe make this safe later
-        _ => MouseButton::MOUSE_BUTTON_LEFT,
-    }
-}
-
-pub fn str_to_key(s: &str) -> KeyboardKey {
-    match s.to_uppercase().as_str() {
-        "SPACE" => KeyboardKey::KEY_SPACE,
-        "ESCAPE" | "ESC" => KeyboardKey::KEY_ESCAPE,
-        "ENTER" | "RETURN" => KeyboardKey::KEY_ENTER,
-        "TAB" => KeyboardKey::KEY_TAB,
-        "BACKSPACE" => KeyboardKey::KEY_BACKSPACE,
-        "INSERT" => KeyboardKey::KEY_INSERT,
-        "DELETE" => KeyboardKey::KEY_DELETE,
-        "RIGHT" => KeyboardKey::KEY_RIGHT,
-        "LEFT" => KeyboardKey::KEY_LEFT,
-        "DOWN" => KeyboardKey::KEY_DOWN,
-        "UP" => KeyboardKey::KEY_UP,
-        "PAGE_UP" => KeyboardKey::KEY_PAGE_UP,
-        "PAGE_DOWN" => KeyboardKey::KEY_PAGE_DOWN,
-        "HOME" => KeyboardKey::KEY_HOME,
-        "END" => KeyboardKey::KEY_END,
-
-        // Letters
-        "A" => KeyboardKey::KEY_A,
-        "B" => KeyboardKey::KEY_B,
-        "C" => KeyboardKey::KEY_C,
-        "D" => KeyboardKey::KEY_D,
-        "E" => KeyboardKey::KEY_E,
-        "F" => KeyboardKey::KEY_F,
-        "G" => KeyboardKey::KEY_G,
-        "H" => KeyboardKey::KEY_H,
-        "I" => KeyboardKey::KEY_I,
-        "J" => KeyboardKey::KEY_J,
-        "K" => KeyboardKey::KEY_K,
-        "L" => KeyboardKey::KEY_L,
-        "M" => KeyboardKey::KEY_M,
-        "N" => KeyboardKey::KEY_N,
-        "O" => KeyboardKey::KEY_O,
-        "P" => KeyboardKey::KEY_P,
-        "Q" => KeyboardKey::KEY_Q,
-        "R" => KeyboardKey::KEY_R,
-        "S" => KeyboardKey::KEY_S,
-        "T" => KeyboardKey::KEY_T,
-        "U" => KeyboardKey::KEY_U,
-        "V" => KeyboardKey::KEY_V,
-        "W" => KeyboardKey::KEY_W,
-        "X" => KeyboardKey::KEY_X,
-        "Y" => KeyboardKey::KEY_Y,
-        "Z" => KeyboardKey::KEY_Z,
-
-        // Numbers
-        "0" => KeyboardKey::KEY_ZERO,
-        "1" => KeyboardKey::KEY_ONE,
-        "2" => KeyboardKey::KEY_TWO,
-        "3" => KeyboardKey::KEY_THREE,
-        "4" => KeyboardKey::KEY_FOUR,
-        "5" => KeyboardKey::KEY_FIVE,
-        "6" => KeyboardKey::KEY_SIX,
-        "7" => KeyboardKey::KEY_SEVEN,
-        "8" => KeyboardKey::KEY_EIGHT,
-        "9" => KeyboardKey::KEY_NINE,
-
-        // Function keys
-        "F1" => KeyboardKey::KEY_F1,
-        "F2" => KeyboardKey::KEY_F2,
-        "F3" => KeyboardKey::KEY_F3,
-        "F4" => KeyboardKey::KEY_F4,
-        "F5" => KeyboardKey::KEY_F5,
-        "F6" => KeyboardKey::KEY_F6,
-        "F7" => KeyboardKey::KEY_F7,
-        "F8" => KeyboardKey::KEY_F8,
-        "F9" => KeyboardKey::KEY_F9,
-        "F10" => KeyboardKey::KEY_F10,
-        "F11" => KeyboardKey::KEY_F11,
-        "F12" => KeyboardKey::KEY_F12,
-
-        // Modifiers
-        "SHIFT" | "LEFT_SHIFT" => KeyboardKey::KEY_LEFT_SHIFT,
-        "RIGHT_SHIFT" => KeyboardKey::KEY_RIGHT_SHIFT,
-        "CTRL" | "CONTROL" | "LEFT_CONTROL" => KeyboardKey::KEY_LEFT_CONTROL,
-        "RIGHT_CONTROL" => KeyboardKey::KEY_RIGHT_CONTROL,
-        "ALT" | "LEFT_ALT" => KeyboardKey::KEY_LEFT_ALT,
-        "RIGHT_ALT" => KeyboardKey::KEY_RIGHT_ALT,
-
-        _ => KeyboardKey::KEY_NULL,
-    }
-}
-
 pub fn str_to_gesture(gesture: &str) -> Gesture {
     match gesture {
         "TAP" | "tap" | "Tap" => Gesture::GESTURE_TAP,
@@ -820,34 +433,25 @@ pub fn luagesture_to_str(gesture: LuaGesture) -> &'static str {
     }
 }
 
-/// Convert integer to MouseButton enum
-pub fn int_to_mouse_button(button: i32) -> MouseButton {
-    match button {
-        0 => MouseButton::MOUSE_BUTTON_LEFT,
-        1 => MouseButton::MOUSE_BUTTON_RIGHT,
-        2 => MouseButton::MOUSE_BUTTON_MIDDLE,
-        3 => MouseButton::MOUSE_BUTTON_SIDE,
-        4 => MouseButton::MOUSE_BUTTON_EXTRA,
-        5 => MouseButton::MOUSE_BUTTON_FORWARD,
-        6 => MouseButton::MOUSE_BUTTON_BACK,
-        _ => MouseButton::MOUSE_BUTTON_LEFT,
-    }
-}
-
 // Library Functions
 
 /// Initialize window and OpenGL context
-fn init_window<'l>(
-    _lua: &Lua,
-    (width, height, title): (i32, i32, String),
-) -> LuaResult<LuaRaylib<'l>> {
-    let (rl, thread) = raylib::init().size(width, height).title(&title).build();
-
-    Ok(LuaRaylib {
-        rl,
-        thread,
-        dh: None,
-    })
+fn init_window(
+    lua: &Lua,
+    (width, height, title, options): (i32, i32, String, WindowOptions),
+) -> LuaResult<LuaAnyUserData> {
+    if let Some(strict) = options.strict {
+        set_strict(strict);
+    }
+    // A hot reload re-executes the main script; keep the window it opened
+    if let Some(window) = hot_reload::reloading_window(lua) {
+        return Ok(window);
+    }
+    let (rl, thread) = options.build(width, height, &title);
+    let alive = LivenessToken::new();
+    CURRENT_WINDOW.with(|current| *current.borrow_mut() = Some(alive.clone()));
+
+    lua.create_userdata(LuaRaylib { rl, thread, alive })
 }
 
 /// Create color from RGBA values
@@ -873,6 +477,25 @@ fn check_collision_point_rec(
     Ok(Rectangle::from(rec).check_collision_point_rec(point))
 }
 
+/// Registry key of the metatable given to colors made by the binding, so a
+/// color can be told apart from a plain list of four numbers.
+const COLOR_METATABLE: &str = "rlmlua.Color";
+
+fn color_metatable(lua: &Lua) -> LuaResult<LuaTable> {
+    if let Some(metatable) = lua.named_registry_value::<Option<LuaTable>>(COLOR_METATABLE)? {
+        return Ok(metatable);
+    }
+    let metatable = lua.create_table()?;
+    lua.set_named_registry_value(COLOR_METATABLE, &metatable)?;
+    Ok(metatable)
+}
+
+/// Whether `table` is a color returned by the binding, such as an
+/// `rl.colors` entry or the result of `rlm.color_from_hex`.
+pub fn is_color_table(lua: &Lua, table: &LuaTable) -> LuaResult<bool> {
+    Ok(table.metatable() == Some(color_metatable(lua)?))
+}
+
 impl IntoLua for LuaColor {
     fn into_lua(self, lua: &Lua) -> LuaResult<LuaValue> {
         let table = lua.create_table()?;
@@ -880,6 +503,7 @@ impl IntoLua for LuaColor {
         table.set(2, self.g)?;
         table.set(3, self.b)?;
         table.set(4, self.a)?;
+        table.set_metatable(Some(color_metatable(lua)?))?;
         Ok(LuaValue::Table(table))
     }
 }
@@ -1154,261 +778,6 @@ fn register_colors(lua: &Lua, exports: &LuaTable) -> LuaResult<()> {
     Ok(())
 }
 
-#[derive(Clone, Copy)]
-pub struct LuaVector2 {
-    pub x: f32,
-    pub y: f32,
-}
-
-impl<'l> LuaUserData for LuaVector2 {
-    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
-        fields.add_field_method_get("x", |_, this| Ok(this.x));
-        fields.add_field_method_set("x", |_, this, val| {
-            this.x = val;
-            Ok(())
-        });
-        fields.add_field_method_get("y", |_, this| Ok(this.y));
-        fields.add_field_method_set("y", |_, this, val| {
-            this.y = val;
-            Ok(())
-        });
-    }
-
-    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
-        methods.add_method("__add", |_, this, other: LuaVector2| {
-            Ok(LuaVector2 {
-                x: this.x + other.x,
-                y: this.y + other.y,
-            })
-        });
-        methods.add_method("__sub", |_, this, other: LuaVector2| {
-            Ok(LuaVector2 {
-                x: this.x - other.x,
-                y: this.y - other.y,
-            })
-        });
-        methods.add_method("__mul", |_, this, other: LuaVector2| {
-            Ok(LuaVector2 {
-                x: this.x * other.x,
-                y: this.y * other.y,
-            })
-        });
-        methods.add_method("__div", |_, this, other: LuaVector2| {
-            Ok(LuaVector2 {
-                x: this.x / other.x,
-                y: this.y / other.y,
-            })
-        });
-        methods.add_method("__mod", |_, this, other: LuaVector2| {
-            Ok(LuaVector2 {
-                x: this.x % other.x,
-                y: this.y % other.y,
-            })
-        });
-        methods.add_method("__pow", |_, this, other: LuaVector2| {
-            Ok(LuaVector2 {
-                x: this.x.powf(other.x),
-                y: this.y.powf(other.y),
-            })
-        });
-        methods.add_method("__unm", |_, this, ()| {
-            Ok(LuaVector2 {
-                x: -this.x,
-                y: -this.y,
-            })
-        });
-        methods.add_method("__len", |_, this, ()| Ok(this.x.hypot(this.y)));
-        methods.add_method("__eq", |_, this, other: LuaVector2| {
-            Ok((this.x - other.x).abs() < f32::EPSILON && (this.y - other.y).abs() < f32::EPSILON)
-        });
-
-        methods.add_method("__lt", |_, this, other: LuaVector2| {
-            Ok(this.x < other.x && this.y < other.y)
-        });
-        methods.add_method("__le", |_, this, other: LuaVector2| {
-            Ok(this.x <= other.x && this.y <= other.y)
-        });
-        methods.add_method("__gt", |_, this, other: LuaVector2| {
-            Ok(this.x > other.x && this.y > other.y)
-        });
-        methods.add_method("__ge", |_, this, other: LuaVector2| {
-            Ok(this.x >= other.x && this.y >= other.y)
-        });
-        methods.add_method("__index", |_, this, key: String| match key.as_str() {
-            "x" => Ok(this.x),
-            "y" => Ok(this.y),
-            _ => Err(LuaError::runtime(format!(
-                "LuaVector2 has no field '{}'",
-                key
-            ))),
-        });
-    }
-}
-
-impl From<LuaVector2> for Vector2 {
-    fn from(value: LuaVector2) -> Self {
-        Vector2::new(value.x, value.y)
-    }
-}
-
-impl From<LuaVector2> for raylib::ffi::Vector2 {
-    fn from(value: LuaVector2) -> Self {
-        raylib::ffi::Vector2 {
-            x: value.x,
-            y: value.y,
-        }
-    }
-}
-
-impl From<Vector2> for LuaVector2 {
-    fn from(value: Vector2) -> Self {
-        LuaVector2 {
-            x: value.x,
-            y: value.y,
-        }
-    }
-}
-
-impl<'lua> FromLua for LuaVector2 {
-    fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
-        match value {
-            LuaValue::Table(table) => {
-                let x = table.get("x")?;
-                let y = table.get("y")?;
-                Ok(LuaVector2 { x, y })
-            }
-            LuaValue::UserData(ud) => {
-                // Try to extract LuaVector2 from userdata
-                ud.borrow::<LuaVector2>().map(|v| *v)
-            }
-            _ => Err(LuaError::FromLuaConversionError {
-                from: value.type_name(),
-                to: "LuaVector2".to_string(),
-                message: Some("expected table with x,y fields or Vector2 userdata".to_string()),
-            }),
-        }
-    }
-}
-
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct LuaVector3 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-}
-
-impl From<LuaVector3> for Vector3 {
-    fn from(value: LuaVector3) -> Self {
-        Vector3::new(value.x, value.y, value.z)
-    }
-}
-
-impl From<Vector3> for LuaVector3 {
-    fn from(value: Vector3) -> Self {
-        LuaVector3 {
-            x: value.x,
-            y: value.y,
-            z: value.z,
-        }
-    }
-}
-
-impl<'l> LuaUserData for LuaVector3 {
-    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
-        fields.add_field_method_get("x", |_, this| Ok(this.x));
-        fields.add_field_method_get("y", |_, this| Ok(this.y));
-        fields.add_field_method_get("z", |_, this| Ok(this.z));
-    }
-    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
-        methods.add_method("new", |_, _this, (x, y, z): (f32, f32, f32)| {
-            Ok(LuaVector3 { x, y, z })
-        });
-        methods.add_method("__add", |_, this, (x, y, z): (f32, f32, f32)| {
-            Ok(LuaVector3 {
-                x: this.x + x,
-                y: this.y + y,
-                z: this.z + z,
-            })
-        });
-        methods.add_method("__sub", |_, this, (x, y, z): (f32, f32, f32)| {
-            Ok(LuaVector3 {
-                x: this.x - x,
-                y: this.y - y,
-                z: this.z - z,
-            })
-        });
-        methods.add_method("__mul", |_, this, (x, y, z): (f32, f32, f32)| {
-            Ok(LuaVector3 {
-                x: this.x * x,
-                y: this.y * y,
-                z: this.z * z,
-            })
-        });
-        methods.add_method("__div", |_, this, (x, y, z): (f32, f32, f32)| {
-            Ok(LuaVector3 {
-                x: this.x / x,
-                y: this.y / y,
-                z: this.z / z,
-            })
-        });
-        methods.add_method("__mod", |_, this, (x, y, z): (f32, f32, f32)| {
-            Ok(LuaVector3 {
-                x: this.x % x,
-                y: this.y % y,
-                z: this.z % z,
-            })
-        });
-        methods.add_method("__pow", |_, this, (x, y, z): (f32, f32, f32)| {
-            Ok(LuaVector3 {
-                x: this.x.powf(x),
-                y: this.y.powf(y),
-                z: this.z.powf(z),
-            })
-        });
-        methods.add_method("__unm", |_, this, ()| {
-            Ok(LuaVector3 {
-                x: -this.x,
-                y: -this.y,
-                z: -this.z,
-            })
-        });
-        methods.add_method("__len", |_, this, ()| {
-            Ok(this.x.hypot(this.y).hypot(this.z))
-        });
-        methods.add_method("__eq", |_, this, other: LuaVector3| {
-            Ok(this.x == other.x && this.y == other.y && this.z == other.z)
-        });
-        methods.add_method("__index", |_, this, key: String| match key.as_str() {
-            "x" => Ok(this.x),
-            "y" => Ok(this.y),
-            "z" => Ok(this.z),
-            _ => Err(LuaError::FromLuaConversionError {
-                from: "Vector3",
-                to: key,
-                message: None,
-            }),
-        });
-    }
-}
-
-impl FromLua for LuaVector3 {
-    fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
-        match value {
-            LuaValue::Table(table) => {
-                let x: f32 = table.get("x")?;
-                let y: f32 = table.get("y")?;
-                let z: f32 = table.get("z")?;
-                Ok(LuaVector3 { x, y, z })
-            }
-            _ => Err(LuaError::FromLuaConversionError {
-                from: value.type_name(),
-                to: "LuaVector3".to_string(),
-                message: None,
-            }),
-        }
-    }
-}
-
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct LuaRectangle {
     pub x: f32,
@@ -1439,7 +808,7 @@ impl From<LuaRectangle> for raylib::ffi::Rectangle {
     }
 }
 
-impl<'lua> FromLua for LuaRectangle {
+impl FromLua for LuaRectangle {
     fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
         match value {
             LuaValue::Table(table) => {
@@ -1492,22 +861,19 @@ impl LuaUserData for LuaRectangle {
     }
 }
 
-pub fn vector2<'lua>(_lua: &Lua, (x, y): (f32, f32)) -> LuaResult<LuaVector2> {
+pub fn vector2(_lua: &Lua, (x, y): (f32, f32)) -> LuaResult<LuaVector2> {
     Ok(LuaVector2 { x, y })
 }
 
-pub fn vector3<'lua>(_lua: &Lua, (x, y, z): (f32, f32, f32)) -> LuaResult<LuaVector3> {
+pub fn vector3(_lua: &Lua, (x, y, z): (f32, f32, f32)) -> LuaResult<LuaVector3> {
     Ok(LuaVector3 { x, y, z })
 }
 
-pub fn rect<'lua>(
-    _lua: &Lua,
-    (x, y, width, height): (f32, f32, f32, f32),
-) -> LuaResult<LuaRectangle> {
+pub fn rect(_lua: &Lua, (x, y, width, height): (f32, f32, f32, f32)) -> LuaResult<LuaRectangle> {
     Ok(LuaRectangle::new(x, y, width, height))
 }
 
-pub fn gesture<'lua>(_lua: &Lua, _gesture: String) -> LuaResult<LuaGesture> {
+pub fn gesture(_lua: &Lua, _gesture: String) -> LuaResult<LuaGesture> {
     match _gesture.as_str() {
         "tap" | "TAP" | "Tap" => Ok(LuaGesture::Tap),
         "double_tap" | "doubletap" | "DOUBLE_TAP" | "DoubleTap" | "DOUBLETAP" => {
@@ -1525,7 +891,7 @@ pub fn gesture<'lua>(_lua: &Lua, _gesture: String) -> LuaResult<LuaGesture> {
     }
 }
 
-pub fn gesture_from_str<'lua>(_lua: &Lua, _gesture: &str) -> LuaResult<LuaGesture> {
+pub fn gesture_from_str(_lua: &Lua, _gesture: &str) -> LuaResult<LuaGesture> {
     match _gesture {
         "tap" | "TAP" | "Tap" => Ok(LuaGesture::Tap),
         "double_tap" | "doubletap" | "DOUBLE_TAP" | "DoubleTap" | "DOUBLETAP" => {
@@ -1594,7 +960,7 @@ impl From<Gesture> for LuaGesture {
     }
 }
 
-impl<'lua> FromLua for LuaGesture {
+impl FromLua for LuaGesture {
     fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
         match value {
             LuaValue::UserData(ud) => ud.borrow::<LuaGesture>().map(|g| *g),
@@ -1673,8 +1039,8 @@ fn gesture_to_int(gesture: LuaGesture) -> i32 {
 
 // Module Entry Point
 
-#[mlua::lua_module]
-fn raylib_lua(lua: &Lua) -> LuaResult<LuaTable> {
+#[cfg_attr(feature = "module", mlua::lua_module)]
+pub fn raylib_lua(lua: &Lua) -> LuaResult<LuaTable> {
     let exports = lua.create_table()?;
 
     // Core functions
@@ -1691,9 +1057,32 @@ fn raylib_lua(lua: &Lua) -> LuaResult<LuaTable> {
     exports.set("vec3", lua.create_function(vector3)?)?;
     exports.set("rect", lua.create_function(rect)?)?;
 
+    // The rlm_lua helpers, for installs that only ship raylib_lua
+    exports.set("rlm", create_rlm_table(lua)?)?;
+
     // Register color constants
     register_colors(lua, &exports)?;
 
+    // Key, mouse button and gamepad codes
+    exports.set("keys", create_code_table(lua, KEYS)?)?;
+    exports.set("mouse_buttons", create_code_table(lua, MOUSE_BUTTONS)?)?;
+    exports.set("gamepad_buttons", create_code_table(lua, GAMEPAD_BUTTONS)?)?;
+    exports.set("gamepad_axes", create_code_table(lua, GAMEPAD_AXES)?)?;
+
+    // Image loading and generation
+    register_image_functions(lua, &exports)?;
+
+    // Strict mode and the managed game loop
+    register_frame_functions(lua, &exports)?;
+    register_run_functions(lua, &exports)?;
+
+    // Fonts
+    register_font_functions(lua, &exports)?;
+    register_text_layout_functions(lua, &exports)?;
+
+    // Audio
+    register_audio_functions(lua, &exports)?;
+
     // Version info
     exports.set("_VERSION", "0.1.0")?;
     exports.set("_DESCRIPTION", "Raylib bindings for Lua")?;
@@ -1712,21 +1101,33 @@ fn raylib_lua(lua: &Lua) -> LuaResult<LuaTable> {
     Ok(exports)
 }
 
-#[mlua::lua_module]
-fn rlm_lua(lua: &Lua) -> LuaResult<LuaTable> {
-    let exports = lua.create_table()?;
-
-    // Vector2 functions
-    exports.set("vec2", lua.create_function(vector2)?)?;
-    exports.set("vec3", lua.create_function(vector3)?)?;
-    exports.set("rect", lua.create_function(rect)?)?;
-
-    // Version info
-    exports.set("_VERSION", "0.1.0")?;
-    exports.set(
-        "_DESCRIPTION",
-        "rlm-related functions and helpers for Raylib/rlmlua",
-    )?;
+#[cfg_attr(feature = "module", mlua::lua_module)]
+pub fn rlm_lua(lua: &Lua) -> LuaResult<LuaTable> {
+    create_rlm_table(lua)
+}
 
-    Ok(exports)
+/// Lua wrappers from `lua/`, embedded so an in-process state needs no files.
+const LUA_WRAPPERS: &[(&str, &str)] = &[
+    ("raylib", include_str!("../lua/raylib/init.lua")),
+    ("rlmlua", include_str!("../lua/rlmlua/init.lua")),
+];
+
+/// Register the modules in a Lua state owned by the host program, so scripts
+/// can `require("raylib")` and `require("rlmlua")` without installing the
+/// shared library.
+pub fn register_modules(lua: &Lua) -> LuaResult<()> {
+    let package: LuaTable = lua.globals().get("package")?;
+    let loaded: LuaTable = package.get("loaded")?;
+    loaded.set("raylib_lua", raylib_lua(lua)?)?;
+    loaded.set("rlm_lua", rlm_lua(lua)?)?;
+
+    let preload: LuaTable = package.get("preload")?;
+    for (name, source) in LUA_WRAPPERS {
+        let loader = lua
+            .load(*source)
+            .set_name(format!("={}", name))
+            .into_function()?;
+        preload.set(*name, loader)?;
+    }
+    Ok(())
 }
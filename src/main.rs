@@ -0,0 +1,119 @@
+//! Standalone runner: `rlmlua [game-dir | script.lua] [args...]`.
+//!
+//! Runs a game without a system Lua or an installed module. Lua is compiled
+//! in and the `raylib` and `rlmlua` modules are registered in-process.
+
+use mlua::prelude::*;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Script run when the runner is given a directory.
+const MAIN_SCRIPT: &str = "main.lua";
+
+/// The script to run for `target`: `target` itself, or `main.lua` inside it
+/// when it is a directory.
+fn resolve_script(target: &Path) -> Result<PathBuf, String> {
+    let script = if target.is_dir() {
+        target.join(MAIN_SCRIPT)
+    } else {
+        target.to_path_buf()
+    };
+    if !script.is_file() {
+        return Err(format!("cannot find {}", script.display()));
+    }
+    Ok(script)
+}
+
+/// `package.path` with the script's directory searched first, so the game can
+/// `require` its own modules from wherever the runner was started.
+fn package_path(script_dir: &Path, current: &str) -> String {
+    let dir = script_dir.to_string_lossy();
+    let dir = if dir.is_empty() { "." } else { &dir };
+    format!("{dir}/?.lua;{dir}/?/init.lua;{current}")
+}
+
+/// Build the standard `arg` table: the script at index 0, the runner at -1
+/// and the script arguments from 1.
+fn create_arg_table(
+    lua: &Lua,
+    program: &str,
+    script: &str,
+    args: &[String],
+) -> LuaResult<LuaTable> {
+    let table = lua.create_table()?;
+    table.set(-1, program)?;
+    table.set(0, script)?;
+    for (i, arg) in args.iter().enumerate() {
+        table.set(i + 1, arg.as_str())?;
+    }
+    Ok(table)
+}
+
+fn run(program: &str, target: &Path, args: &[String]) -> LuaResult<()> {
+    let script = resolve_script(target).map_err(LuaError::runtime)?;
+    let source = std::fs::read(&script)
+        .map_err(|e| LuaError::runtime(format!("cannot read {}: {}", script.display(), e)))?;
+    let script_name = script.to_string_lossy().into_owned();
+
+    let lua = Lua::new();
+    rlmlua::register_modules(&lua)?;
+
+    let package: LuaTable = lua.globals().get("package")?;
+    let script_dir = script.parent().unwrap_or(Path::new("."));
+    let path: String = package.get("path")?;
+    package.set("path", package_path(script_dir, &path))?;
+
+    lua.globals()
+        .set("arg", create_arg_table(&lua, program, &script_name, args)?)?;
+
+    lua.load(source)
+        .set_name(format!("@{}", script_name))
+        .call::<()>(args.to_vec())
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args();
+    let program = args.next().unwrap_or_else(|| "rlmlua".to_string());
+    let target = args.next().unwrap_or_else(|| ".".to_string());
+    if target == "-h" || target == "--help" {
+        println!("usage: {} [game-dir | script.lua] [args...]", program);
+        println!(
+            "Runs {} from game-dir, or script.lua, with raylib built in.",
+            MAIN_SCRIPT
+        );
+        return ExitCode::SUCCESS;
+    }
+    let args: Vec<String> = args.collect();
+
+    match run(&program, Path::new(&target), &args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}: {}", program, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_directory_is_searched_before_the_default_path() {
+        assert_eq!(
+            package_path(Path::new("games/pong"), "./?.lua"),
+            "games/pong/?.lua;games/pong/?/init.lua;./?.lua"
+        );
+        assert_eq!(package_path(Path::new(""), ""), "./?.lua;./?/init.lua;");
+    }
+
+    #[test]
+    fn directories_resolve_to_their_main_script() {
+        let dir = std::env::temp_dir().join(format!("rlmlua-runner-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert!(resolve_script(&dir).unwrap_err().contains(MAIN_SCRIPT));
+        std::fs::write(dir.join(MAIN_SCRIPT), "").unwrap();
+        assert_eq!(resolve_script(&dir).unwrap(), dir.join(MAIN_SCRIPT));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,216 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::cell::Cell;
+
+use crate::camera::active_camera_mode;
+use crate::frame::with_scoped_mode;
+use crate::texture::LuaTexture;
+use crate::{LivenessToken, LuaRaylib, in_frame};
+
+thread_local! {
+    // Id of the render texture that draws are currently redirected to
+    static TEXTURE_MODE: Cell<Option<u32>> = const { Cell::new(None) };
+}
+
+/// Offscreen render target loaded through a window.
+///
+/// Unloaded when collected or closed, like textures. `rt.texture` hands out
+/// its color buffer as a `Texture` that becomes invalid once the render
+/// texture is unloaded.
+pub struct LuaRenderTexture {
+    target: Option<RenderTexture2D>,
+    window: LivenessToken,
+    /// Shared with the textures returned by `rt.texture`.
+    alive: LivenessToken,
+}
+
+impl LuaRenderTexture {
+    fn target(&self) -> LuaResult<&RenderTexture2D> {
+        match &self.target {
+            Some(_) if !self.window.is_alive() => Err(LuaError::runtime(
+                "RenderTexture belongs to a window that has been closed",
+            )),
+            Some(target) => Ok(target),
+            None => Err(LuaError::runtime("RenderTexture has already been unloaded")),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.target.is_some() && self.window.is_alive()
+    }
+
+    fn unload(&mut self) {
+        let Some(target) = self.target.take() else {
+            return;
+        };
+        self.alive.invalidate();
+        if !self.window.is_alive() {
+            // The GL context is gone, there is nothing left to free
+            std::mem::forget(target);
+            return;
+        }
+        // Never leave draws redirected to a framebuffer that no longer exists
+        if TEXTURE_MODE.get() == Some(target.id()) {
+            TEXTURE_MODE.set(None);
+            unsafe { ffi::EndTextureMode() };
+        }
+    }
+}
+
+impl Drop for LuaRenderTexture {
+    fn drop(&mut self) {
+        self.unload();
+    }
+}
+
+impl LuaUserData for LuaRenderTexture {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("id", |_, this| Ok(this.target()?.id()));
+        fields.add_field_method_get("width", |_, this| Ok(this.target()?.texture().width));
+        fields.add_field_method_get("height", |_, this| Ok(this.target()?.texture().height));
+        fields.add_field_method_get("texture", |_, this| {
+            Ok(LuaTexture::borrowed(
+                this.target()?.texture().clone(),
+                this.window.clone(),
+                this.alive.clone(),
+            ))
+        });
+    }
+
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("is_valid", |_, this, ()| Ok(this.is_valid()));
+
+        methods.add_method_mut("unload", |_, this, ()| {
+            this.unload();
+            Ok(())
+        });
+
+        methods.add_meta_method_mut(LuaMetaMethod::Close, |_, this, _: LuaValue| {
+            this.unload();
+            Ok(())
+        });
+    }
+}
+
+fn begin_texture_mode(target: &LuaRenderTexture) -> LuaResult<()> {
+    let target = target.target()?;
+    if !in_frame() {
+        return Err(LuaError::runtime(
+            "begin_texture_mode must be called between begin_drawing and end_drawing",
+        ));
+    }
+    if TEXTURE_MODE.get().is_some() {
+        return Err(LuaError::runtime(
+            "begin_texture_mode called while texture mode is already active",
+        ));
+    }
+    if let Some(mode) = active_camera_mode() {
+        return Err(LuaError::runtime(format!(
+            "begin_texture_mode called while {} mode is active, end it first",
+            mode
+        )));
+    }
+    TEXTURE_MODE.set(Some(target.id()));
+    unsafe { ffi::BeginTextureMode(*target.as_ref()) };
+    Ok(())
+}
+
+fn end_texture_mode() -> LuaResult<()> {
+    if TEXTURE_MODE.get().is_none() {
+        return Err(LuaError::runtime(
+            "end_texture_mode called without a matching begin_texture_mode",
+        ));
+    }
+    if let Some(mode) = active_camera_mode() {
+        return Err(LuaError::runtime(format!(
+            "end_texture_mode called while {} mode is active, end it first",
+            mode
+        )));
+    }
+    TEXTURE_MODE.set(None);
+    unsafe { ffi::EndTextureMode() };
+    Ok(())
+}
+
+/// Whether draws are currently redirected to a render texture.
+pub fn texture_mode_active() -> bool {
+    TEXTURE_MODE.get().is_some()
+}
+
+/// Leave texture mode if the frame ends while it is still active.
+pub fn reset_texture_mode() {
+    if TEXTURE_MODE.take().is_some() {
+        unsafe { ffi::EndTextureMode() };
+    }
+}
+
+/// Render texture loading and texture mode methods on the window.
+pub fn add_render_texture_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    methods.add_method_mut(
+        "load_render_texture",
+        |_, this, (width, height): (u32, u32)| {
+            this.check_open("load_render_texture")?;
+            let target = this
+                .rl
+                .load_render_texture(&this.thread, width, height)
+                .map_err(|e| LuaError::runtime(e.to_string()))?;
+            Ok(LuaRenderTexture {
+                target: Some(target),
+                window: this.alive.clone(),
+                alive: LivenessToken::new(),
+            })
+        },
+    );
+
+    methods.add_method(
+        "begin_texture_mode",
+        |_, _this, target: LuaUserDataRef<LuaRenderTexture>| begin_texture_mode(&target),
+    );
+
+    methods.add_method("end_texture_mode", |_, _this, ()| end_texture_mode());
+
+    methods.add_function(
+        "with_texture_mode",
+        |_,
+         (window, target, func): (
+            LuaAnyUserData,
+            LuaUserDataRef<LuaRenderTexture>,
+            LuaFunction,
+        )| {
+            // The target is borrowed only by begin, so the callback may read it
+            with_scoped_mode(
+                window,
+                func,
+                move || begin_texture_mode(&target),
+                end_texture_mode,
+            )
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::camera::set_test_camera_mode;
+
+    #[test]
+    fn ending_texture_mode_needs_a_begin_and_no_camera_mode() {
+        let err = end_texture_mode().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("end_texture_mode called without a matching begin_texture_mode")
+        );
+
+        TEXTURE_MODE.set(Some(1));
+        set_test_camera_mode(true);
+        let err = end_texture_mode().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("end_texture_mode called while 2D mode is active, end it first")
+        );
+        // Still redirected, the camera mode has to end first
+        assert!(texture_mode_active());
+        set_test_camera_mode(false);
+        TEXTURE_MODE.set(None);
+    }
+}
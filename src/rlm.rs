@@ -0,0 +1,363 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+
+use crate::camera::{camera2d, camera3d};
+use crate::easing::create_ease_table;
+use crate::text_input::text_input;
+use crate::tween::tween;
+use crate::{LuaColor, LuaRectangle, LuaVector2, rect, vector2, vector3};
+
+fn vec2_add(_lua: &Lua, (a, b): (LuaVector2, LuaVector2)) -> LuaResult<LuaVector2> {
+    Ok(LuaVector2::new(a.x + b.x, a.y + b.y))
+}
+
+fn vec2_sub(_lua: &Lua, (a, b): (LuaVector2, LuaVector2)) -> LuaResult<LuaVector2> {
+    Ok(LuaVector2::new(a.x - b.x, a.y - b.y))
+}
+
+fn vec2_scale(_lua: &Lua, (v, s): (LuaVector2, f32)) -> LuaResult<LuaVector2> {
+    Ok(LuaVector2::new(v.x * s, v.y * s))
+}
+
+fn vec2_length(_lua: &Lua, v: LuaVector2) -> LuaResult<f32> {
+    Ok(v.length())
+}
+
+fn vec2_normalize(_lua: &Lua, v: LuaVector2) -> LuaResult<LuaVector2> {
+    Ok(v.normalize())
+}
+
+fn distance(_lua: &Lua, (a, b): (LuaVector2, LuaVector2)) -> LuaResult<f32> {
+    Ok(a.distance(b))
+}
+
+fn vec2_dot(_lua: &Lua, (a, b): (LuaVector2, LuaVector2)) -> LuaResult<f32> {
+    Ok(a.dot(b))
+}
+
+fn vec2_lerp(_lua: &Lua, (a, b, t): (LuaVector2, LuaVector2, f32)) -> LuaResult<LuaVector2> {
+    Ok(a.lerp(b, t))
+}
+
+fn rect_contains_point(_lua: &Lua, (rect, x, y): (LuaRectangle, f32, f32)) -> LuaResult<bool> {
+    Ok(Rectangle::from(rect).check_collision_point_rec(Vector2::new(x, y)))
+}
+
+fn rect_overlaps(_lua: &Lua, (a, b): (LuaRectangle, LuaRectangle)) -> LuaResult<bool> {
+    Ok(Rectangle::from(a).check_collision_recs(&Rectangle::from(b)))
+}
+
+/// Parse `"#rrggbb"`, `"rrggbb"` or the same with a trailing alpha byte.
+fn color_from_hex(_lua: &Lua, hex: String) -> LuaResult<LuaColor> {
+    let digits = hex.strip_prefix('#').unwrap_or(&hex);
+    let invalid = || {
+        LuaError::runtime(format!(
+            "Invalid hex color '{}', expected \"#rrggbb\" or \"#rrggbbaa\"",
+            hex
+        ))
+    };
+    if !(digits.len() == 6 || digits.len() == 8) || !digits.is_ascii() {
+        return Err(invalid());
+    }
+    let byte = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).map_err(|_| invalid());
+    Ok(LuaColor {
+        r: byte(0)?,
+        g: byte(2)?,
+        b: byte(4)?,
+        a: if digits.len() == 8 { byte(6)? } else { 255 },
+    })
+}
+
+fn color_from_hsv(_lua: &Lua, (h, s, v): (f32, f32, f32)) -> LuaResult<LuaColor> {
+    Ok(unsafe { ffi::ColorFromHSV(h, s, v) }.into())
+}
+
+fn color_lerp(_lua: &Lua, (a, b, t): (LuaColor, LuaColor, f32)) -> LuaResult<LuaColor> {
+    let t = t.clamp(0.0, 1.0);
+    let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    Ok(LuaColor {
+        r: mix(a.r, b.r),
+        g: mix(a.g, b.g),
+        b: mix(a.b, b.b),
+        a: mix(a.a, b.a),
+    })
+}
+
+fn color_fade(_lua: &Lua, (color, alpha): (LuaColor, f32)) -> LuaResult<LuaColor> {
+    Ok(unsafe { ffi::Fade(color.into(), alpha) }.into())
+}
+
+fn lerp(_lua: &Lua, (a, b, t): (f64, f64, f64)) -> LuaResult<f64> {
+    Ok(a + (b - a) * t)
+}
+
+fn clamp(_lua: &Lua, (value, min, max): (f64, f64, f64)) -> LuaResult<f64> {
+    if min > max {
+        return Err(LuaError::runtime(format!(
+            "clamp: min ({}) is greater than max ({})",
+            min, max
+        )));
+    }
+    Ok(value.clamp(min, max))
+}
+
+fn map(
+    _lua: &Lua,
+    (value, in_min, in_max, out_min, out_max): (f64, f64, f64, f64, f64),
+) -> LuaResult<f64> {
+    if in_min == in_max {
+        return Err(LuaError::runtime("map: input range is empty"));
+    }
+    Ok(out_min + (value - in_min) * (out_max - out_min) / (in_max - in_min))
+}
+
+fn smoothstep(_lua: &Lua, t: f64) -> LuaResult<f64> {
+    let t = t.clamp(0.0, 1.0);
+    Ok(t * t * (3.0 - 2.0 * t))
+}
+
+fn deg_to_rad(_lua: &Lua, degrees: f64) -> LuaResult<f64> {
+    Ok(degrees.to_radians())
+}
+
+fn rad_to_deg(_lua: &Lua, radians: f64) -> LuaResult<f64> {
+    Ok(radians.to_degrees())
+}
+
+/// Resolution of the random floats, kept within f32 precision.
+const RANDOM_STEPS: i32 = 0xFF_FFFF;
+
+/// Uniform float in `[0, 1]` from raylib's random generator.
+fn random_unit() -> f64 {
+    unsafe { ffi::GetRandomValue(0, RANDOM_STEPS) as f64 / RANDOM_STEPS as f64 }
+}
+
+fn random_range(_lua: &Lua, (min, max): (f64, f64)) -> LuaResult<f64> {
+    Ok(min + (max - min) * random_unit())
+}
+
+fn random_int(_lua: &Lua, (min, max): (i32, i32)) -> LuaResult<i32> {
+    Ok(unsafe { ffi::GetRandomValue(min.min(max), max.max(min)) })
+}
+
+fn random_bool(_lua: &Lua, probability: Option<f64>) -> LuaResult<bool> {
+    Ok(random_unit() < probability.unwrap_or(0.5))
+}
+
+fn random_choice(_lua: &Lua, table: LuaTable) -> LuaResult<LuaValue> {
+    let len = table.raw_len() as i32;
+    if len == 0 {
+        return Err(LuaError::runtime("random_choice: table is empty"));
+    }
+    table.raw_get(unsafe { ffi::GetRandomValue(1, len) })
+}
+
+/// Countdown timer advanced manually with `update(dt)`.
+pub struct LuaTimer {
+    duration: f32,
+    elapsed: f32,
+}
+
+impl LuaTimer {
+    fn is_done(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+        (self.elapsed / self.duration).min(1.0)
+    }
+}
+
+impl LuaUserData for LuaTimer {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("duration", |_, this| Ok(this.duration));
+        fields.add_field_method_set("duration", |_, this, val: f32| {
+            this.duration = val;
+            Ok(())
+        });
+        fields.add_field_method_get("elapsed", |_, this| Ok(this.elapsed));
+    }
+
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method_mut("update", |_, this, dt: f32| {
+            this.elapsed = (this.elapsed + dt).min(this.duration.max(0.0));
+            Ok(this.is_done())
+        });
+        methods.add_method("progress", |_, this, ()| Ok(this.progress()));
+        methods.add_method("is_done", |_, this, ()| Ok(this.is_done()));
+        methods.add_method_mut("reset", |_, this, ()| {
+            this.elapsed = 0.0;
+            Ok(())
+        });
+    }
+}
+
+fn timer(_lua: &Lua, duration: f32) -> LuaResult<LuaTimer> {
+    Ok(LuaTimer {
+        duration,
+        elapsed: 0.0,
+    })
+}
+
+/// Build the rlmlua helper table.
+///
+/// `build.rs` checks every name declared in `lua/rlmlua/meta.lua` against the
+/// `exports.set` calls in this function, so keep them as string literals.
+pub fn create_rlm_table(lua: &Lua) -> LuaResult<LuaTable> {
+    let exports = lua.create_table()?;
+
+    // Constructors
+    exports.set("vec2", lua.create_function(vector2)?)?;
+    exports.set("vec3", lua.create_function(vector3)?)?;
+    exports.set("rect", lua.create_function(rect)?)?;
+    exports.set("camera2d", lua.create_function(camera2d)?)?;
+    exports.set("camera3d", lua.create_function(camera3d)?)?;
+
+    // Vector2 helpers
+    exports.set("vec2_add", lua.create_function(vec2_add)?)?;
+    exports.set("vec2_sub", lua.create_function(vec2_sub)?)?;
+    exports.set("vec2_scale", lua.create_function(vec2_scale)?)?;
+    exports.set("vec2_length", lua.create_function(vec2_length)?)?;
+    exports.set("vec2_normalize", lua.create_function(vec2_normalize)?)?;
+    exports.set("distance", lua.create_function(distance)?)?;
+    exports.set("vec2_dot", lua.create_function(vec2_dot)?)?;
+    exports.set("vec2_lerp", lua.create_function(vec2_lerp)?)?;
+
+    // Rectangle helpers
+    exports.set(
+        "rect_contains_point",
+        lua.create_function(rect_contains_point)?,
+    )?;
+    exports.set("rect_overlaps", lua.create_function(rect_overlaps)?)?;
+
+    // Color helpers
+    exports.set("color_from_hex", lua.create_function(color_from_hex)?)?;
+    exports.set("color_from_hsv", lua.create_function(color_from_hsv)?)?;
+    exports.set("color_lerp", lua.create_function(color_lerp)?)?;
+    exports.set("color_fade", lua.create_function(color_fade)?)?;
+
+    // Math helpers
+    exports.set("lerp", lua.create_function(lerp)?)?;
+    exports.set("clamp", lua.create_function(clamp)?)?;
+    exports.set("map", lua.create_function(map)?)?;
+    exports.set("smoothstep", lua.create_function(smoothstep)?)?;
+    exports.set("deg_to_rad", lua.create_function(deg_to_rad)?)?;
+    exports.set("rad_to_deg", lua.create_function(rad_to_deg)?)?;
+
+    // Random helpers
+    exports.set("random_range", lua.create_function(random_range)?)?;
+    exports.set("random_int", lua.create_function(random_int)?)?;
+    exports.set("random_bool", lua.create_function(random_bool)?)?;
+    exports.set("random_choice", lua.create_function(random_choice)?)?;
+
+    // Animation helpers
+    exports.set("ease", create_ease_table(lua)?)?;
+    exports.set("timer", lua.create_function(timer)?)?;
+    exports.set("tween", lua.create_function(tween)?)?;
+
+    // Text input
+    exports.set("text_input", lua.create_function(text_input)?)?;
+
+    // Version info
+    exports.set("_VERSION", "0.1.0")?;
+    exports.set("_DESCRIPTION", "Math and utility helpers for raylib")?;
+
+    Ok(exports)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timer_progress_is_clamped() {
+        let mut timer = LuaTimer {
+            duration: 2.0,
+            elapsed: 0.5,
+        };
+        assert_eq!(timer.progress(), 0.25);
+        assert!(!timer.is_done());
+        timer.elapsed = 2.0;
+        assert_eq!(timer.progress(), 1.0);
+        assert!(timer.is_done());
+        timer.duration = 0.0;
+        assert_eq!(timer.progress(), 1.0);
+    }
+}
+
+// Tests needing a Lua state, which only links when Lua is built in:
+// `cargo test --no-default-features --features runner`
+#[cfg(all(test, not(feature = "module")))]
+mod lua_tests {
+    use super::*;
+
+    fn rgba(color: LuaColor) -> (u8, u8, u8, u8) {
+        (color.r, color.g, color.b, color.a)
+    }
+
+    #[test]
+    fn hex_colors_take_an_optional_hash_and_alpha() {
+        let lua = Lua::new();
+        let color = color_from_hex(&lua, "#ff8000".to_string()).unwrap();
+        assert_eq!(rgba(color), (255, 128, 0, 255));
+        let color = color_from_hex(&lua, "10203040".to_string()).unwrap();
+        assert_eq!(rgba(color), (16, 32, 48, 64));
+        for bad in ["#fff", "#gg0000", "#ff00000"] {
+            let err = color_from_hex(&lua, bad.to_string()).err().unwrap();
+            assert!(err.to_string().contains("Invalid hex color"));
+        }
+    }
+
+    #[test]
+    fn math_helpers_check_their_ranges() {
+        let lua = Lua::new();
+        assert_eq!(lerp(&lua, (2.0, 4.0, 0.25)).unwrap(), 2.5);
+        assert_eq!(clamp(&lua, (5.0, 0.0, 1.0)).unwrap(), 1.0);
+        assert!(clamp(&lua, (0.5, 1.0, 0.0)).is_err());
+        assert_eq!(map(&lua, (5.0, 0.0, 10.0, 100.0, 200.0)).unwrap(), 150.0);
+        assert!(map(&lua, (5.0, 1.0, 1.0, 0.0, 1.0)).is_err());
+        assert_eq!(smoothstep(&lua, 2.0).unwrap(), 1.0);
+        assert_eq!(smoothstep(&lua, 0.5).unwrap(), 0.5);
+    }
+
+    #[test]
+    fn color_lerp_clamps_t() {
+        let lua = Lua::new();
+        let black = LuaColor {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 255,
+        };
+        let white = LuaColor {
+            r: 255,
+            g: 255,
+            b: 255,
+            a: 255,
+        };
+        let mid = color_lerp(&lua, (black, white, 0.5)).unwrap();
+        assert_eq!(rgba(mid), (128, 128, 128, 255));
+        let past = color_lerp(&lua, (black, white, 2.0)).unwrap();
+        assert_eq!(rgba(past), (255, 255, 255, 255));
+    }
+
+    #[test]
+    fn helpers_are_exported_by_name() {
+        let lua = Lua::new();
+        let rlm = create_rlm_table(&lua).unwrap();
+        for name in [
+            "vec2_add",
+            "color_from_hex",
+            "clamp",
+            "random_choice",
+            "timer",
+        ] {
+            assert!(rlm.get::<LuaFunction>(name).is_ok(), "{} missing", name);
+        }
+        let ease: LuaTable = rlm.get("ease").unwrap();
+        assert!(ease.get::<LuaFunction>("in_out_cubic").is_ok());
+    }
+}
@@ -0,0 +1,411 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::any::Any;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use crate::LuaRaylib;
+use crate::crash_screen::{CrashAction, CrashScreen};
+use crate::frame::{abandon_frame, draw_frame};
+use crate::hot_reload::{HotReload, draw_error_overlay, hand_over, is_reloading};
+
+/// Option names accepted by `rl.run`, used for the unknown option error.
+const RUN_OPTIONS: &[&str] = &[
+    "load",
+    "update",
+    "fixed_update",
+    "draw",
+    "quit",
+    "on_error",
+    "crash_screen",
+    "tick_rate",
+    "max_steps",
+    "hot_reload",
+    "on_reload",
+    "state",
+];
+
+/// Callbacks and timing settings passed to `rl.run`.
+pub struct RunOptions {
+    load: Option<LuaFunction>,
+    update: Option<LuaFunction>,
+    fixed_update: Option<LuaFunction>,
+    draw: Option<LuaFunction>,
+    quit: Option<LuaFunction>,
+    on_error: Option<LuaFunction>,
+    crash_screen: bool,
+    tick_rate: f64,
+    max_steps: u32,
+    hot_reload: bool,
+    on_reload: Option<LuaFunction>,
+    state: LuaValue,
+}
+
+impl FromLua for RunOptions {
+    fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
+        let table = match value {
+            LuaValue::Table(table) => table,
+            other => {
+                return Err(LuaError::FromLuaConversionError {
+                    from: other.type_name(),
+                    to: "RunOptions".to_string(),
+                    message: Some("expected a table of callbacks".to_string()),
+                });
+            }
+        };
+
+        for pair in table.pairs::<LuaValue, LuaValue>() {
+            let (key, _) = pair?;
+            let known = match &key {
+                LuaValue::String(s) => RUN_OPTIONS.contains(&&*s.to_str()?),
+                _ => false,
+            };
+            if !known {
+                return Err(LuaError::runtime(format!(
+                    "run: unknown option {}, expected one of: {}",
+                    key.to_string()?,
+                    RUN_OPTIONS.join(", ")
+                )));
+            }
+        }
+
+        let tick_rate = table.get::<Option<f64>>("tick_rate")?.unwrap_or(60.0);
+        if !tick_rate.is_finite() || tick_rate <= 0.0 {
+            return Err(LuaError::runtime(format!(
+                "run: tick_rate must be positive, got {}",
+                tick_rate
+            )));
+        }
+        let max_steps = table.get::<Option<u32>>("max_steps")?.unwrap_or(5);
+        if max_steps == 0 {
+            return Err(LuaError::runtime("run: max_steps must be at least 1"));
+        }
+
+        Ok(RunOptions {
+            load: table.get("load")?,
+            update: table.get("update")?,
+            fixed_update: table.get("fixed_update")?,
+            draw: table.get("draw")?,
+            quit: table.get("quit")?,
+            on_error: table.get("on_error")?,
+            crash_screen: table.get::<Option<bool>>("crash_screen")?.unwrap_or(true),
+            tick_rate,
+            max_steps,
+            hot_reload: table.get::<Option<bool>>("hot_reload")?.unwrap_or(false),
+            on_reload: table.get("on_reload")?,
+            state: table.get("state")?,
+        })
+    }
+}
+
+/// Accumulator turning variable frame times into a whole number of fixed
+/// steps, plus how far the clock is into the next step.
+#[derive(Debug)]
+struct FixedStep {
+    step: f64,
+    max_steps: u32,
+    accumulator: f64,
+}
+
+impl FixedStep {
+    fn new(tick_rate: f64, max_steps: u32) -> Self {
+        FixedStep {
+            step: 1.0 / tick_rate,
+            max_steps,
+            accumulator: 0.0,
+        }
+    }
+
+    /// Add `dt` seconds and return the steps to run and the interpolation
+    /// alpha in `[0, 1)`. Time beyond `max_steps` is dropped rather than
+    /// carried over, so a slow frame can not snowball into slower ones.
+    fn advance(&mut self, dt: f64) -> (u32, f64) {
+        self.accumulator += dt.max(0.0);
+        let mut steps = 0;
+        while self.accumulator >= self.step && steps < self.max_steps {
+            self.accumulator -= self.step;
+            steps += 1;
+        }
+        if self.accumulator >= self.step {
+            self.accumulator %= self.step;
+        }
+        (steps, self.accumulator / self.step)
+    }
+
+    fn reset(&mut self) {
+        self.accumulator = 0.0;
+    }
+}
+
+/// What the loop does after a callback failed.
+enum Recovery {
+    Continue,
+    Restart,
+    Stop,
+    /// Stop, then raise the error once `quit` has run
+    Fail(LuaError),
+}
+
+/// Show the crash screen for the error of `phase` until the player restarts
+/// or quits.
+fn show_crash_screen(
+    lua: &Lua,
+    window: &LuaAnyUserData,
+    phase: &str,
+    err: LuaError,
+) -> LuaResult<Recovery> {
+    let mut screen = CrashScreen::new(phase, &err);
+    while window_open(window)? {
+        match screen.update()? {
+            Some(CrashAction::Restart) => return Ok(Recovery::Restart),
+            Some(CrashAction::Quit) => break,
+            None => {}
+        }
+        draw_frame(lua, window.clone(), |_| {
+            screen.draw();
+            Ok(())
+        })?;
+    }
+    Ok(Recovery::Fail(err))
+}
+
+/// Pass the error of `phase` to the `on_error` handler. Without one the
+/// crash screen is shown, or the error raised when it is turned off.
+fn handle_error(
+    lua: &Lua,
+    window: &LuaAnyUserData,
+    options: &RunOptions,
+    phase: &str,
+    err: LuaError,
+) -> LuaResult<Recovery> {
+    // A callback may fail between begin_drawing and end_drawing
+    abandon_frame();
+    let Some(on_error) = &options.on_error else {
+        if !options.crash_screen {
+            return Err(err);
+        }
+        return show_crash_screen(lua, window, phase, err);
+    };
+    let action: Option<String> = on_error.call((err.to_string(), phase))?;
+    match action.as_deref() {
+        Some("continue") => Ok(Recovery::Continue),
+        Some("restart") => Ok(Recovery::Restart),
+        None => Ok(Recovery::Stop),
+        Some(other) => Err(LuaError::runtime(format!(
+            "run: on_error returned '{}', expected \"continue\", \"restart\" or nil",
+            other
+        ))),
+    }
+}
+
+/// Text of a panic payload.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+/// Call a callback, turning a Rust panic in a function it calls into a Lua
+/// error rather than letting it unwind through Lua's C frames.
+fn call_phase(callback: &Option<LuaFunction>, args: impl IntoLuaMulti) -> LuaResult<()> {
+    let Some(callback) = callback else {
+        return Ok(());
+    };
+    catch_unwind(AssertUnwindSafe(|| callback.call(args))).unwrap_or_else(|payload| {
+        Err(LuaError::runtime(format!(
+            "Rust panic: {}",
+            panic_message(payload.as_ref())
+        )))
+    })
+}
+
+fn window_open(window: &LuaAnyUserData) -> LuaResult<bool> {
+    let raylib = window.borrow::<LuaRaylib>()?;
+    Ok(raylib.alive.is_alive() && !unsafe { ffi::WindowShouldClose() })
+}
+
+/// Run one frame: `update`, any due `fixed_update` steps and `draw`, with
+/// the last reload error drawn on top.
+fn run_frame(
+    lua: &Lua,
+    window: &LuaAnyUserData,
+    options: &RunOptions,
+    clock: &mut FixedStep,
+    reload_error: Option<&str>,
+) -> Result<(), (&'static str, LuaError)> {
+    let dt = unsafe { ffi::GetFrameTime() } as f64;
+    call_phase(&options.update, dt).map_err(|e| ("update", e))?;
+
+    let (steps, alpha) = clock.advance(dt);
+    for _ in 0..steps {
+        call_phase(&options.fixed_update, clock.step).map_err(|e| ("fixed_update", e))?;
+    }
+
+    draw_frame(lua, window.clone(), |context| {
+        let drawn = call_phase(&options.draw, (context, alpha));
+        if let Some(message) = reload_error {
+            draw_error_overlay("Reload failed, still running the previous code", message);
+        }
+        drawn
+    })
+    .map_err(|e| ("draw", e))
+}
+
+/// `rl.run(window, callbacks)`: managed game loop with a fixed timestep.
+fn run(lua: &Lua, (window, mut options): (LuaAnyUserData, RunOptions)) -> LuaResult<()> {
+    // The main script is being re-executed by a hot reload; the loop already
+    // running takes over the new callbacks
+    if is_reloading(lua) {
+        return Err(hand_over(lua, options));
+    }
+    let mut hot_reload = if options.hot_reload {
+        Some(HotReload::for_caller(lua)?)
+    } else {
+        None
+    };
+    let mut state = options.state.clone();
+    let mut clock = FixedStep::new(options.tick_rate, options.max_steps);
+    let mut loaded = false;
+    let mut failure = None;
+
+    while window_open(&window)? {
+        if let Some(watcher) = &mut hot_reload
+            && let Some(new_options) = watcher.reload(lua, &window)
+        {
+            let old_state = std::mem::replace(&mut state, new_options.state.clone());
+            options = new_options;
+            clock = FixedStep::new(options.tick_rate, options.max_steps);
+            // Without on_reload the new code starts over from load
+            match &options.on_reload {
+                Some(on_reload) => match on_reload.call::<LuaValue>(old_state.clone()) {
+                    Ok(LuaValue::Nil) => state = old_state,
+                    Ok(new_state) => state = new_state,
+                    Err(err) => {
+                        state = old_state;
+                        watcher.set_error(err);
+                    }
+                },
+                None => loaded = false,
+            }
+        }
+        if !loaded {
+            loaded = true;
+            clock.reset();
+            if let Err(err) = call_phase(&options.load, window.clone()) {
+                match handle_error(lua, &window, &options, "load", err)? {
+                    Recovery::Continue => {}
+                    Recovery::Restart => {
+                        loaded = false;
+                        continue;
+                    }
+                    Recovery::Stop => break,
+                    Recovery::Fail(err) => {
+                        failure = Some(err);
+                        break;
+                    }
+                }
+            }
+        }
+        let reload_error = hot_reload.as_ref().and_then(HotReload::error);
+        if let Err((phase, err)) = run_frame(lua, &window, &options, &mut clock, reload_error) {
+            match handle_error(lua, &window, &options, phase, err)? {
+                Recovery::Continue => {}
+                Recovery::Restart => loaded = false,
+                Recovery::Stop => break,
+                Recovery::Fail(err) => {
+                    failure = Some(err);
+                    break;
+                }
+            }
+        }
+    }
+
+    if let Err(err) = call_phase(&options.quit, ())
+        && let Recovery::Fail(err) = handle_error(lua, &window, &options, "quit", err)?
+    {
+        return Err(err);
+    }
+    match failure {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+pub fn register_run_functions(lua: &Lua, exports: &LuaTable) -> LuaResult<()> {
+    exports.set("run", lua.create_function(run)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_steps_carry_leftover_time_into_alpha() {
+        let mut clock = FixedStep::new(10.0, 5);
+        assert_eq!(clock.advance(0.05).0, 0);
+        let (steps, alpha) = clock.advance(0.1);
+        assert_eq!(steps, 1);
+        assert!((alpha - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn panics_in_callbacks_become_errors() {
+        let payload = catch_unwind(|| panic!("index out of bounds")).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "index out of bounds");
+        let payload = catch_unwind(|| std::panic::panic_any(7)).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "unknown panic");
+    }
+
+    #[test]
+    fn catch_up_is_capped_and_excess_time_dropped() {
+        let mut clock = FixedStep::new(10.0, 3);
+        let (steps, alpha) = clock.advance(1.05);
+        assert_eq!(steps, 3);
+        assert!((alpha - 0.5).abs() < 1e-9);
+        // The dropped time does not come back on the next frame
+        assert_eq!(clock.advance(0.0).0, 0);
+    }
+}
+
+// Tests needing a Lua state, which only links when Lua is built in:
+// `cargo test --no-default-features --features runner`
+#[cfg(all(test, not(feature = "module")))]
+mod lua_tests {
+    use super::*;
+
+    #[test]
+    fn panics_in_bound_functions_fail_the_phase() {
+        let lua = Lua::new();
+        let explode = lua
+            .create_function(|_, ()| -> LuaResult<()> { panic!("index out of bounds") })
+            .unwrap();
+        lua.globals().set("explode", explode).unwrap();
+        let update: LuaFunction = lua
+            .load("return function(dt) explode() end")
+            .eval()
+            .unwrap();
+
+        let err = call_phase(&Some(update), 0.016).unwrap_err();
+        assert!(err.to_string().contains("Rust panic: index out of bounds"));
+        assert!(call_phase(&None, ()).is_ok());
+    }
+
+    #[test]
+    fn errors_close_a_frame_left_open() {
+        let lua = Lua::new();
+        let window = lua.create_any_userdata(()).unwrap();
+        let options: RunOptions = lua
+            .load("return { on_error = function() return 'continue' end }")
+            .eval()
+            .unwrap();
+        crate::frame::open_test_frame();
+        let recovery =
+            handle_error(&lua, &window, &options, "update", LuaError::runtime("boom")).unwrap();
+        assert!(matches!(recovery, Recovery::Continue));
+        assert!(!crate::frame::in_frame());
+    }
+}
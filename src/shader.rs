@@ -0,0 +1,655 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::ffi::{CString, c_void};
+
+use crate::frame::with_scoped_mode;
+use crate::texture::LuaTexture;
+use crate::vector3::LuaMatrix;
+use crate::{LivenessToken, LuaColor, LuaRaylib, LuaVector2, LuaVector3, in_frame, is_color_table};
+
+thread_local! {
+    // Id of the shader applied to the frame in progress
+    static SHADER_MODE: Cell<Option<u32>> = const { Cell::new(None) };
+
+    // Warnings logged by raylib while a shader is being compiled
+    static CAPTURED_LOG: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Print raylib's log like its default logger does, keeping warnings and
+/// errors aside for the shader being compiled.
+fn capture_line(level: TraceLogLevel, text: &str) {
+    let name = match level {
+        TraceLogLevel::LOG_TRACE => "TRACE",
+        TraceLogLevel::LOG_DEBUG => "DEBUG",
+        TraceLogLevel::LOG_INFO => "INFO",
+        TraceLogLevel::LOG_WARNING => "WARNING",
+        TraceLogLevel::LOG_ERROR => "ERROR",
+        TraceLogLevel::LOG_FATAL => "FATAL",
+        TraceLogLevel::LOG_ALL | TraceLogLevel::LOG_NONE => "",
+    };
+    println!("{}: {}", name, text);
+    if level as i32 >= TraceLogLevel::LOG_WARNING as i32 {
+        CAPTURED_LOG.with(|log| {
+            if let Some(lines) = log.borrow_mut().as_mut() {
+                lines.push(text.trim_end().to_string());
+            }
+        });
+    }
+}
+
+/// Hands logging back to raylib's own logger when dropped, even if the
+/// compile call panics.
+struct LogCapture;
+
+impl Drop for LogCapture {
+    fn drop(&mut self) {
+        unsafe { ffi::SetTraceLogCallback(None) };
+    }
+}
+
+/// Run `f`, returning the warnings and errors raylib logged meanwhile.
+///
+/// The callback is only installed for the duration of `f`; raylib has no
+/// other logger set outside of it, since it is linked into this module.
+fn capture_log<R>(f: impl FnOnce() -> R) -> LuaResult<(R, Vec<String>)> {
+    set_trace_log_callback(capture_line).map_err(|e| LuaError::runtime(e.to_string()))?;
+    CAPTURED_LOG.with(|log| log.replace(Some(Vec::new())));
+    let result = {
+        let _capture = LogCapture;
+        f()
+    };
+    let lines = CAPTURED_LOG.with(|log| log.take()).unwrap_or_default();
+    Ok((result, lines))
+}
+
+/// GLSL shader program loaded through a window.
+///
+/// Unloaded when collected or closed, like textures. raylib's default shader
+/// is returned when both sources are nil and is never unloaded.
+pub struct LuaShader {
+    shader: Option<ffi::Shader>,
+    window: LivenessToken,
+}
+
+impl LuaShader {
+    /// The underlying shader, failing if it can no longer be used.
+    fn shader(&self) -> LuaResult<ffi::Shader> {
+        match self.shader {
+            Some(_) if !self.window.is_alive() => Err(LuaError::runtime(
+                "Shader belongs to a window that has been closed",
+            )),
+            Some(shader) => Ok(shader),
+            None => Err(LuaError::runtime("Shader has already been unloaded")),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.shader.is_some() && self.window.is_alive()
+    }
+
+    fn unload(&mut self) {
+        let Some(shader) = self.shader.take() else {
+            return;
+        };
+        if !self.window.is_alive() {
+            return;
+        }
+        if SHADER_MODE.get() == Some(shader.id) {
+            SHADER_MODE.set(None);
+            unsafe { ffi::EndShaderMode() };
+        }
+        // UnloadShader leaves the default shader alone
+        unsafe { ffi::UnloadShader(shader) };
+    }
+
+    /// Location of a uniform given by index or by name.
+    fn location(&self, location: LuaValue) -> LuaResult<i32> {
+        match location {
+            LuaValue::Integer(i) => Ok(i as i32),
+            LuaValue::String(name) => {
+                let name = CString::new(name.as_bytes().to_vec()).map_err(LuaError::external)?;
+                Ok(unsafe { ffi::GetShaderLocation(self.shader()?, name.as_ptr()) })
+            }
+            other => Err(LuaError::runtime(format!(
+                "Uniform location must be an integer or a name, got {}",
+                other.type_name()
+            ))),
+        }
+    }
+}
+
+impl Drop for LuaShader {
+    fn drop(&mut self) {
+        self.unload();
+    }
+}
+
+/// Uniform types accepted by `set_value`, as (name, components, integer).
+const UNIFORM_TYPES: &[(&str, usize, bool)] = &[
+    ("float", 1, false),
+    ("vec2", 2, false),
+    ("vec3", 3, false),
+    ("vec4", 4, false),
+    ("int", 1, true),
+    ("ivec2", 2, true),
+    ("ivec3", 3, true),
+    ("ivec4", 4, true),
+];
+
+/// A value ready to be uploaded to a uniform.
+#[derive(Debug, Clone)]
+enum UniformValue {
+    Float(Vec<f32>),
+    Int(Vec<i32>),
+    Matrix(ffi::Matrix),
+    /// Color normalized to `0..1`, uploaded as a vec4
+    Color([f32; 4]),
+    Texture(ffi::Texture2D),
+}
+
+impl UniformValue {
+    /// Build from plain numbers: 1 to 4 become a float vector and 16 a matrix.
+    fn from_numbers(values: Vec<f32>) -> LuaResult<Self> {
+        match values.len() {
+            1..=4 => Ok(UniformValue::Float(values)),
+            16 => {
+                let mut rows = [0.0; 16];
+                rows.copy_from_slice(&values);
+                Ok(UniformValue::Matrix(LuaMatrix::from_rows(rows).0.into()))
+            }
+            n => Err(LuaError::runtime(format!(
+                "Uniform values need 1 to 4 components or 16 for a matrix, got {}",
+                n
+            ))),
+        }
+    }
+
+    /// Normalize a color given in `0..255`, with alpha defaulting to 255.
+    fn color(r: f32, g: f32, b: f32, a: Option<f32>) -> Self {
+        UniformValue::Color([r, g, b, a.unwrap_or(255.0)].map(|c| c / 255.0))
+    }
+
+    /// Build from the items of a Lua list. Numbers are kept as given, so a
+    /// plain list is only normalized when set as a "color" uniform.
+    fn from_sequence(values: Vec<LuaValue>) -> LuaResult<Self> {
+        let numbers = values
+            .into_iter()
+            .map(|v| match v {
+                LuaValue::Integer(i) => Ok(i as f32),
+                LuaValue::Number(n) => Ok(n as f32),
+                other => Err(LuaError::runtime(format!(
+                    "Uniform values must be numbers, got {}",
+                    other.type_name()
+                ))),
+            })
+            .collect::<LuaResult<Vec<f32>>>()?;
+        UniformValue::from_numbers(numbers)
+    }
+
+    /// Convert to the uniform type named in GLSL terms, such as "ivec2" or "mat4".
+    fn with_type(self, uniform_type: &str) -> LuaResult<Self> {
+        let mismatch = |value: &UniformValue| {
+            LuaError::runtime(format!(
+                "Cannot set a {} uniform from {}",
+                uniform_type,
+                value.describe()
+            ))
+        };
+        match (uniform_type, self) {
+            ("mat4", matrix @ UniformValue::Matrix(_)) => Ok(matrix),
+            ("sampler2d", texture @ UniformValue::Texture(_)) => Ok(texture),
+            ("color", color @ UniformValue::Color(_)) => Ok(color),
+            // {r, g, b[, a]} in 0-255, uploaded as a normalized vec4
+            ("color", UniformValue::Float(v)) if matches!(v.len(), 3 | 4) => {
+                Ok(UniformValue::color(v[0], v[1], v[2], v.get(3).copied()))
+            }
+            ("color", value) => Err(mismatch(&value)),
+            (name, value) => {
+                let Some(&(_, count, int)) = UNIFORM_TYPES.iter().find(|(n, _, _)| *n == name)
+                else {
+                    return Err(LuaError::runtime(format!(
+                        "Unknown uniform type '{}', expected one of: {}, mat4, sampler2d, color",
+                        name,
+                        UNIFORM_TYPES
+                            .iter()
+                            .map(|(n, _, _)| *n)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )));
+                };
+                let floats: Vec<f32> = match &value {
+                    UniformValue::Float(v) => v.clone(),
+                    UniformValue::Int(v) => v.iter().map(|i| *i as f32).collect(),
+                    UniformValue::Color(v) => v.to_vec(),
+                    _ => return Err(mismatch(&value)),
+                };
+                if floats.len() != count {
+                    return Err(mismatch(&value));
+                }
+                Ok(if int {
+                    UniformValue::Int(floats.iter().map(|f| *f as i32).collect())
+                } else {
+                    UniformValue::Float(floats)
+                })
+            }
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            UniformValue::Float(v) if v.len() == 1 => "a number".to_string(),
+            UniformValue::Int(v) if v.len() == 1 => "an integer".to_string(),
+            UniformValue::Float(v) => format!("{} numbers", v.len()),
+            UniformValue::Int(v) => format!("{} integers", v.len()),
+            UniformValue::Matrix(_) => "a matrix".to_string(),
+            UniformValue::Color(_) => "a color".to_string(),
+            UniformValue::Texture(_) => "a texture".to_string(),
+        }
+    }
+
+    /// raylib uniform data type for float and integer vectors.
+    fn data_type(&self) -> Option<ShaderUniformDataType> {
+        use ShaderUniformDataType::*;
+        match self {
+            UniformValue::Float(v) => [
+                SHADER_UNIFORM_FLOAT,
+                SHADER_UNIFORM_VEC2,
+                SHADER_UNIFORM_VEC3,
+                SHADER_UNIFORM_VEC4,
+            ]
+            .get(v.len().checked_sub(1)?)
+            .copied(),
+            UniformValue::Int(v) => [
+                SHADER_UNIFORM_INT,
+                SHADER_UNIFORM_IVEC2,
+                SHADER_UNIFORM_IVEC3,
+                SHADER_UNIFORM_IVEC4,
+            ]
+            .get(v.len().checked_sub(1)?)
+            .copied(),
+            UniformValue::Color(_) => Some(SHADER_UNIFORM_VEC4),
+            _ => None,
+        }
+    }
+
+    fn upload(&self, shader: ffi::Shader, location: i32) {
+        let data_type = self.data_type().map_or(0, |t| t as i32);
+        match self {
+            UniformValue::Float(v) => unsafe {
+                ffi::SetShaderValue(shader, location, v.as_ptr() as *const c_void, data_type)
+            },
+            UniformValue::Int(v) => unsafe {
+                ffi::SetShaderValue(shader, location, v.as_ptr() as *const c_void, data_type)
+            },
+            UniformValue::Color(v) => unsafe {
+                ffi::SetShaderValue(shader, location, v.as_ptr() as *const c_void, data_type)
+            },
+            UniformValue::Matrix(m) => unsafe { ffi::SetShaderValueMatrix(shader, location, *m) },
+            UniformValue::Texture(texture) => unsafe {
+                ffi::SetShaderValueTexture(shader, location, *texture)
+            },
+        }
+    }
+}
+
+impl FromLua for UniformValue {
+    fn from_lua(value: LuaValue, lua: &Lua) -> LuaResult<Self> {
+        match value {
+            LuaValue::Integer(i) => Ok(UniformValue::Float(vec![i as f32])),
+            LuaValue::Number(n) => Ok(UniformValue::Float(vec![n as f32])),
+            LuaValue::Boolean(b) => Ok(UniformValue::Int(vec![b as i32])),
+            LuaValue::UserData(ud) => {
+                if let Ok(v) = ud.borrow::<LuaVector2>() {
+                    Ok(UniformValue::Float(vec![v.x, v.y]))
+                } else if let Ok(v) = ud.borrow::<LuaVector3>() {
+                    Ok(UniformValue::Float(vec![v.x, v.y, v.z]))
+                } else if let Ok(texture) = ud.borrow::<LuaTexture>() {
+                    Ok(UniformValue::Texture(*texture.texture()?.as_ref()))
+                } else {
+                    Err(LuaError::runtime(
+                        "Uniform values must be numbers, vectors, matrices or textures",
+                    ))
+                }
+            }
+            LuaValue::Table(table) => {
+                if is_color_table(lua, &table)? {
+                    let c = LuaColor::from_lua(LuaValue::Table(table), lua)?;
+                    return Ok(UniformValue::color(
+                        c.r as f32,
+                        c.g as f32,
+                        c.b as f32,
+                        Some(c.a as f32),
+                    ));
+                }
+                // Same layouts as Vector3:transform: m0..m15 fields or 16
+                // numbers row by row
+                if table.contains_key("m0")? || table.raw_len() == 16 {
+                    let matrix = LuaMatrix::from_lua(LuaValue::Table(table), lua)?;
+                    return Ok(UniformValue::Matrix(matrix.0.into()));
+                }
+                if table.contains_key("x")? {
+                    let mut values = vec![table.get("x")?, table.get("y")?];
+                    for key in ["z", "w"] {
+                        match table.get::<Option<f32>>(key)? {
+                            Some(v) => values.push(v),
+                            None => break,
+                        }
+                    }
+                    return Ok(UniformValue::Float(values));
+                }
+                UniformValue::from_sequence(table.sequence_values().collect::<LuaResult<_>>()?)
+            }
+            other => Err(LuaError::runtime(format!(
+                "Uniform values must be numbers, vectors, matrices or textures, got {}",
+                other.type_name()
+            ))),
+        }
+    }
+}
+
+impl LuaUserData for LuaShader {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("id", |_, this| Ok(this.shader()?.id));
+    }
+
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("is_valid", |_, this, ()| Ok(this.is_valid()));
+
+        methods.add_method_mut("unload", |_, this, ()| {
+            this.unload();
+            Ok(())
+        });
+
+        methods.add_meta_method_mut(LuaMetaMethod::Close, |_, this, _: LuaValue| {
+            this.unload();
+            Ok(())
+        });
+
+        methods.add_method("get_location", |_, this, name: LuaString| {
+            this.location(LuaValue::String(name))
+        });
+
+        methods.add_method(
+            "set_value",
+            |_, this, (location, value, uniform_type): (LuaValue, UniformValue, Option<String>)| {
+                let shader = this.shader()?;
+                let location = this.location(location)?;
+                let value = match uniform_type {
+                    Some(uniform_type) => value.with_type(&uniform_type.to_lowercase())?,
+                    None => value,
+                };
+                value.upload(shader, location);
+                Ok(())
+            },
+        );
+    }
+}
+
+fn c_source(source: Option<String>) -> LuaResult<Option<CString>> {
+    source
+        .map(|s| CString::new(s).map_err(LuaError::external))
+        .transpose()
+}
+
+fn c_ptr(source: &Option<CString>) -> *const std::os::raw::c_char {
+    source.as_ref().map_or(std::ptr::null(), |s| s.as_ptr())
+}
+
+/// Compile a shader, turning raylib's compile log into the error message.
+fn load_shader_with(
+    window: &LivenessToken,
+    vs: Option<String>,
+    fs: Option<String>,
+    from_memory: bool,
+) -> LuaResult<LuaShader> {
+    if !from_memory {
+        for path in vs.iter().chain(fs.iter()) {
+            if !std::path::Path::new(path).is_file() {
+                return Err(LuaError::runtime(format!(
+                    "Failed to load shader: file '{}' not found",
+                    path
+                )));
+            }
+        }
+    }
+    let custom = vs.is_some() || fs.is_some();
+    let (vs, fs) = (c_source(vs)?, c_source(fs)?);
+    let (shader, log) = capture_log(|| unsafe {
+        if from_memory {
+            ffi::LoadShaderFromMemory(c_ptr(&vs), c_ptr(&fs))
+        } else {
+            ffi::LoadShader(c_ptr(&vs), c_ptr(&fs))
+        }
+    })?;
+
+    // raylib falls back to its default shader when compiling or linking fails
+    let failed = !unsafe { ffi::IsShaderValid(shader) }
+        || (custom && shader.id == unsafe { ffi::rlGetShaderIdDefault() });
+    if failed {
+        let mut message = "Failed to load shader".to_string();
+        for line in log {
+            message.push('\n');
+            message.push_str(&line);
+        }
+        return Err(LuaError::runtime(message));
+    }
+    Ok(LuaShader {
+        shader: Some(shader),
+        window: window.clone(),
+    })
+}
+
+fn begin_shader_mode(shader: &LuaShader) -> LuaResult<()> {
+    let shader = shader.shader()?;
+    if !in_frame() {
+        return Err(LuaError::runtime(
+            "begin_shader_mode must be called between begin_drawing and end_drawing",
+        ));
+    }
+    if SHADER_MODE.get().is_some() {
+        return Err(LuaError::runtime(
+            "begin_shader_mode called while shader mode is already active",
+        ));
+    }
+    SHADER_MODE.set(Some(shader.id));
+    unsafe { ffi::BeginShaderMode(shader) };
+    Ok(())
+}
+
+fn end_shader_mode() -> LuaResult<()> {
+    if SHADER_MODE.take().is_none() {
+        return Err(LuaError::runtime(
+            "end_shader_mode called without a matching begin_shader_mode",
+        ));
+    }
+    unsafe { ffi::EndShaderMode() };
+    Ok(())
+}
+
+/// Whether a custom shader is currently applied.
+pub fn shader_mode_active() -> bool {
+    SHADER_MODE.get().is_some()
+}
+
+/// Leave shader mode if the frame ends while it is still active.
+pub fn reset_shader_mode() {
+    if SHADER_MODE.take().is_some() {
+        unsafe { ffi::EndShaderMode() };
+    }
+}
+
+/// Shader loading and shader mode methods on the window.
+pub fn add_shader_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    methods.add_method(
+        "load_shader",
+        |_, this, (vs_path, fs_path): (Option<String>, Option<String>)| {
+            this.check_open("load_shader")?;
+            load_shader_with(&this.alive, vs_path, fs_path, false)
+        },
+    );
+
+    methods.add_method(
+        "load_shader_from_memory",
+        |_, this, (vs_code, fs_code): (Option<String>, Option<String>)| {
+            this.check_open("load_shader_from_memory")?;
+            load_shader_with(&this.alive, vs_code, fs_code, true)
+        },
+    );
+
+    methods.add_method(
+        "begin_shader_mode",
+        |_, _this, shader: LuaUserDataRef<LuaShader>| begin_shader_mode(&shader),
+    );
+
+    methods.add_method("end_shader_mode", |_, _this, ()| end_shader_mode());
+
+    methods.add_function(
+        "with_shader_mode",
+        |_, (window, shader, func): (LuaAnyUserData, LuaUserDataRef<LuaShader>, LuaFunction)| {
+            // The shader is borrowed only by begin, so the callback may set uniforms
+            with_scoped_mode(
+                window,
+                func,
+                move || begin_shader_mode(&shader),
+                end_shader_mode,
+            )
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_become_float_vectors_or_matrices() {
+        assert_eq!(
+            UniformValue::from_numbers(vec![1.0, 2.0])
+                .unwrap()
+                .data_type(),
+            Some(ShaderUniformDataType::SHADER_UNIFORM_VEC2)
+        );
+        // Listed row by row, like Vector3:transform: the translation is
+        // the last column
+        let mut rows = [0.0; 16];
+        for i in [0, 5, 10, 15] {
+            rows[i] = 1.0;
+        }
+        rows[3] = 7.0;
+        match UniformValue::from_numbers(rows.to_vec()) {
+            Ok(UniformValue::Matrix(m)) => assert_eq!((m.m12, m.m3), (7.0, 0.0)),
+            other => panic!("expected a matrix, got {:?}", other),
+        }
+        assert!(UniformValue::from_numbers(vec![0.0; 5]).is_err());
+    }
+
+    #[test]
+    fn integer_lists_are_uploaded_unchanged() {
+        // {1, 0, 0, 1} reaches from_numbers as is, not divided by 255
+        let vec4 = UniformValue::from_numbers(vec![1.0, 0.0, 0.0, 1.0]).unwrap();
+        assert!(matches!(&vec4, UniformValue::Float(v) if v == &[1.0, 0.0, 0.0, 1.0]));
+
+        let ivec4 = UniformValue::from_numbers(vec![1.0, 2.0, 3.0, 4.0])
+            .unwrap()
+            .with_type("ivec4")
+            .unwrap();
+        assert!(matches!(&ivec4, UniformValue::Int(v) if v == &[1, 2, 3, 4]));
+
+        let color = UniformValue::from_numbers(vec![255.0, 0.0, 51.0])
+            .unwrap()
+            .with_type("color")
+            .unwrap();
+        assert!(matches!(&color, UniformValue::Color(v) if v == &[1.0, 0.0, 0.2, 1.0]));
+        let err = UniformValue::from_numbers(vec![1.0, 2.0])
+            .unwrap()
+            .with_type("color")
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Cannot set a color uniform from 2 numbers")
+        );
+    }
+
+    #[test]
+    fn explicit_types_convert_and_check_components() {
+        let value = UniformValue::Float(vec![2.0, 3.0]);
+        let ints = value.clone().with_type("ivec2").unwrap();
+        assert!(matches!(&ints, UniformValue::Int(v) if v == &[2, 3]));
+        assert_eq!(
+            ints.data_type(),
+            Some(ShaderUniformDataType::SHADER_UNIFORM_IVEC2)
+        );
+        assert!(value.clone().with_type("vec3").is_err());
+        assert!(value.with_type("mat3").is_err());
+        assert!(matches!(
+            UniformValue::Int(vec![1]).with_type("float"),
+            Ok(UniformValue::Float(v)) if v == [1.0]
+        ));
+    }
+}
+
+// Tests needing a Lua state, which only links when Lua is built in:
+// `cargo test --no-default-features --features runner`
+#[cfg(all(test, not(feature = "module")))]
+mod lua_tests {
+    use super::*;
+
+    #[test]
+    fn integer_tables_are_not_treated_as_colors() {
+        let lua = Lua::new();
+        let value: UniformValue = lua.load("return {1, 0, 0, 1}").eval().unwrap();
+        assert!(matches!(&value, UniformValue::Float(v) if v == &[1.0, 0.0, 0.0, 1.0]));
+        let value: UniformValue = lua.load("return {1, 2, 3, 4}").eval().unwrap();
+        let value = value.with_type("ivec4").unwrap();
+        assert!(matches!(&value, UniformValue::Int(v) if v == &[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn binding_colors_are_inferred_as_vec4() {
+        let lua = Lua::new();
+        let red = LuaColor {
+            r: 255,
+            g: 0,
+            b: 51,
+            a: 255,
+        };
+        lua.globals().set("red", red).unwrap();
+        let value: UniformValue = lua.load("return red").eval().unwrap();
+        assert!(matches!(&value, UniformValue::Color(v) if v == &[1.0, 0.0, 0.2, 1.0]));
+        assert_eq!(
+            value.data_type(),
+            Some(ShaderUniformDataType::SHADER_UNIFORM_VEC4)
+        );
+        assert!(matches!(
+            value.with_type("color"),
+            Ok(UniformValue::Color(_))
+        ));
+    }
+
+    #[test]
+    fn matrix_tables_match_vector3_transform() {
+        let lua = Lua::new();
+        let fields: UniformValue = lua
+            .load(
+                r#"
+                local m = {}
+                for i = 0, 15 do m["m" .. i] = 0 end
+                m.m0, m.m5, m.m10, m.m15, m.m12 = 1, 1, 1, 1, 7
+                return m
+                "#,
+            )
+            .eval()
+            .unwrap();
+        let list: UniformValue = lua
+            .load("return {1, 0, 0, 7, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1}")
+            .eval()
+            .unwrap();
+        for value in [fields, list] {
+            match value {
+                UniformValue::Matrix(m) => assert_eq!((m.m12, m.m3, m.m15), (7.0, 0.0, 1.0)),
+                other => panic!("expected a matrix, got {:?}", other),
+            }
+        }
+    }
+}
@@ -0,0 +1,389 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+
+use crate::draw::DrawArgs;
+use crate::{LuaRaylib, LuaVector2, with_draw_handle};
+
+/// List of points given as a Lua array of `Vector2` userdata or `{x, y}` tables.
+pub struct LuaPoints(pub Vec<ffi::Vector2>);
+
+impl FromLua for LuaPoints {
+    fn from_lua(value: LuaValue, lua: &Lua) -> LuaResult<Self> {
+        let LuaValue::Table(table) = value else {
+            return Err(LuaError::FromLuaConversionError {
+                from: value.type_name(),
+                to: "points".to_string(),
+                message: Some("expected an array of Vector2 or {x, y} tables".to_string()),
+            });
+        };
+        let mut points = Vec::with_capacity(table.raw_len());
+        for (i, point) in table.sequence_values::<LuaValue>().enumerate() {
+            let point = LuaVector2::from_lua(point?, lua).map_err(|_| {
+                LuaError::runtime(format!(
+                    "points[{}] must be a Vector2 or {{x, y}} table",
+                    i + 1
+                ))
+            })?;
+            points.push(point.into());
+        }
+        Ok(LuaPoints(points))
+    }
+}
+
+impl LuaPoints {
+    /// Fail unless there are at least `min` points, naming the drawing function.
+    fn require(&self, min: usize, function: &str) -> LuaResult<()> {
+        if self.0.len() < min {
+            return Err(LuaError::runtime(format!(
+                "{} needs at least {} points, got {}",
+                function,
+                min,
+                self.0.len()
+            )));
+        }
+        Ok(())
+    }
+
+    fn count(&self) -> i32 {
+        self.0.len() as i32
+    }
+}
+
+type SplineFn = unsafe extern "C" fn(*const ffi::Vector2, i32, f32, ffi::Color);
+
+/// Spline drawing methods as (name, raylib function, minimum number of points).
+const SPLINES: &[(&str, SplineFn, usize)] = &[
+    ("draw_spline_linear", ffi::DrawSplineLinear, 2),
+    ("draw_spline_basis", ffi::DrawSplineBasis, 4),
+    ("draw_spline_catmull_rom", ffi::DrawSplineCatmullRom, 4),
+    (
+        "draw_spline_bezier_quadratic",
+        ffi::DrawSplineBezierQuadratic,
+        3,
+    ),
+    ("draw_spline_bezier_cubic", ffi::DrawSplineBezierCubic, 4),
+];
+
+/// Float version of raylib's DrawCircleGradient and DrawEllipse, which only
+/// take whole-pixel centers: the same fan of 36 triangles, shaded from
+/// `inner` at the center to `outer` at the edge.
+fn draw_ellipse_fan(
+    center: ffi::Vector2,
+    radius_h: f32,
+    radius_v: f32,
+    inner: ffi::Color,
+    outer: ffi::Color,
+) {
+    // From rlgl.h, which the bindings do not include
+    const RL_TRIANGLES: i32 = 0x0004;
+    let edge = |degrees: i32| {
+        let angle = (degrees as f32).to_radians();
+        (
+            center.x + angle.cos() * radius_h,
+            center.y + angle.sin() * radius_v,
+        )
+    };
+    unsafe {
+        ffi::rlBegin(RL_TRIANGLES);
+        for degrees in (0..360).step_by(10) {
+            ffi::rlColor4ub(inner.r, inner.g, inner.b, inner.a);
+            ffi::rlVertex2f(center.x, center.y);
+            ffi::rlColor4ub(outer.r, outer.g, outer.b, outer.a);
+            let (x, y) = edge(degrees + 10);
+            ffi::rlVertex2f(x, y);
+            let (x, y) = edge(degrees);
+            ffi::rlVertex2f(x, y);
+        }
+        ffi::rlEnd();
+    }
+}
+
+/// Rectangle, circle, polygon, line and spline drawing methods on the window.
+///
+/// Positions are floats and may be passed as numbers or vectors, see
+/// `DrawArgs`.
+pub fn add_shape_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    // Rectangles
+    methods.add_method(
+        "draw_rectangle_rounded",
+        |lua, _this, args: LuaMultiValue| {
+            let mut args = DrawArgs::new(lua, "draw_rectangle_rounded", args);
+            let rect = args.rectangle("rect")?;
+            let roundness = args.number("roundness")?;
+            let segments = args.value("segments", "an integer")?;
+            let color = args.color("color")?;
+            with_draw_handle("draw_rectangle_rounded", |_| unsafe {
+                ffi::DrawRectangleRounded(rect, roundness, segments, color)
+            })?;
+            Ok(())
+        },
+    );
+
+    methods.add_method(
+        "draw_rectangle_rounded_lines",
+        |lua, _this, args: LuaMultiValue| {
+            let mut args = DrawArgs::new(lua, "draw_rectangle_rounded_lines", args);
+            let rect = args.rectangle("rect")?;
+            let roundness = args.number("roundness")?;
+            let segments = args.value("segments", "an integer")?;
+            let color = args.color("color")?;
+            let thick: Option<f32> = args.value("thick", "a number or nil")?;
+            with_draw_handle("draw_rectangle_rounded_lines", |_| unsafe {
+                match thick {
+                    Some(thick) => {
+                        ffi::DrawRectangleRoundedLinesEx(rect, roundness, segments, thick, color)
+                    }
+                    None => ffi::DrawRectangleRoundedLines(rect, roundness, segments, color),
+                }
+            })?;
+            Ok(())
+        },
+    );
+
+    // Float versions of raylib's DrawRectangleGradientV/H, which pass the
+    // corners to DrawRectangleGradientEx in this order
+    methods.add_method(
+        "draw_rectangle_gradient_v",
+        |lua, _this, args: LuaMultiValue| {
+            let mut args = DrawArgs::new(lua, "draw_rectangle_gradient_v", args);
+            let rect = args.rectangle("rect")?;
+            let top = args.color("top")?;
+            let bottom = args.color("bottom")?;
+            with_draw_handle("draw_rectangle_gradient_v", |_| unsafe {
+                ffi::DrawRectangleGradientEx(rect, top, bottom, bottom, top)
+            })?;
+            Ok(())
+        },
+    );
+
+    methods.add_method(
+        "draw_rectangle_gradient_h",
+        |lua, _this, args: LuaMultiValue| {
+            let mut args = DrawArgs::new(lua, "draw_rectangle_gradient_h", args);
+            let rect = args.rectangle("rect")?;
+            let left = args.color("left")?;
+            let right = args.color("right")?;
+            with_draw_handle("draw_rectangle_gradient_h", |_| unsafe {
+                ffi::DrawRectangleGradientEx(rect, left, left, right, right)
+            })?;
+            Ok(())
+        },
+    );
+
+    methods.add_method(
+        "draw_rectangle_gradient_ex",
+        |lua, _this, args: LuaMultiValue| {
+            let mut args = DrawArgs::new(lua, "draw_rectangle_gradient_ex", args);
+            let rect = args.rectangle("rect")?;
+            let top_left = args.color("top_left")?;
+            let bottom_left = args.color("bottom_left")?;
+            let top_right = args.color("top_right")?;
+            let bottom_right = args.color("bottom_right")?;
+            with_draw_handle("draw_rectangle_gradient_ex", |_| unsafe {
+                ffi::DrawRectangleGradientEx(rect, top_left, bottom_left, top_right, bottom_right)
+            })?;
+            Ok(())
+        },
+    );
+
+    methods.add_method("draw_rectangle_pro", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_rectangle_pro", args);
+        let rect = args.rectangle("rect")?;
+        let origin = args.vector("origin")?;
+        let rotation = args.number("rotation")?;
+        let color = args.color("color")?;
+        with_draw_handle("draw_rectangle_pro", |_| unsafe {
+            ffi::DrawRectanglePro(rect, origin, rotation, color)
+        })?;
+        Ok(())
+    });
+
+    methods.add_method(
+        "draw_rectangle_lines_ex",
+        |lua, _this, args: LuaMultiValue| {
+            let mut args = DrawArgs::new(lua, "draw_rectangle_lines_ex", args);
+            let rect = args.rectangle("rect")?;
+            let thick = args.number("thick")?;
+            let color = args.color("color")?;
+            with_draw_handle("draw_rectangle_lines_ex", |_| unsafe {
+                ffi::DrawRectangleLinesEx(rect, thick, color)
+            })?;
+            Ok(())
+        },
+    );
+
+    // Circles, ellipses and rings
+    methods.add_method("draw_circle_sector", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_circle_sector", args);
+        let center = args.vector("center")?;
+        let radius = args.number("radius")?;
+        let start_angle = args.number("start_angle")?;
+        let end_angle = args.number("end_angle")?;
+        let segments = args.value("segments", "an integer")?;
+        let color = args.color("color")?;
+        with_draw_handle("draw_circle_sector", |_| unsafe {
+            ffi::DrawCircleSector(center, radius, start_angle, end_angle, segments, color)
+        })?;
+        Ok(())
+    });
+
+    methods.add_method("draw_circle_gradient", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_circle_gradient", args);
+        let center = args.vector("center")?;
+        let radius = args.number("radius")?;
+        let inner = args.color("inner")?;
+        let outer = args.color("outer")?;
+        with_draw_handle("draw_circle_gradient", |_| {
+            draw_ellipse_fan(center, radius, radius, inner, outer)
+        })?;
+        Ok(())
+    });
+
+    methods.add_method("draw_ellipse", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_ellipse", args);
+        let center = args.vector("center")?;
+        let radius_h = args.number("radius_h")?;
+        let radius_v = args.number("radius_v")?;
+        let color = args.color("color")?;
+        with_draw_handle("draw_ellipse", |_| {
+            draw_ellipse_fan(center, radius_h, radius_v, color, color)
+        })?;
+        Ok(())
+    });
+
+    methods.add_method("draw_ring_lines", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_ring_lines", args);
+        let center = args.vector("center")?;
+        let inner_radius = args.number("inner_radius")?;
+        let outer_radius = args.number("outer_radius")?;
+        let start_angle = args.number("start_angle")?;
+        let end_angle = args.number("end_angle")?;
+        let segments = args.value("segments", "an integer")?;
+        let color = args.color("color")?;
+        with_draw_handle("draw_ring_lines", |_| unsafe {
+            ffi::DrawRingLines(
+                center,
+                inner_radius,
+                outer_radius,
+                start_angle,
+                end_angle,
+                segments,
+                color,
+            )
+        })?;
+        Ok(())
+    });
+
+    // Triangles and polygons
+    methods.add_method("draw_triangle_lines", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_triangle_lines", args);
+        let v1 = args.vector("v1")?;
+        let v2 = args.vector("v2")?;
+        let v3 = args.vector("v3")?;
+        let color = args.color("color")?;
+        with_draw_handle("draw_triangle_lines", |_| unsafe {
+            ffi::DrawTriangleLines(v1, v2, v3, color)
+        })?;
+        Ok(())
+    });
+
+    for (name, draw) in [
+        (
+            "draw_triangle_fan",
+            ffi::DrawTriangleFan as unsafe extern "C" fn(*const ffi::Vector2, i32, ffi::Color),
+        ),
+        ("draw_triangle_strip", ffi::DrawTriangleStrip),
+    ] {
+        methods.add_method(name, move |lua, _this, args: LuaMultiValue| {
+            let mut args = DrawArgs::new(lua, name, args);
+            let points = args.points("points")?;
+            let color = args.color("color")?;
+            points.require(3, name)?;
+            with_draw_handle(name, |_| unsafe {
+                draw(points.0.as_ptr(), points.count(), color)
+            })?;
+            Ok(())
+        });
+    }
+
+    methods.add_method("draw_poly", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_poly", args);
+        let center = args.vector("center")?;
+        let sides = args.value("sides", "an integer")?;
+        let radius = args.number("radius")?;
+        let rotation = args.number("rotation")?;
+        let color = args.color("color")?;
+        with_draw_handle("draw_poly", |_| unsafe {
+            ffi::DrawPoly(center, sides, radius, rotation, color)
+        })?;
+        Ok(())
+    });
+
+    methods.add_method("draw_poly_lines_ex", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_poly_lines_ex", args);
+        let center = args.vector("center")?;
+        let sides = args.value("sides", "an integer")?;
+        let radius = args.number("radius")?;
+        let rotation = args.number("rotation")?;
+        let thick = args.number("thick")?;
+        let color = args.color("color")?;
+        with_draw_handle("draw_poly_lines_ex", |_| unsafe {
+            ffi::DrawPolyLinesEx(center, sides, radius, rotation, thick, color)
+        })?;
+        Ok(())
+    });
+
+    // Lines and splines
+    methods.add_method("draw_line_strip", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_line_strip", args);
+        let points = args.points("points")?;
+        let color = args.color("color")?;
+        points.require(2, "draw_line_strip")?;
+        with_draw_handle("draw_line_strip", |_| unsafe {
+            ffi::DrawLineStrip(points.0.as_ptr(), points.count(), color)
+        })?;
+        Ok(())
+    });
+
+    methods.add_method("draw_line_bezier", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_line_bezier", args);
+        let start = args.vector("start_pos")?;
+        let end = args.vector("end_pos")?;
+        let thick = args.number("thick")?;
+        let color = args.color("color")?;
+        with_draw_handle("draw_line_bezier", |_| unsafe {
+            ffi::DrawLineBezier(start, end, thick, color)
+        })?;
+        Ok(())
+    });
+
+    for &(name, draw, min_points) in SPLINES {
+        methods.add_method(name, move |lua, _this, args: LuaMultiValue| {
+            let mut args = DrawArgs::new(lua, name, args);
+            let points = args.points("points")?;
+            let thick = args.number("thick")?;
+            let color = args.color("color")?;
+            points.require(min_points, name)?;
+            with_draw_handle(name, |_| unsafe {
+                draw(points.0.as_ptr(), points.count(), thick, color)
+            })?;
+            Ok(())
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn too_few_points_names_the_function_and_minimum() {
+        let points = LuaPoints(vec![ffi::Vector2 { x: 0.0, y: 0.0 }; 2]);
+        assert!(points.require(2, "draw_line_strip").is_ok());
+        let err = points.require(4, "draw_spline_basis").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("draw_spline_basis needs at least 4 points, got 2")
+        );
+    }
+}
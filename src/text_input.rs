@@ -0,0 +1,349 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+
+use crate::input::{char_pressed, clipboard_text, set_clipboard_text};
+
+/// Single-line text field state edited one character at a time.
+///
+/// The text is kept as `char`s so cursor movement and deletion never split a
+/// UTF-8 sequence. `cursor` is the number of characters before the caret and
+/// the selection runs from `anchor` to `cursor`.
+pub struct LuaTextInput {
+    chars: Vec<char>,
+    cursor: usize,
+    anchor: Option<usize>,
+    max_length: Option<usize>,
+}
+
+impl LuaTextInput {
+    fn new(text: &str, max_length: Option<usize>) -> Self {
+        let mut input = LuaTextInput {
+            chars: Vec::new(),
+            cursor: 0,
+            anchor: None,
+            max_length,
+        };
+        input.set_text(text);
+        input
+    }
+
+    fn text(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    fn set_text(&mut self, text: &str) {
+        self.chars = text.chars().filter(|c| !c.is_control()).collect();
+        if let Some(max) = self.max_length {
+            self.chars.truncate(max);
+        }
+        self.cursor = self.chars.len();
+        self.anchor = None;
+    }
+
+    fn set_max_length(&mut self, max_length: Option<usize>) {
+        self.max_length = max_length;
+        if let Some(max) = max_length
+            && self.chars.len() > max
+        {
+            let text = self.text();
+            self.set_text(&text);
+        }
+    }
+
+    /// Selected range as ordered character offsets, if the selection is not empty.
+    fn selection(&self) -> Option<(usize, usize)> {
+        let anchor = self.anchor?;
+        (anchor != self.cursor).then(|| (anchor.min(self.cursor), anchor.max(self.cursor)))
+    }
+
+    fn selected_text(&self) -> String {
+        self.selection()
+            .map(|(start, end)| self.chars[start..end].iter().collect())
+            .unwrap_or_default()
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        let Some((start, end)) = self.selection() else {
+            return false;
+        };
+        self.chars.drain(start..end);
+        self.cursor = start;
+        self.anchor = None;
+        true
+    }
+
+    /// Insert at the caret, replacing the selection; returns true if the text changed.
+    fn insert(&mut self, text: &str) -> bool {
+        let mut changed = self.delete_selection();
+        let room = self
+            .max_length
+            .map_or(usize::MAX, |max| max.saturating_sub(self.chars.len()));
+        let new: Vec<char> = text
+            .chars()
+            .filter(|c| !c.is_control())
+            .take(room)
+            .collect();
+        if !new.is_empty() {
+            let count = new.len();
+            self.chars.splice(self.cursor..self.cursor, new);
+            self.cursor += count;
+            changed = true;
+        }
+        changed
+    }
+
+    fn backspace(&mut self) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+        if self.cursor == 0 {
+            return false;
+        }
+        self.cursor -= 1;
+        self.chars.remove(self.cursor);
+        true
+    }
+
+    fn delete(&mut self) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+        if self.cursor >= self.chars.len() {
+            return false;
+        }
+        self.chars.remove(self.cursor);
+        true
+    }
+
+    /// Move the caret, extending the selection when `select` is set.
+    fn move_to(&mut self, position: usize, select: bool) {
+        if select {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = position.min(self.chars.len());
+        if self.anchor == Some(self.cursor) {
+            self.anchor = None;
+        }
+    }
+
+    fn move_left(&mut self, select: bool) {
+        match self.selection() {
+            Some((start, _)) if !select => self.move_to(start, false),
+            _ => self.move_to(self.cursor.saturating_sub(1), select),
+        }
+    }
+
+    fn move_right(&mut self, select: bool) {
+        match self.selection() {
+            Some((_, end)) if !select => self.move_to(end, false),
+            _ => self.move_to(self.cursor + 1, select),
+        }
+    }
+
+    fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.cursor = self.chars.len();
+        if self.cursor == 0 {
+            self.anchor = None;
+        }
+    }
+
+    fn copy(&self) -> LuaResult<()> {
+        if self.selection().is_some() {
+            set_clipboard_text(&self.selected_text())?;
+        }
+        Ok(())
+    }
+
+    fn cut(&mut self) -> LuaResult<bool> {
+        self.copy()?;
+        Ok(self.delete_selection())
+    }
+
+    fn paste(&mut self) -> bool {
+        self.insert(&clipboard_text())
+    }
+
+    /// Apply this frame's typed characters and editing keys; returns true if the text changed.
+    fn update(&mut self) -> LuaResult<bool> {
+        let down = |keys: &[KeyboardKey]| keys.iter().any(|k| unsafe { ffi::IsKeyDown(*k as i32) });
+        let pressed = |key: KeyboardKey| unsafe {
+            ffi::IsKeyPressed(key as i32) || ffi::IsKeyPressedRepeat(key as i32)
+        };
+        let shift = down(&[KeyboardKey::KEY_LEFT_SHIFT, KeyboardKey::KEY_RIGHT_SHIFT]);
+        let command = down(&[
+            KeyboardKey::KEY_LEFT_CONTROL,
+            KeyboardKey::KEY_RIGHT_CONTROL,
+            KeyboardKey::KEY_LEFT_SUPER,
+            KeyboardKey::KEY_RIGHT_SUPER,
+        ]);
+
+        let mut changed = false;
+        while let Some(c) = char_pressed() {
+            changed |= self.insert(c.encode_utf8(&mut [0; 4]));
+        }
+
+        if command {
+            if pressed(KeyboardKey::KEY_A) {
+                self.select_all();
+            }
+            if pressed(KeyboardKey::KEY_C) {
+                self.copy()?;
+            }
+            if pressed(KeyboardKey::KEY_X) {
+                changed |= self.cut()?;
+            }
+            if pressed(KeyboardKey::KEY_V) {
+                changed |= self.paste();
+            }
+        }
+
+        if pressed(KeyboardKey::KEY_BACKSPACE) {
+            changed |= self.backspace();
+        }
+        if pressed(KeyboardKey::KEY_DELETE) {
+            changed |= self.delete();
+        }
+        if pressed(KeyboardKey::KEY_LEFT) {
+            self.move_left(shift);
+        }
+        if pressed(KeyboardKey::KEY_RIGHT) {
+            self.move_right(shift);
+        }
+        if pressed(KeyboardKey::KEY_HOME) {
+            self.move_to(0, shift);
+        }
+        if pressed(KeyboardKey::KEY_END) {
+            self.move_to(self.chars.len(), shift);
+        }
+        Ok(changed)
+    }
+}
+
+impl LuaUserData for LuaTextInput {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("text", |_, this| Ok(this.text()));
+        fields.add_field_method_set("text", |_, this, text: String| {
+            this.set_text(&text);
+            Ok(())
+        });
+        fields.add_field_method_get("cursor", |_, this| Ok(this.cursor));
+        fields.add_field_method_set("cursor", |_, this, cursor: usize| {
+            this.move_to(cursor, false);
+            Ok(())
+        });
+        fields.add_field_method_get("length", |_, this| Ok(this.chars.len()));
+        fields.add_field_method_get("max_length", |_, this| Ok(this.max_length));
+        fields.add_field_method_set("max_length", |_, this, max: Option<usize>| {
+            this.set_max_length(max);
+            Ok(())
+        });
+    }
+
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method_mut("update", |_, this, ()| this.update());
+        methods.add_method_mut("insert", |_, this, text: String| Ok(this.insert(&text)));
+        methods.add_method_mut("backspace", |_, this, ()| Ok(this.backspace()));
+        methods.add_method_mut("delete", |_, this, ()| Ok(this.delete()));
+        methods.add_method_mut("move_left", |_, this, select: Option<bool>| {
+            this.move_left(select.unwrap_or(false));
+            Ok(())
+        });
+        methods.add_method_mut("move_right", |_, this, select: Option<bool>| {
+            this.move_right(select.unwrap_or(false));
+            Ok(())
+        });
+        methods.add_method_mut("move_home", |_, this, select: Option<bool>| {
+            this.move_to(0, select.unwrap_or(false));
+            Ok(())
+        });
+        methods.add_method_mut("move_end", |_, this, select: Option<bool>| {
+            let end = this.chars.len();
+            this.move_to(end, select.unwrap_or(false));
+            Ok(())
+        });
+        methods.add_method_mut("select_all", |_, this, ()| {
+            this.select_all();
+            Ok(())
+        });
+        methods.add_method("get_selection", |_, this, ()| Ok(this.selection().unzip()));
+        methods.add_method("selected_text", |_, this, ()| Ok(this.selected_text()));
+        methods.add_method("copy", |_, this, ()| this.copy());
+        methods.add_method_mut("cut", |_, this, ()| this.cut());
+        methods.add_method_mut("paste", |_, this, ()| Ok(this.paste()));
+        methods.add_method_mut("clear", |_, this, ()| {
+            this.set_text("");
+            Ok(())
+        });
+    }
+}
+
+pub fn text_input(
+    _lua: &Lua,
+    (text, max_length): (Option<String>, Option<usize>),
+) -> LuaResult<LuaTextInput> {
+    Ok(LuaTextInput::new(&text.unwrap_or_default(), max_length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn editing_is_utf8_safe() {
+        let mut input = LuaTextInput::new("héllo", None);
+        input.move_left(false);
+        input.move_left(false);
+        input.move_left(false);
+        assert!(input.backspace());
+        assert_eq!(input.text(), "hllo");
+        assert!(input.insert("ü"));
+        assert_eq!(input.text(), "hüllo");
+        assert_eq!(input.cursor, 2);
+    }
+
+    #[test]
+    fn typing_replaces_the_selection() {
+        let mut input = LuaTextInput::new("hello world", None);
+        input.move_to(0, false);
+        for _ in 0..5 {
+            input.move_right(true);
+        }
+        assert_eq!(input.selection(), Some((0, 5)));
+        assert_eq!(input.selected_text(), "hello");
+        input.insert("goodbye");
+        assert_eq!(input.text(), "goodbye world");
+        assert_eq!(input.selection(), None);
+    }
+
+    #[test]
+    fn max_length_limits_insertions() {
+        let mut input = LuaTextInput::new("abc", Some(5));
+        assert!(input.insert("defg"));
+        assert_eq!(input.text(), "abcde");
+        assert!(!input.insert("x"));
+        input.set_max_length(Some(2));
+        assert_eq!(input.text(), "ab");
+    }
+
+    #[test]
+    fn moving_without_shift_collapses_the_selection() {
+        let mut input = LuaTextInput::new("abcdef", None);
+        input.select_all();
+        input.move_left(false);
+        assert_eq!(input.cursor, 0);
+        assert_eq!(input.selection(), None);
+        input.select_all();
+        input.move_right(false);
+        assert_eq!(input.cursor, 6);
+    }
+
+    #[test]
+    fn control_characters_are_dropped() {
+        let mut input = LuaTextInput::new("a\nb", None);
+        assert_eq!(input.text(), "ab");
+        assert!(!input.insert("\t"));
+    }
+}
@@ -0,0 +1,657 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::ffi::CString;
+
+use crate::font::LuaFont;
+use crate::{LuaColor, LuaRaylib, LuaRectangle, with_draw_handle};
+
+/// Option names accepted by the text layout options table.
+const LAYOUT_OPTIONS: &[&str] = &["wrap", "align", "valign", "ellipsis", "line_spacing"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Wrap {
+    #[default]
+    Word,
+    Char,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Align {
+    #[default]
+    Left,
+    Center,
+    Right,
+    Justify,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum VAlign {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+/// Pick the variant named `value` from `choices`, for the option called `option`.
+fn parse_choice<T: Copy>(
+    option: &str,
+    value: Option<String>,
+    choices: &[(&str, T)],
+) -> LuaResult<Option<T>> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    match choices.iter().find(|(name, _)| *name == value) {
+        Some((_, choice)) => Ok(Some(*choice)),
+        None => Err(LuaError::runtime(format!(
+            "Unknown {} '{}', expected one of: {}",
+            option,
+            value,
+            choices
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))),
+    }
+}
+
+/// Options table shared by `draw_text_boxed` and `layout_text`.
+#[derive(Debug, Clone, Default)]
+pub struct LayoutOptions {
+    wrap: Wrap,
+    align: Align,
+    valign: VAlign,
+    ellipsis: Option<String>,
+    line_spacing: f32,
+}
+
+impl FromLua for LayoutOptions {
+    fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
+        let table = match value {
+            LuaValue::Nil => return Ok(LayoutOptions::default()),
+            LuaValue::Table(table) => table,
+            other => {
+                return Err(LuaError::FromLuaConversionError {
+                    from: other.type_name(),
+                    to: "LayoutOptions".to_string(),
+                    message: Some("expected a table of text layout options".to_string()),
+                });
+            }
+        };
+
+        for pair in table.pairs::<LuaValue, LuaValue>() {
+            let (key, _) = pair?;
+            let known = match &key {
+                LuaValue::String(s) => LAYOUT_OPTIONS.contains(&&*s.to_str()?),
+                _ => false,
+            };
+            if !known {
+                return Err(LuaError::runtime(format!(
+                    "Unknown text layout option {}, expected one of: {}",
+                    key.to_string()?,
+                    LAYOUT_OPTIONS.join(", ")
+                )));
+            }
+        }
+
+        let wrap = parse_choice(
+            "wrap",
+            table.get("wrap")?,
+            &[
+                ("word", Wrap::Word),
+                ("char", Wrap::Char),
+                ("none", Wrap::None),
+            ],
+        )?;
+        let align = parse_choice(
+            "align",
+            table.get("align")?,
+            &[
+                ("left", Align::Left),
+                ("center", Align::Center),
+                ("right", Align::Right),
+                ("justify", Align::Justify),
+            ],
+        )?;
+        let valign = parse_choice(
+            "valign",
+            table.get("valign")?,
+            &[
+                ("top", VAlign::Top),
+                ("middle", VAlign::Middle),
+                ("bottom", VAlign::Bottom),
+            ],
+        )?;
+        let ellipsis = match table.get::<LuaValue>("ellipsis")? {
+            LuaValue::Nil | LuaValue::Boolean(false) => None,
+            LuaValue::Boolean(true) => Some("...".to_string()),
+            LuaValue::String(s) => Some(s.to_str()?.to_string()),
+            other => {
+                return Err(LuaError::runtime(format!(
+                    "ellipsis must be a boolean or a string, got {}",
+                    other.type_name()
+                )));
+            }
+        };
+
+        Ok(LayoutOptions {
+            wrap: wrap.unwrap_or_default(),
+            align: align.unwrap_or_default(),
+            valign: valign.unwrap_or_default(),
+            ellipsis,
+            line_spacing: table.get::<Option<f32>>("line_spacing")?.unwrap_or(0.0),
+        })
+    }
+}
+
+/// One laid out line. `start` and `end` are byte offsets into the source text.
+#[derive(Debug, Clone, PartialEq)]
+struct LayoutLine {
+    text: String,
+    start: usize,
+    end: usize,
+    x: f32,
+    y: f32,
+    width: f32,
+    /// Extra space added to every word gap when the line is justified.
+    gap: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct TextLayout {
+    lines: Vec<LayoutLine>,
+    font_size: f32,
+    truncated: bool,
+}
+
+/// A line found by wrapping, before it is positioned.
+struct Span {
+    start: usize,
+    end: usize,
+    /// Whether the paragraph continues on the next line, which makes it justifiable.
+    wrapped: bool,
+}
+
+/// Byte length of the longest prefix of `text` that fits in `max_width`,
+/// always at least one character so wrapping makes progress.
+fn fit_chars(text: &str, max_width: f32, measure: &impl Fn(&str) -> f32) -> usize {
+    let mut fit = 0;
+    for (i, c) in text.char_indices() {
+        let end = i + c.len_utf8();
+        if fit > 0 && measure(&text[..end]) > max_width {
+            break;
+        }
+        fit = end;
+    }
+    fit
+}
+
+/// Byte ranges of the whitespace separated words in `text`.
+fn words(text: &str) -> Vec<(usize, usize)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some(i),
+            (true, Some(s)) => {
+                words.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        words.push((s, text.len()));
+    }
+    words
+}
+
+/// Split one paragraph (no newlines) into lines, with offsets relative to `base`.
+fn wrap_paragraph(
+    text: &str,
+    base: usize,
+    wrap: Wrap,
+    max_width: f32,
+    measure: &impl Fn(&str) -> f32,
+    spans: &mut Vec<Span>,
+) {
+    let first = spans.len();
+    let mut push = |start: usize, end: usize| {
+        spans.push(Span {
+            start: base + start,
+            end: base + end,
+            wrapped: true,
+        })
+    };
+
+    match wrap {
+        Wrap::None => push(0, text.len()),
+        Wrap::Char => {
+            let mut start = 0;
+            while start < text.len() {
+                let end = start + fit_chars(&text[start..], max_width, measure);
+                push(start, end);
+                start = end;
+            }
+        }
+        Wrap::Word => {
+            let words = words(text);
+            let mut i = 0;
+            while i < words.len() {
+                // Words too long for a line on their own are broken between characters
+                let mut start = words[i].0;
+                let mut end = words[i].1;
+                while measure(&text[start..end]) > max_width {
+                    let split = start + fit_chars(&text[start..end], max_width, measure);
+                    if split == end {
+                        break;
+                    }
+                    push(start, split);
+                    start = split;
+                }
+
+                i += 1;
+                while i < words.len() && measure(&text[start..words[i].1]) <= max_width {
+                    end = words[i].1;
+                    i += 1;
+                }
+                push(start, end);
+            }
+        }
+    }
+
+    // Keep blank lines, and mark the paragraph's last line as not wrapped
+    match spans[first..].last_mut() {
+        Some(last) => last.wrapped = false,
+        None => spans.push(Span {
+            start: base,
+            end: base,
+            wrapped: false,
+        }),
+    }
+}
+
+/// Shorten `text` until it fits in `max_width` with `ellipsis` appended.
+fn ellipsize(text: &str, ellipsis: &str, max_width: f32, measure: &impl Fn(&str) -> f32) -> String {
+    let mut end = text.len();
+    loop {
+        let kept = text[..end].trim_end();
+        let candidate = format!("{}{}", kept, ellipsis);
+        if end == 0 || measure(&candidate) <= max_width {
+            return candidate;
+        }
+        end = text[..end].char_indices().last().map_or(0, |(i, _)| i);
+    }
+}
+
+/// Lay `text` out inside `rect`; `measure` gives the drawn width of a single line.
+fn layout(
+    text: &str,
+    rect: LuaRectangle,
+    size: f32,
+    options: &LayoutOptions,
+    measure: impl Fn(&str) -> f32,
+) -> TextLayout {
+    let mut spans = Vec::new();
+    let mut base = 0;
+    for line in text.split('\n') {
+        let paragraph = line.strip_suffix('\r').unwrap_or(line);
+        wrap_paragraph(
+            paragraph,
+            base,
+            options.wrap,
+            rect.width,
+            &measure,
+            &mut spans,
+        );
+        base += line.len() + 1;
+    }
+
+    let line_height = size + options.line_spacing;
+    let mut cut_last = false;
+    if options.ellipsis.is_some() {
+        let fit = ((rect.height + options.line_spacing) / line_height)
+            .floor()
+            .max(0.0) as usize;
+        if spans.len() > fit {
+            spans.truncate(fit);
+            cut_last = true;
+        }
+    }
+    let mut truncated = cut_last;
+
+    let count = spans.len();
+    let mut lines: Vec<LayoutLine> = spans
+        .into_iter()
+        .enumerate()
+        .map(|(i, span)| {
+            let source = &text[span.start..span.end];
+            let mut line_text = source.to_string();
+            let overflows = measure(source) > rect.width;
+            let cut = (cut_last && i + 1 == count) || overflows;
+            if let Some(ellipsis) = &options.ellipsis
+                && cut
+            {
+                line_text = ellipsize(source, ellipsis, rect.width, &measure);
+                truncated = true;
+            }
+
+            let mut width = measure(&line_text);
+            let mut gap = 0.0;
+            let gaps = words(&line_text).len().saturating_sub(1);
+            if options.align == Align::Justify && span.wrapped && !cut && gaps > 0 {
+                gap = ((rect.width - width) / gaps as f32).max(0.0);
+                width += gap * gaps as f32;
+            }
+            let x = rect.x
+                + match options.align {
+                    Align::Left | Align::Justify => 0.0,
+                    Align::Center => (rect.width - width) / 2.0,
+                    Align::Right => rect.width - width,
+                };
+            LayoutLine {
+                text: line_text,
+                start: span.start,
+                end: span.end,
+                x,
+                y: i as f32 * line_height,
+                width,
+                gap,
+            }
+        })
+        .collect();
+
+    let height = if count == 0 {
+        0.0
+    } else {
+        count as f32 * line_height - options.line_spacing
+    };
+    let top = rect.y
+        + match options.valign {
+            VAlign::Top => 0.0,
+            VAlign::Middle => (rect.height - height) / 2.0,
+            VAlign::Bottom => rect.height - height,
+        };
+    for line in &mut lines {
+        line.y += top;
+    }
+
+    TextLayout {
+        lines,
+        font_size: size,
+        truncated,
+    }
+}
+
+/// The font to lay text out with, falling back to raylib's default font.
+fn font_or_default(font: Option<LuaUserDataRef<LuaFont>>) -> LuaResult<ffi::Font> {
+    match font {
+        Some(font) => font.font(),
+        None => Ok(unsafe { ffi::GetFontDefault() }),
+    }
+}
+
+/// Lay out text with the widths raylib would draw it at.
+fn measure_layout(
+    font: ffi::Font,
+    text: &str,
+    rect: LuaRectangle,
+    size: f32,
+    spacing: f32,
+    options: &LayoutOptions,
+) -> LuaResult<TextLayout> {
+    if text.contains('\0') {
+        return Err(LuaError::runtime("Text must not contain NUL bytes"));
+    }
+    if let Some(ellipsis) = &options.ellipsis
+        && ellipsis.contains('\0')
+    {
+        return Err(LuaError::runtime("ellipsis must not contain NUL bytes"));
+    }
+    let measure = |line: &str| match CString::new(line) {
+        Ok(line) => unsafe { ffi::MeasureTextEx(font, line.as_ptr(), size, spacing).x },
+        Err(_) => 0.0,
+    };
+    Ok(layout(text, rect, size, options, measure))
+}
+
+fn layout_to_table(lua: &Lua, text: &str, layout: &TextLayout) -> LuaResult<LuaTable> {
+    let lines = lua.create_table()?;
+    let mut width: f32 = 0.0;
+    for line in &layout.lines {
+        // Character offsets, 1-based like string.sub, so they match utf8 functions
+        let start = text[..line.start].chars().count() + 1;
+        let length = text[line.start..line.end].chars().count();
+        let entry = lua.create_table()?;
+        entry.set("text", line.text.as_str())?;
+        entry.set("start", start)?;
+        entry.set("length", length)?;
+        entry.set("x", line.x)?;
+        entry.set("y", line.y)?;
+        entry.set("width", line.width)?;
+        entry.set("height", layout.font_size)?;
+        lines.push(entry)?;
+        width = width.max(line.width);
+    }
+
+    let result = lua.create_table()?;
+    let height = match (layout.lines.first(), layout.lines.last()) {
+        (Some(first), Some(last)) => last.y + layout.font_size - first.y,
+        _ => 0.0,
+    };
+    result.set("lines", lines)?;
+    result.set("width", width)?;
+    result.set("height", height)?;
+    result.set("truncated", layout.truncated)?;
+    Ok(result)
+}
+
+fn layout_text(
+    lua: &Lua,
+    (font, text, rect, size, spacing, options): (
+        Option<LuaUserDataRef<LuaFont>>,
+        String,
+        LuaRectangle,
+        f32,
+        f32,
+        LayoutOptions,
+    ),
+) -> LuaResult<LuaTable> {
+    let layout = measure_layout(font_or_default(font)?, &text, rect, size, spacing, &options)?;
+    layout_to_table(lua, &text, &layout)
+}
+
+/// Register the module-level text layout function.
+pub fn register_text_layout_functions(lua: &Lua, exports: &LuaTable) -> LuaResult<()> {
+    exports.set("layout_text", lua.create_function(layout_text)?)?;
+    Ok(())
+}
+
+/// Boxed text drawing methods on the window.
+pub fn add_text_layout_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    methods.add_method(
+        "draw_text_boxed",
+        |_,
+         _this,
+         (font, text, rect, size, spacing, color, options): (
+            Option<LuaUserDataRef<LuaFont>>,
+            String,
+            LuaRectangle,
+            f32,
+            f32,
+            LuaColor,
+            LayoutOptions,
+        )| {
+            let font = font_or_default(font)?;
+            let layout = measure_layout(font, &text, rect, size, spacing, &options)?;
+            let color: ffi::Color = color.into();
+            let draw = |text: &str, x: f32, y: f32| {
+                if let Ok(text) = CString::new(text) {
+                    unsafe {
+                        ffi::DrawTextEx(
+                            font,
+                            text.as_ptr(),
+                            ffi::Vector2 { x, y },
+                            size,
+                            spacing,
+                            color,
+                        )
+                    };
+                }
+            };
+            let measure = |text: &str| match CString::new(text) {
+                Ok(text) => unsafe { ffi::MeasureTextEx(font, text.as_ptr(), size, spacing).x },
+                Err(_) => 0.0,
+            };
+
+            with_draw_handle("draw_text_boxed", |_| {
+                for line in &layout.lines {
+                    if line.gap == 0.0 {
+                        draw(&line.text, line.x, line.y);
+                        continue;
+                    }
+                    // Justified lines are drawn word by word with the gap added
+                    for (n, (start, end)) in words(&line.text).into_iter().enumerate() {
+                        let offset = if start == 0 {
+                            0.0
+                        } else {
+                            measure(&line.text[..start]) + spacing
+                        };
+                        draw(
+                            &line.text[start..end],
+                            line.x + offset + line.gap * n as f32,
+                            line.y,
+                        );
+                    }
+                }
+            })?;
+            Ok(())
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every character is 10 units wide
+    fn measure(text: &str) -> f32 {
+        text.chars().count() as f32 * 10.0
+    }
+
+    fn texts(layout: &TextLayout) -> Vec<&str> {
+        layout.lines.iter().map(|line| line.text.as_str()).collect()
+    }
+
+    fn options(wrap: Wrap, align: Align) -> LayoutOptions {
+        LayoutOptions {
+            wrap,
+            align,
+            ..LayoutOptions::default()
+        }
+    }
+
+    #[test]
+    fn word_wrap_breaks_between_words_and_splits_long_words() {
+        let rect = LuaRectangle::new(0.0, 0.0, 100.0, 200.0);
+        let wrapped = layout(
+            "the quick brown fox jumps\nabcdefghijklmno end",
+            rect,
+            10.0,
+            &options(Wrap::Word, Align::Left),
+            measure,
+        );
+        assert_eq!(
+            texts(&wrapped),
+            ["the quick", "brown fox", "jumps", "abcdefghij", "klmno end"]
+        );
+        assert_eq!(wrapped.lines[1].y, 10.0);
+    }
+
+    #[test]
+    fn char_wrap_is_utf8_aware() {
+        let rect = LuaRectangle::new(0.0, 0.0, 30.0, 100.0);
+        let wrapped = layout(
+            "héllö wörld",
+            rect,
+            10.0,
+            &options(Wrap::Char, Align::Left),
+            measure,
+        );
+        assert_eq!(texts(&wrapped), ["hél", "lö ", "wör", "ld"]);
+        assert_eq!(
+            &"héllö wörld"[wrapped.lines[1].start..wrapped.lines[1].end],
+            "lö "
+        );
+    }
+
+    #[test]
+    fn alignment_positions_lines() {
+        let rect = LuaRectangle::new(5.0, 0.0, 100.0, 100.0);
+        let center = layout(
+            "ab",
+            rect,
+            10.0,
+            &options(Wrap::Word, Align::Center),
+            measure,
+        );
+        assert_eq!(center.lines[0].x, 45.0);
+        let right = layout(
+            "ab",
+            rect,
+            10.0,
+            &options(Wrap::Word, Align::Right),
+            measure,
+        );
+        assert_eq!(right.lines[0].x, 85.0);
+
+        let justified = layout(
+            "aa bb cc dd ee",
+            rect,
+            10.0,
+            &options(Wrap::Word, Align::Justify),
+            measure,
+        );
+        assert_eq!(texts(&justified), ["aa bb cc", "dd ee"]);
+        assert_eq!(justified.lines[0].gap, 10.0);
+        assert_eq!(justified.lines[0].width, 100.0);
+        // The paragraph's last line stays ragged
+        assert_eq!(justified.lines[1].gap, 0.0);
+    }
+
+    #[test]
+    fn vertical_alignment_centers_the_block() {
+        let rect = LuaRectangle::new(0.0, 10.0, 100.0, 100.0);
+        let options = LayoutOptions {
+            valign: VAlign::Middle,
+            line_spacing: 10.0,
+            ..LayoutOptions::default()
+        };
+        let block = layout("one\ntwo", rect, 20.0, &options, measure);
+        // Two 20 unit lines with 10 between them make a 50 unit block
+        assert_eq!(block.lines[0].y, 35.0);
+        assert_eq!(block.lines[1].y, 65.0);
+    }
+
+    #[test]
+    fn ellipsis_truncates_overflowing_text() {
+        let rect = LuaRectangle::new(0.0, 0.0, 100.0, 25.0);
+        let options = LayoutOptions {
+            ellipsis: Some("...".to_string()),
+            ..LayoutOptions::default()
+        };
+        let wrapped = layout("the quick brown fox jumps", rect, 10.0, &options, measure);
+        assert_eq!(texts(&wrapped), ["the quick", "brown f..."]);
+        assert!(wrapped.truncated);
+
+        let single = LayoutOptions {
+            wrap: Wrap::None,
+            ..options
+        };
+        let single_line = layout("a very long single line", rect, 10.0, &single, measure);
+        assert_eq!(texts(&single_line), ["a very..."]);
+        assert!(single_line.truncated);
+    }
+}
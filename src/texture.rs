@@ -0,0 +1,219 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+
+use crate::draw::DrawArgs;
+use crate::{LivenessToken, LuaRaylib, with_draw_handle};
+
+/// GPU texture loaded through a window, or the color buffer of a render
+/// texture.
+///
+/// A loaded texture is unloaded when the userdata is collected or closed. If
+/// the window that created it goes away first, the texture is forgotten instead
+/// of unloaded, because its GL context no longer exists. A render texture's
+/// color buffer is never unloaded here; it stays usable while its render
+/// texture is loaded.
+pub struct LuaTexture {
+    texture: Option<WeakTexture2D>,
+    window: LivenessToken,
+    /// Liveness of the render texture that owns this texture, if any.
+    owner: Option<LivenessToken>,
+}
+
+impl LuaTexture {
+    pub fn new(texture: Texture2D, window: LivenessToken) -> Self {
+        LuaTexture {
+            // Unloading is handled by `unload`, which knows whether the window is alive
+            texture: Some(unsafe { texture.make_weak() }),
+            window,
+            owner: None,
+        }
+    }
+
+    /// A texture borrowed from a render texture, usable while `owner` is alive.
+    pub fn borrowed(texture: WeakTexture2D, window: LivenessToken, owner: LivenessToken) -> Self {
+        LuaTexture {
+            texture: Some(texture),
+            window,
+            owner: Some(owner),
+        }
+    }
+
+    /// Borrow the underlying texture, failing if it can no longer be used.
+    pub fn texture(&self) -> LuaResult<&WeakTexture2D> {
+        match &self.texture {
+            Some(_) if !self.window.is_alive() => Err(LuaError::runtime(
+                "Texture belongs to a window that has been closed",
+            )),
+            Some(_) if self.owner.as_ref().is_some_and(|owner| !owner.is_alive()) => Err(
+                LuaError::runtime("Texture belongs to a render texture that has been unloaded"),
+            ),
+            Some(texture) => Ok(texture),
+            None => Err(LuaError::runtime("Texture has already been unloaded")),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.texture().is_ok()
+    }
+
+    fn unload(&mut self) {
+        if let Some(texture) = self.texture.take()
+            && self.owner.is_none()
+            && self.window.is_alive()
+        {
+            unsafe { ffi::UnloadTexture(*texture.as_ref()) };
+        }
+    }
+}
+
+impl Drop for LuaTexture {
+    fn drop(&mut self) {
+        self.unload();
+    }
+}
+
+impl LuaUserData for LuaTexture {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("id", |_, this| Ok(this.texture()?.id));
+        fields.add_field_method_get("width", |_, this| Ok(this.texture()?.width));
+        fields.add_field_method_get("height", |_, this| Ok(this.texture()?.height));
+        fields.add_field_method_get("mipmaps", |_, this| Ok(this.texture()?.mipmaps));
+    }
+
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        methods.add_method("is_valid", |_, this, ()| Ok(this.is_valid()));
+
+        methods.add_method_mut("unload", |_, this, ()| {
+            this.unload();
+            Ok(())
+        });
+
+        methods.add_meta_method_mut(LuaMetaMethod::Close, |_, this, _: LuaValue| {
+            this.unload();
+            Ok(())
+        });
+    }
+}
+
+/// Texture loading and drawing methods on the window.
+pub fn add_texture_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    methods.add_method_mut("load_texture", |_, this, path: String| {
+        this.check_open("load_texture")?;
+        let texture = this
+            .rl
+            .load_texture(&this.thread, &path)
+            .map_err(|e| LuaError::runtime(e.to_string()))?;
+        Ok(LuaTexture::new(texture, this.alive.clone()))
+    });
+
+    // Positions as x, y or as vectors, see DrawArgs
+    for name in ["draw_texture", "draw_texture_v"] {
+        methods.add_method_mut(name, move |lua, _this, args: LuaMultiValue| {
+            let mut args = DrawArgs::new(lua, name, args);
+            let texture: LuaUserDataRef<LuaTexture> = args.value("texture", "a Texture")?;
+            let position = args.vector("position")?;
+            let tint = args.color("tint")?;
+            let texture = texture.texture()?;
+            with_draw_handle(name, |d| d.draw_texture_v(texture, position, tint))?;
+            Ok(())
+        });
+    }
+
+    methods.add_method_mut("draw_texture_ex", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_texture_ex", args);
+        let texture: LuaUserDataRef<LuaTexture> = args.value("texture", "a Texture")?;
+        let position = args.vector("position")?;
+        let rotation = args.number("rotation")?;
+        let scale = args.number("scale")?;
+        let tint = args.color("tint")?;
+        let texture = texture.texture()?;
+        with_draw_handle("draw_texture_ex", |d| {
+            d.draw_texture_ex(texture, position, rotation, scale, tint)
+        })?;
+        Ok(())
+    });
+
+    methods.add_method_mut("draw_texture_rec", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_texture_rec", args);
+        let texture: LuaUserDataRef<LuaTexture> = args.value("texture", "a Texture")?;
+        let source = args.rectangle("source")?;
+        let position = args.vector("position")?;
+        let tint = args.color("tint")?;
+        let texture = texture.texture()?;
+        with_draw_handle("draw_texture_rec", |d| {
+            d.draw_texture_rec(texture, source, position, tint)
+        })?;
+        Ok(())
+    });
+
+    methods.add_method_mut("draw_texture_pro", |lua, _this, args: LuaMultiValue| {
+        let mut args = DrawArgs::new(lua, "draw_texture_pro", args);
+        let texture: LuaUserDataRef<LuaTexture> = args.value("texture", "a Texture")?;
+        let source = args.rectangle("source")?;
+        let dest = args.rectangle("dest")?;
+        let origin = args.vector("origin")?;
+        let rotation = args.number("rotation")?;
+        let tint = args.color("tint")?;
+        let texture = texture.texture()?;
+        with_draw_handle("draw_texture_pro", |d| {
+            d.draw_texture_pro(texture, source, dest, origin, rotation, tint)
+        })?;
+        Ok(())
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Never handed to raylib: the tests only take paths that skip UnloadTexture
+    fn fake_texture() -> WeakTexture2D {
+        unsafe {
+            WeakTexture2D::from_raw(ffi::Texture2D {
+                id: 1,
+                width: 4,
+                height: 2,
+                mipmaps: 1,
+                format: 7,
+            })
+        }
+    }
+
+    fn error(texture: &LuaTexture) -> String {
+        texture.texture().err().unwrap().to_string()
+    }
+
+    #[test]
+    fn closing_the_window_invalidates_its_textures() {
+        let window = LivenessToken::new();
+        let mut texture = LuaTexture {
+            texture: Some(fake_texture()),
+            window: window.clone(),
+            owner: None,
+        };
+        assert_eq!(texture.texture().unwrap().width, 4);
+        assert!(texture.is_valid());
+
+        window.invalidate();
+        assert!(!texture.is_valid());
+        assert!(error(&texture).contains("window that has been closed"));
+        // Forgotten rather than unloaded, there is no GL context left
+        texture.unload();
+        assert!(error(&texture).contains("already been unloaded"));
+    }
+
+    #[test]
+    fn render_texture_colors_follow_their_owner() {
+        let owner = LivenessToken::new();
+        let mut texture = LuaTexture::borrowed(fake_texture(), LivenessToken::new(), owner.clone());
+        assert!(texture.is_valid());
+
+        owner.invalidate();
+        assert!(error(&texture).contains("render texture that has been unloaded"));
+        // The owner unloads the color buffer, so unloading only lets it go
+        texture.unload();
+        assert!(error(&texture).contains("already been unloaded"));
+        texture.unload();
+        assert!(!texture.is_valid());
+    }
+}
@@ -0,0 +1,465 @@
+use mlua::prelude::*;
+
+use crate::easing::{EaseFn, find_easing, linear};
+use crate::{LuaColor, LuaVector2, LuaVector3};
+
+/// A field value a tween knows how to interpolate.
+#[derive(Clone, Copy)]
+enum TweenValue {
+    Number(f64),
+    Vector2(LuaVector2),
+    Vector3(LuaVector3),
+    Color(LuaColor),
+}
+
+impl TweenValue {
+    /// Read the starting value of a field; its type decides how the field is tweened.
+    fn start(lua: &Lua, field: &str, value: LuaValue) -> LuaResult<Self> {
+        match value {
+            LuaValue::Integer(i) => Ok(TweenValue::Number(i as f64)),
+            LuaValue::Number(n) => Ok(TweenValue::Number(n)),
+            LuaValue::UserData(ref ud) if ud.is::<LuaVector2>() => {
+                Ok(TweenValue::Vector2(*ud.borrow::<LuaVector2>()?))
+            }
+            LuaValue::UserData(ref ud) if ud.is::<LuaVector3>() => {
+                Ok(TweenValue::Vector3(*ud.borrow::<LuaVector3>()?))
+            }
+            LuaValue::Table(ref table) => {
+                // Plain tables are read by shape: {x, y}, {x, y, z} or {r, g, b, a}
+                let start = if table.contains_key("z")? {
+                    LuaVector3::from_lua(value, lua).map(TweenValue::Vector3)
+                } else if table.contains_key("x")? {
+                    LuaVector2::from_lua(value, lua).map(TweenValue::Vector2)
+                } else if table.contains_key(1)? {
+                    LuaColor::from_lua(value, lua).map(TweenValue::Color)
+                } else {
+                    Err(LuaError::runtime("unrecognised table"))
+                };
+                start.map_err(|_| {
+                    LuaError::runtime(format!(
+                        "tween: field '{}' is a table that is not {{x, y}}, {{x, y, z}} or a color {{r, g, b, a}}",
+                        field
+                    ))
+                })
+            }
+            other => Err(LuaError::runtime(format!(
+                "tween: field '{}' is a {}, expected a number, Vector2, Vector3 or color",
+                field,
+                other.type_name()
+            ))),
+        }
+    }
+
+    /// Convert a goal to the same kind of value as the field's start value.
+    fn goal(&self, lua: &Lua, field: &str, value: LuaValue) -> LuaResult<Self> {
+        let goal = match self {
+            TweenValue::Number(_) => f64::from_lua(value, lua).map(TweenValue::Number),
+            TweenValue::Vector2(_) => LuaVector2::from_lua(value, lua).map(TweenValue::Vector2),
+            TweenValue::Vector3(_) => LuaVector3::from_lua(value, lua).map(TweenValue::Vector3),
+            TweenValue::Color(_) => LuaColor::from_lua(value, lua).map(TweenValue::Color),
+        };
+        goal.map_err(|_| {
+            LuaError::runtime(format!(
+                "tween: goal for field '{}' must be a {} like its current value",
+                field,
+                self.kind()
+            ))
+        })
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            TweenValue::Number(_) => "number",
+            TweenValue::Vector2(_) => "Vector2",
+            TweenValue::Vector3(_) => "Vector3",
+            TweenValue::Color(_) => "color",
+        }
+    }
+
+    /// Interpolate towards `goal`; `t` may leave `[0, 1]` for overshooting curves.
+    fn lerp(&self, goal: &TweenValue, t: f32) -> TweenValue {
+        match (self, goal) {
+            (TweenValue::Number(a), TweenValue::Number(b)) => {
+                TweenValue::Number(a + (b - a) * t as f64)
+            }
+            (TweenValue::Vector2(a), TweenValue::Vector2(b)) => TweenValue::Vector2(a.lerp(*b, t)),
+            (TweenValue::Vector3(a), TweenValue::Vector3(b)) => TweenValue::Vector3(a.lerp(*b, t)),
+            (TweenValue::Color(a), TweenValue::Color(b)) => {
+                let mix = |a: u8, b: u8| {
+                    (a as f32 + (b as f32 - a as f32) * t)
+                        .round()
+                        .clamp(0.0, 255.0) as u8
+                };
+                TweenValue::Color(LuaColor {
+                    r: mix(a.r, b.r),
+                    g: mix(a.g, b.g),
+                    b: mix(a.b, b.b),
+                    a: mix(a.a, b.a),
+                })
+            }
+            _ => *self,
+        }
+    }
+}
+
+impl IntoLua for TweenValue {
+    fn into_lua(self, lua: &Lua) -> LuaResult<LuaValue> {
+        match self {
+            TweenValue::Number(n) => n.into_lua(lua),
+            TweenValue::Vector2(v) => v.into_lua(lua),
+            TweenValue::Vector3(v) => v.into_lua(lua),
+            TweenValue::Color(c) => c.into_lua(lua),
+        }
+    }
+}
+
+/// Easing given either by name from `rlmlua.ease` or as a Lua function.
+pub enum Easing {
+    Curve(EaseFn),
+    Function(LuaFunction),
+}
+
+impl Easing {
+    fn apply(&self, t: f32) -> LuaResult<f32> {
+        match self {
+            Easing::Curve(f) => Ok(f(t)),
+            Easing::Function(f) => f.call(t),
+        }
+    }
+}
+
+impl FromLua for Easing {
+    fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
+        match value {
+            LuaValue::Nil => Ok(Easing::Curve(linear)),
+            LuaValue::Function(f) => Ok(Easing::Function(f)),
+            LuaValue::String(s) => {
+                let name = s.to_str()?;
+                find_easing(&name).map(Easing::Curve).ok_or_else(|| {
+                    LuaError::runtime(format!(
+                        "tween: unknown easing '{}', expected a name from rlmlua.ease",
+                        name
+                    ))
+                })
+            }
+            other => Err(LuaError::FromLuaConversionError {
+                from: other.type_name(),
+                to: "Easing".to_string(),
+                message: Some("expected an easing name or function".to_string()),
+            }),
+        }
+    }
+}
+
+/// Clock of a tween: where it is in the current run and how many repeats are
+/// left.
+///
+/// `repeats` counts how many more times the tween plays after the first run
+/// (`-1` repeats forever). With `yoyo` every repeat runs in the opposite
+/// direction, so a yoyo tween with one repeat ends back at its start values.
+struct Playback {
+    duration: f32,
+    elapsed: f32,
+    yoyo: bool,
+    repeats: i32,
+    played: i32,
+    reversed: bool,
+    done: bool,
+}
+
+impl Playback {
+    fn new(duration: f32, yoyo: bool, repeats: i32) -> Self {
+        Playback {
+            duration,
+            elapsed: 0.0,
+            yoyo,
+            repeats,
+            played: 0,
+            reversed: false,
+            done: false,
+        }
+    }
+
+    /// Progress through the current run, before easing.
+    fn progress(&self) -> f32 {
+        (self.elapsed / self.duration).clamp(0.0, 1.0)
+    }
+
+    /// Progress through the current run in its direction of play.
+    fn direction_progress(&self) -> f32 {
+        let t = self.progress();
+        if self.reversed { 1.0 - t } else { t }
+    }
+
+    /// Advance the clock, returns true on the update the tween finishes.
+    ///
+    /// Whole runs are skipped arithmetically, so a huge `dt` on a tween that
+    /// repeats forever costs the same as a small one.
+    fn advance(&mut self, dt: f32) -> LuaResult<bool> {
+        if !dt.is_finite() {
+            return Err(LuaError::runtime(format!(
+                "tween: update needs a finite dt, got {}",
+                dt
+            )));
+        }
+        if self.done {
+            return Ok(false);
+        }
+        let duration = self.duration as f64;
+        let elapsed = self.elapsed as f64 + dt.max(0.0) as f64;
+        // Runs completed by this update, each of which starts a repeat if
+        // one is left
+        let runs = (elapsed / duration).floor();
+        let left = if self.repeats < 0 {
+            f64::INFINITY
+        } else {
+            (self.repeats - self.played).max(0) as f64
+        };
+        let started = runs.min(left);
+        if self.yoyo && started % 2.0 == 1.0 {
+            self.reversed = !self.reversed;
+        }
+        self.played = self.played.saturating_add(started as i32);
+        if runs > left {
+            self.elapsed = self.duration;
+            self.done = true;
+        } else {
+            self.elapsed = (elapsed % duration) as f32;
+        }
+        Ok(self.done)
+    }
+
+    fn reset(&mut self) {
+        self.elapsed = 0.0;
+        self.played = 0;
+        self.reversed = false;
+        self.done = false;
+    }
+}
+
+/// Animates fields of a Lua table towards goal values, advanced with `update(dt)`.
+pub struct LuaTween {
+    target: LuaTable,
+    fields: Vec<(String, TweenValue, TweenValue)>,
+    playback: Playback,
+    easing: Easing,
+    on_complete: Option<LuaFunction>,
+}
+
+impl LuaTween {
+    /// Advance the clock, returns true on the update the tween finishes.
+    fn advance(&mut self, dt: f32) -> LuaResult<bool> {
+        let finished = self.playback.advance(dt)?;
+        // Once finished the target is left alone
+        if finished || !self.playback.done {
+            self.apply()?;
+        }
+        Ok(finished)
+    }
+
+    /// Write the interpolated values back to the target table.
+    fn apply(&self) -> LuaResult<()> {
+        let t = self.easing.apply(self.playback.direction_progress())?;
+        for (field, start, goal) in &self.fields {
+            self.target.set(field.as_str(), start.lerp(goal, t))?;
+        }
+        Ok(())
+    }
+}
+
+impl LuaUserData for LuaTween {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("target", |_, this| Ok(this.target.clone()));
+        fields.add_field_method_get("duration", |_, this| Ok(this.playback.duration));
+        fields.add_field_method_get("elapsed", |_, this| Ok(this.playback.elapsed));
+        fields.add_field_method_get("yoyo", |_, this| Ok(this.playback.yoyo));
+        fields.add_field_method_set("yoyo", |_, this, val: bool| {
+            this.playback.yoyo = val;
+            Ok(())
+        });
+        fields.add_field_method_get("repeats", |_, this| Ok(this.playback.repeats));
+        fields.add_field_method_set("repeats", |_, this, val: i32| {
+            this.playback.repeats = val;
+            Ok(())
+        });
+        fields.add_field_method_get("on_complete", |_, this| Ok(this.on_complete.clone()));
+        fields.add_field_method_set("on_complete", |_, this, val: Option<LuaFunction>| {
+            this.on_complete = val;
+            Ok(())
+        });
+    }
+
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        // `on_complete` runs after the tween is released, so it may use the tween
+        methods.add_function("update", |_, (ud, dt): (LuaAnyUserData, f32)| {
+            let (finished, on_complete, target) = {
+                let mut this = ud.borrow_mut::<LuaTween>()?;
+                let finished = this.advance(dt)?;
+                (finished, this.on_complete.clone(), this.target.clone())
+            };
+            if finished && let Some(on_complete) = on_complete {
+                on_complete.call::<()>((target, ud))?;
+            }
+            Ok(finished)
+        });
+        methods.add_method("is_done", |_, this, ()| Ok(this.playback.done));
+        methods.add_method("progress", |_, this, ()| Ok(this.playback.progress()));
+        methods.add_method_mut("reset", |_, this, ()| {
+            this.playback.reset();
+            this.apply()
+        });
+    }
+}
+
+pub fn tween(
+    lua: &Lua,
+    (target, goals, duration, easing, options): (LuaTable, LuaTable, f32, Easing, Option<LuaTable>),
+) -> LuaResult<LuaTween> {
+    if duration <= 0.0 || !duration.is_finite() {
+        return Err(LuaError::runtime(format!(
+            "tween: duration must be a positive number of seconds, got {}",
+            duration
+        )));
+    }
+
+    let mut fields = Vec::new();
+    for pair in goals.pairs::<String, LuaValue>() {
+        let (field, goal) = pair?;
+        let start = TweenValue::start(lua, &field, target.get(field.as_str())?)?;
+        let goal = start.goal(lua, &field, goal)?;
+        fields.push((field, start, goal));
+    }
+
+    let (yoyo, repeats, on_complete) = match options {
+        Some(options) => (
+            options.get::<Option<bool>>("yoyo")?.unwrap_or(false),
+            options.get::<Option<i32>>("repeats")?.unwrap_or(0),
+            options.get("on_complete")?,
+        ),
+        None => (false, 0, None),
+    };
+
+    Ok(LuaTween {
+        target,
+        fields,
+        playback: Playback::new(duration, yoyo, repeats),
+        easing,
+        on_complete,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn yoyo_repeats_play_in_alternating_directions() {
+        let mut playback = Playback::new(1.0, true, -1);
+        playback.advance(0.25).unwrap();
+        assert_eq!(playback.direction_progress(), 0.25);
+        playback.advance(1.0).unwrap();
+        assert_eq!(playback.direction_progress(), 0.75);
+        playback.advance(1.0).unwrap();
+        assert_eq!(playback.direction_progress(), 0.25);
+        // An odd number of skipped runs ends up reversed
+        playback.advance(3.0).unwrap();
+        assert_eq!(playback.direction_progress(), 0.75);
+    }
+
+    #[test]
+    fn finite_repeats_end_at_the_right_value_and_finish_once() {
+        let mut playback = Playback::new(2.0, true, 1);
+        assert!(!playback.advance(3.0).unwrap());
+        assert!(!playback.done);
+        assert!(playback.advance(10.0).unwrap());
+        assert!(playback.done);
+        // A yoyo tween with one repeat ends back at its start
+        assert_eq!(playback.direction_progress(), 0.0);
+        assert!(!playback.advance(1.0).unwrap());
+
+        let mut playback = Playback::new(1.0, false, 2);
+        assert!(playback.advance(100.0).unwrap());
+        assert_eq!(playback.played, 2);
+        assert_eq!(playback.direction_progress(), 1.0);
+    }
+
+    #[test]
+    fn endless_tweens_skip_whole_runs() {
+        let mut playback = Playback::new(0.5, true, -1);
+        assert!(!playback.advance(f32::MAX).unwrap());
+        assert!(!playback.done);
+        assert!(playback.elapsed < playback.duration);
+        assert!(playback.advance(f32::INFINITY).is_err());
+        assert!(playback.advance(f32::NAN).is_err());
+    }
+}
+
+// Tests needing a Lua state, which only links when Lua is built in:
+// `cargo test --no-default-features --features runner`
+#[cfg(all(test, not(feature = "module")))]
+mod lua_tests {
+    use super::*;
+
+    #[test]
+    fn on_complete_runs_once() {
+        let lua = Lua::new();
+        lua.globals()
+            .set("tween", lua.create_function(tween).unwrap())
+            .unwrap();
+        let (calls, x, done): (i32, f64, bool) = lua
+            .load(
+                r#"
+                local calls = 0
+                local target = { x = 0 }
+                local t = tween(target, { x = 10 }, 1, nil, {
+                    repeats = 2,
+                    on_complete = function() calls = calls + 1 end,
+                })
+                t:update(0.5)
+                t:update(1e30)
+                t:update(5)
+                assert(not pcall(t.update, t, math.huge))
+                return calls, target.x, t:is_done()
+                "#,
+            )
+            .eval()
+            .unwrap();
+        assert_eq!((calls, x, done), (1, 10.0, true));
+    }
+
+    #[test]
+    fn plain_tables_are_tweened_by_shape() {
+        let lua = Lua::new();
+        lua.globals()
+            .set("tween", lua.create_function(tween).unwrap())
+            .unwrap();
+        let (x, y, z, r): (f32, f32, f32, u8) = lua
+            .load(
+                r#"
+                local target = {
+                    position = { x = 0, y = 0 },
+                    velocity = { x = 0, y = 0, z = 0 },
+                    tint = { 0, 0, 0 },
+                }
+                local t = tween(target, {
+                    position = { x = 10, y = 20 },
+                    velocity = { x = 0, y = 0, z = 4 },
+                    tint = { 200, 0, 0 },
+                }, 1)
+                t:update(0.5)
+                return target.position.x, target.position.y, target.velocity.z, target.tint[1]
+                "#,
+            )
+            .eval()
+            .unwrap();
+        assert_eq!((x, y, z, r), (5.0, 10.0, 2.0, 100));
+
+        let err = lua
+            .load("tween({ size = { w = 1 } }, { size = { w = 2 } }, 1)")
+            .exec()
+            .unwrap_err();
+        assert!(err.to_string().contains(
+            "tween: field 'size' is a table that is not {x, y}, {x, y, z} or a color {r, g, b, a}"
+        ));
+    }
+}
@@ -0,0 +1,390 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LuaVector2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl LuaVector2 {
+    pub fn new(x: f32, y: f32) -> Self {
+        LuaVector2 { x, y }
+    }
+
+    pub fn dot(self, other: LuaVector2) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Z component of the 3D cross product of the two vectors.
+    pub fn cross(self, other: LuaVector2) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn length_sqr(self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn length(self) -> f32 {
+        self.length_sqr().sqrt()
+    }
+
+    /// Unit vector in the same direction, or the vector itself if it is zero.
+    pub fn normalize(self) -> LuaVector2 {
+        let length = self.length();
+        if length == 0.0 {
+            return self;
+        }
+        scale(self, 1.0 / length)
+    }
+
+    /// Signed angle in radians from this vector to `other`.
+    pub fn angle_to(self, other: LuaVector2) -> f32 {
+        self.cross(other).atan2(self.dot(other))
+    }
+
+    /// Rotate by `angle` radians.
+    pub fn rotate(self, angle: f32) -> LuaVector2 {
+        let (sin, cos) = angle.sin_cos();
+        LuaVector2::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    pub fn distance(self, other: LuaVector2) -> f32 {
+        zip(self, other, |a, b| a - b).length()
+    }
+
+    pub fn lerp(self, other: LuaVector2, amount: f32) -> LuaVector2 {
+        zip(self, other, |a, b| a + amount * (b - a))
+    }
+
+    /// Step towards `target` by at most `max_distance`.
+    pub fn move_towards(self, target: LuaVector2, max_distance: f32) -> LuaVector2 {
+        let delta = zip(target, self, |a, b| a - b);
+        let distance = delta.length();
+        if distance == 0.0 || (max_distance >= 0.0 && distance <= max_distance) {
+            return target;
+        }
+        zip(self, delta, |v, d| v + d / distance * max_distance)
+    }
+
+    /// Reflect the vector off a surface with the given normal.
+    pub fn reflect(self, normal: LuaVector2) -> LuaVector2 {
+        let d = 2.0 * self.dot(normal);
+        zip(self, normal, |v, n| v - d * n)
+    }
+
+    /// The vector rotated 90 degrees counter-clockwise.
+    pub fn perpendicular(self) -> LuaVector2 {
+        LuaVector2::new(-self.y, self.x)
+    }
+}
+
+fn zip(a: LuaVector2, b: LuaVector2, f: impl Fn(f32, f32) -> f32) -> LuaVector2 {
+    LuaVector2::new(f(a.x, b.x), f(a.y, b.y))
+}
+
+fn scale(v: LuaVector2, s: f32) -> LuaVector2 {
+    LuaVector2::new(v.x * s, v.y * s)
+}
+
+/// Vector type a scalar operand is spread over.
+pub trait Splat: Copy {
+    fn splat(s: f32) -> Self;
+}
+
+impl Splat for LuaVector2 {
+    fn splat(s: f32) -> Self {
+        LuaVector2::new(s, s)
+    }
+}
+
+/// Operand of a vector arithmetic metamethod: another vector or a scalar on
+/// either side. Shared with Vector3, which spreads scalars over three
+/// components.
+#[derive(Clone, Copy, Debug)]
+pub enum Operand<V = LuaVector2> {
+    Vector(V),
+    Scalar(f32),
+}
+
+impl<V: Splat> Operand<V> {
+    pub fn splat(self) -> V {
+        match self {
+            Operand::Vector(v) => v,
+            Operand::Scalar(s) => V::splat(s),
+        }
+    }
+}
+
+impl<V: FromLua> FromLua for Operand<V> {
+    fn from_lua(value: LuaValue, lua: &Lua) -> LuaResult<Self> {
+        match value {
+            LuaValue::Integer(i) => Ok(Operand::Scalar(i as f32)),
+            LuaValue::Number(n) => Ok(Operand::Scalar(n as f32)),
+            other => V::from_lua(other, lua).map(Operand::Vector),
+        }
+    }
+}
+
+fn arith(a: Operand, b: Operand, f: impl Fn(f32, f32) -> f32) -> LuaVector2 {
+    zip(a.splat(), b.splat(), f)
+}
+
+pub fn add(a: Operand, b: Operand) -> LuaVector2 {
+    arith(a, b, |a, b| a + b)
+}
+
+pub fn sub(a: Operand, b: Operand) -> LuaVector2 {
+    arith(a, b, |a, b| a - b)
+}
+
+pub fn mul(a: Operand, b: Operand) -> LuaVector2 {
+    arith(a, b, |a, b| a * b)
+}
+
+pub fn div(a: Operand, b: Operand) -> LuaVector2 {
+    arith(a, b, |a, b| a / b)
+}
+
+pub fn rem(a: Operand, b: Operand) -> LuaVector2 {
+    arith(a, b, |a, b| a % b)
+}
+
+pub fn pow(a: Operand, b: Operand) -> LuaVector2 {
+    arith(a, b, f32::powf)
+}
+
+impl std::fmt::Display for LuaVector2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Vector2({}, {})", self.x, self.y)
+    }
+}
+
+/// String form of one side of a `..` expression.
+fn concat_part(lua: &Lua, value: LuaValue) -> LuaResult<String> {
+    if let LuaValue::UserData(ud) = &value
+        && let Ok(v) = ud.borrow::<LuaVector2>()
+    {
+        return Ok(v.to_string());
+    }
+    match lua.coerce_string(value.clone())? {
+        Some(s) => Ok(s.to_str()?.to_string()),
+        None => Err(LuaError::runtime(format!(
+            "attempt to concatenate a {} value with a Vector2",
+            value.type_name()
+        ))),
+    }
+}
+
+impl LuaUserData for LuaVector2 {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("x", |_, this| Ok(this.x));
+        fields.add_field_method_set("x", |_, this, val| {
+            this.x = val;
+            Ok(())
+        });
+        fields.add_field_method_get("y", |_, this| Ok(this.y));
+        fields.add_field_method_set("y", |_, this, val| {
+            this.y = val;
+            Ok(())
+        });
+    }
+
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        // Arithmetic works with a vector or a number on either side
+        methods.add_meta_function(LuaMetaMethod::Add, |_, (a, b): (Operand, Operand)| {
+            Ok(add(a, b))
+        });
+        methods.add_meta_function(LuaMetaMethod::Sub, |_, (a, b): (Operand, Operand)| {
+            Ok(sub(a, b))
+        });
+        methods.add_meta_function(LuaMetaMethod::Mul, |_, (a, b): (Operand, Operand)| {
+            Ok(mul(a, b))
+        });
+        methods.add_meta_function(LuaMetaMethod::Div, |_, (a, b): (Operand, Operand)| {
+            Ok(div(a, b))
+        });
+        methods.add_meta_function(LuaMetaMethod::Mod, |_, (a, b): (Operand, Operand)| {
+            Ok(rem(a, b))
+        });
+        methods.add_meta_function(LuaMetaMethod::Pow, |_, (a, b): (Operand, Operand)| {
+            Ok(pow(a, b))
+        });
+        methods.add_meta_method(LuaMetaMethod::Unm, |_, this, ()| {
+            Ok(LuaVector2::new(-this.x, -this.y))
+        });
+        methods.add_meta_method(LuaMetaMethod::Len, |_, this, ()| Ok(this.length()));
+        methods.add_meta_method(LuaMetaMethod::Eq, |_, this, other: LuaVector2| {
+            Ok((this.x - other.x).abs() < f32::EPSILON && (this.y - other.y).abs() < f32::EPSILON)
+        });
+
+        // Ordering compares lengths, so `a < b` is the same as `#a < #b`
+        methods.add_meta_method(LuaMetaMethod::Lt, |_, this, other: LuaVector2| {
+            Ok(this.length_sqr() < other.length_sqr())
+        });
+        methods.add_meta_method(LuaMetaMethod::Le, |_, this, other: LuaVector2| {
+            Ok(this.length_sqr() <= other.length_sqr())
+        });
+
+        methods.add_meta_method(LuaMetaMethod::ToString, |_, this, ()| Ok(this.to_string()));
+        methods.add_meta_function(
+            LuaMetaMethod::Concat,
+            |lua, (a, b): (LuaValue, LuaValue)| Ok(concat_part(lua, a)? + &concat_part(lua, b)?),
+        );
+
+        methods.add_method("length", |_, this, ()| Ok(this.length()));
+        methods.add_method("length_sqr", |_, this, ()| Ok(this.length_sqr()));
+        methods.add_method("normalize", |_, this, ()| Ok(this.normalize()));
+        methods.add_method("dot", |_, this, other: LuaVector2| Ok(this.dot(other)));
+        methods.add_method("cross", |_, this, other: LuaVector2| Ok(this.cross(other)));
+        methods.add_method("angle", |_, this, other: Option<LuaVector2>| {
+            Ok(match other {
+                Some(other) => this.angle_to(other),
+                None => this.y.atan2(this.x),
+            })
+        });
+        methods.add_method("rotate", |_, this, angle: f32| Ok(this.rotate(angle)));
+        methods.add_method("distance", |_, this, other: LuaVector2| {
+            Ok(this.distance(other))
+        });
+        methods.add_method("lerp", |_, this, (other, amount): (LuaVector2, f32)| {
+            Ok(this.lerp(other, amount))
+        });
+        methods.add_method(
+            "move_towards",
+            |_, this, (target, max_distance): (LuaVector2, f32)| {
+                Ok(this.move_towards(target, max_distance))
+            },
+        );
+        methods.add_method("clamp", |_, this, (min, max): (Operand, Operand)| {
+            let (min, max) = (min.splat(), max.splat());
+            Ok(LuaVector2::new(
+                this.x.clamp(min.x.min(max.x), max.x.max(min.x)),
+                this.y.clamp(min.y.min(max.y), max.y.max(min.y)),
+            ))
+        });
+        methods.add_method("reflect", |_, this, normal: LuaVector2| {
+            Ok(this.reflect(normal))
+        });
+        methods.add_method("perpendicular", |_, this, ()| Ok(this.perpendicular()));
+        methods.add_method("copy", |_, this, ()| Ok(*this));
+        methods.add_method("unpack", |_, this, ()| Ok((this.x, this.y)));
+    }
+}
+
+impl From<LuaVector2> for Vector2 {
+    fn from(value: LuaVector2) -> Self {
+        Vector2::new(value.x, value.y)
+    }
+}
+
+impl From<LuaVector2> for raylib::ffi::Vector2 {
+    fn from(value: LuaVector2) -> Self {
+        raylib::ffi::Vector2 {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+impl From<Vector2> for LuaVector2 {
+    fn from(value: Vector2) -> Self {
+        LuaVector2 {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+impl From<raylib::ffi::Vector2> for LuaVector2 {
+    fn from(value: raylib::ffi::Vector2) -> Self {
+        LuaVector2 {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+impl FromLua for LuaVector2 {
+    fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
+        match value {
+            LuaValue::Table(table) => {
+                let x = table.get("x")?;
+                let y = table.get("y")?;
+                Ok(LuaVector2 { x, y })
+            }
+            LuaValue::UserData(ud) => {
+                // Try to extract LuaVector2 from userdata
+                ud.borrow::<LuaVector2>().map(|v| *v)
+            }
+            _ => Err(LuaError::FromLuaConversionError {
+                from: value.type_name(),
+                to: "LuaVector2".to_string(),
+                message: Some("expected table with x,y fields or Vector2 userdata".to_string()),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f32 = 1e-5;
+
+    fn a() -> LuaVector2 {
+        LuaVector2::new(3.0, -4.0)
+    }
+
+    fn b() -> LuaVector2 {
+        LuaVector2::new(-1.5, 2.0)
+    }
+
+    fn assert_close(actual: LuaVector2, expected: Vector2) {
+        assert!(
+            (actual.x - expected.x).abs() < EPS && (actual.y - expected.y).abs() < EPS,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn scalar_operators_work_on_both_sides() {
+        let ra = Vector2::from(a());
+        let v = Operand::Vector(a());
+        let two = Operand::Scalar(2.0);
+        assert_close(mul(v, two), ra * 2.0);
+        assert_close(mul(two, v), ra * 2.0);
+        assert_close(div(v, two), ra / 2.0);
+        assert_close(div(two, v), Vector2::new(2.0, 2.0) / ra);
+    }
+
+    #[test]
+    fn methods_match_raymath() {
+        let (ra, rb) = (Vector2::from(a()), Vector2::from(b()));
+        assert!((a().length() - ra.length()).abs() < EPS);
+        assert!((a().length_sqr() - ra.length_sqr()).abs() < EPS);
+        assert!((a().dot(b()) - ra.dot(rb)).abs() < EPS);
+        assert!((a().distance(b()) - ra.distance_to(rb)).abs() < EPS);
+        assert_close(a().normalize(), ra.normalized());
+        assert_close(a().lerp(b(), 0.3), ra.lerp(rb, 0.3));
+        assert_close(a().rotate(0.7), ra.rotated(0.7));
+    }
+
+    #[test]
+    fn move_towards_stops_at_target() {
+        let start = LuaVector2::new(0.0, 0.0);
+        let target = LuaVector2::new(10.0, 0.0);
+        assert_eq!(start.move_towards(target, 4.0), LuaVector2::new(4.0, 0.0));
+        assert_eq!(start.move_towards(target, 40.0), target);
+    }
+
+    #[test]
+    fn reflect_and_perpendicular() {
+        let normal = LuaVector2::new(0.0, 1.0);
+        assert_eq!(a().reflect(normal), LuaVector2::new(3.0, 4.0));
+        assert_eq!(a().perpendicular(), LuaVector2::new(4.0, 3.0));
+        assert!(a().perpendicular().dot(a()).abs() < EPS);
+    }
+}
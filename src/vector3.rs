@@ -0,0 +1,490 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+
+use crate::vector2::Splat;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LuaVector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl LuaVector3 {
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        LuaVector3 { x, y, z }
+    }
+
+    pub fn dot(self, other: LuaVector3) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(self, other: LuaVector3) -> LuaVector3 {
+        LuaVector3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Unit vector in the same direction, or the vector itself if it is zero.
+    pub fn normalize(self) -> LuaVector3 {
+        let length = self.length();
+        if length == 0.0 {
+            return self;
+        }
+        scale(self, 1.0 / length)
+    }
+
+    pub fn distance(self, other: LuaVector3) -> f32 {
+        zip(self, other, |a, b| a - b).length()
+    }
+
+    pub fn lerp(self, other: LuaVector3, amount: f32) -> LuaVector3 {
+        zip(self, other, |a, b| a + amount * (b - a))
+    }
+
+    /// Rotate around `axis` by `angle` radians (Euler-Rodrigues formula).
+    pub fn rotate_by_axis_angle(self, axis: LuaVector3, angle: f32) -> LuaVector3 {
+        let axis = axis.normalize();
+        let half = angle / 2.0;
+        let w = scale(axis, half.sin());
+        let wv = w.cross(self);
+        let wwv = w.cross(wv);
+        let wv = scale(wv, 2.0 * half.cos());
+        let wwv = scale(wwv, 2.0);
+        zip(zip(self, wv, |a, b| a + b), wwv, |a, b| a + b)
+    }
+
+    /// Transform the point by a 4x4 matrix, including its translation.
+    pub fn transform(self, mat: &Matrix) -> LuaVector3 {
+        LuaVector3::new(
+            mat.m0 * self.x + mat.m4 * self.y + mat.m8 * self.z + mat.m12,
+            mat.m1 * self.x + mat.m5 * self.y + mat.m9 * self.z + mat.m13,
+            mat.m2 * self.x + mat.m6 * self.y + mat.m10 * self.z + mat.m14,
+        )
+    }
+
+    /// Reflect the vector off a surface with the given normal.
+    pub fn reflect(self, normal: LuaVector3) -> LuaVector3 {
+        let d = 2.0 * self.dot(normal);
+        zip(self, normal, |v, n| v - d * n)
+    }
+}
+
+fn zip(a: LuaVector3, b: LuaVector3, f: impl Fn(f32, f32) -> f32) -> LuaVector3 {
+    LuaVector3::new(f(a.x, b.x), f(a.y, b.y), f(a.z, b.z))
+}
+
+fn scale(v: LuaVector3, s: f32) -> LuaVector3 {
+    LuaVector3::new(v.x * s, v.y * s, v.z * s)
+}
+
+impl Splat for LuaVector3 {
+    fn splat(s: f32) -> Self {
+        LuaVector3::new(s, s, s)
+    }
+}
+
+type Operand = crate::vector2::Operand<LuaVector3>;
+
+fn arith(a: Operand, b: Operand, f: impl Fn(f32, f32) -> f32) -> LuaVector3 {
+    zip(a.splat(), b.splat(), f)
+}
+
+pub fn add(a: Operand, b: Operand) -> LuaVector3 {
+    arith(a, b, |a, b| a + b)
+}
+
+pub fn sub(a: Operand, b: Operand) -> LuaVector3 {
+    arith(a, b, |a, b| a - b)
+}
+
+pub fn mul(a: Operand, b: Operand) -> LuaVector3 {
+    arith(a, b, |a, b| a * b)
+}
+
+pub fn div(a: Operand, b: Operand) -> LuaVector3 {
+    arith(a, b, |a, b| a / b)
+}
+
+pub fn rem(a: Operand, b: Operand) -> LuaVector3 {
+    arith(a, b, |a, b| a % b)
+}
+
+pub fn pow(a: Operand, b: Operand) -> LuaVector3 {
+    arith(a, b, f32::powf)
+}
+
+impl From<LuaVector3> for Vector3 {
+    fn from(value: LuaVector3) -> Self {
+        Vector3::new(value.x, value.y, value.z)
+    }
+}
+
+impl From<LuaVector3> for raylib::ffi::Vector3 {
+    fn from(value: LuaVector3) -> Self {
+        raylib::ffi::Vector3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl From<raylib::ffi::Vector3> for LuaVector3 {
+    fn from(value: raylib::ffi::Vector3) -> Self {
+        LuaVector3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl From<Vector3> for LuaVector3 {
+    fn from(value: Vector3) -> Self {
+        LuaVector3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl LuaUserData for LuaVector3 {
+    fn add_fields<F: LuaUserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("x", |_, this| Ok(this.x));
+        fields.add_field_method_set("x", |_, this, val| {
+            this.x = val;
+            Ok(())
+        });
+        fields.add_field_method_get("y", |_, this| Ok(this.y));
+        fields.add_field_method_set("y", |_, this, val| {
+            this.y = val;
+            Ok(())
+        });
+        fields.add_field_method_get("z", |_, this| Ok(this.z));
+        fields.add_field_method_set("z", |_, this, val| {
+            this.z = val;
+            Ok(())
+        });
+    }
+
+    fn add_methods<M: LuaUserDataMethods<Self>>(methods: &mut M) {
+        // Arithmetic works with a vector or a number on either side
+        methods.add_meta_function(LuaMetaMethod::Add, |_, (a, b): (Operand, Operand)| {
+            Ok(add(a, b))
+        });
+        methods.add_meta_function(LuaMetaMethod::Sub, |_, (a, b): (Operand, Operand)| {
+            Ok(sub(a, b))
+        });
+        methods.add_meta_function(LuaMetaMethod::Mul, |_, (a, b): (Operand, Operand)| {
+            Ok(mul(a, b))
+        });
+        methods.add_meta_function(LuaMetaMethod::Div, |_, (a, b): (Operand, Operand)| {
+            Ok(div(a, b))
+        });
+        methods.add_meta_function(LuaMetaMethod::Mod, |_, (a, b): (Operand, Operand)| {
+            Ok(rem(a, b))
+        });
+        methods.add_meta_function(LuaMetaMethod::Pow, |_, (a, b): (Operand, Operand)| {
+            Ok(pow(a, b))
+        });
+        methods.add_meta_method(LuaMetaMethod::Unm, |_, this, ()| {
+            Ok(LuaVector3::new(-this.x, -this.y, -this.z))
+        });
+        methods.add_meta_method(LuaMetaMethod::Len, |_, this, ()| Ok(this.length()));
+        methods.add_meta_method(LuaMetaMethod::Eq, |_, this, other: LuaVector3| {
+            Ok((this.x - other.x).abs() < f32::EPSILON
+                && (this.y - other.y).abs() < f32::EPSILON
+                && (this.z - other.z).abs() < f32::EPSILON)
+        });
+        methods.add_meta_method(LuaMetaMethod::ToString, |_, this, ()| {
+            Ok(format!("Vector3({}, {}, {})", this.x, this.y, this.z))
+        });
+
+        methods.add_method("dot", |_, this, other: LuaVector3| Ok(this.dot(other)));
+        methods.add_method("cross", |_, this, other: LuaVector3| Ok(this.cross(other)));
+        methods.add_method("length", |_, this, ()| Ok(this.length()));
+        methods.add_method("normalize", |_, this, ()| Ok(this.normalize()));
+        methods.add_method("distance", |_, this, other: LuaVector3| {
+            Ok(this.distance(other))
+        });
+        methods.add_method("lerp", |_, this, (other, amount): (LuaVector3, f32)| {
+            Ok(this.lerp(other, amount))
+        });
+        methods.add_method(
+            "rotate_by_axis_angle",
+            |_, this, (axis, angle): (LuaVector3, f32)| Ok(this.rotate_by_axis_angle(axis, angle)),
+        );
+        methods.add_method("transform", |_, this, mat: LuaMatrix| {
+            Ok(this.transform(&mat.0))
+        });
+        methods.add_method("reflect", |_, this, normal: LuaVector3| {
+            Ok(this.reflect(normal))
+        });
+    }
+}
+
+impl FromLua for LuaVector3 {
+    fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
+        match value {
+            LuaValue::Table(table) => {
+                let x: f32 = table.get("x")?;
+                let y: f32 = table.get("y")?;
+                let z: f32 = table.get("z")?;
+                Ok(LuaVector3 { x, y, z })
+            }
+            LuaValue::UserData(ud) => ud.borrow::<LuaVector3>().map(|v| *v),
+            _ => Err(LuaError::FromLuaConversionError {
+                from: value.type_name(),
+                to: "LuaVector3".to_string(),
+                message: Some("expected table with x,y,z fields or Vector3 userdata".to_string()),
+            }),
+        }
+    }
+}
+
+/// 4x4 matrix read from a Lua table, either with raylib's `m0`..`m15` fields
+/// or as 16 numbers listed row by row.
+pub struct LuaMatrix(pub Matrix);
+
+impl FromLua for LuaMatrix {
+    fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
+        let LuaValue::Table(table) = value else {
+            return Err(LuaError::FromLuaConversionError {
+                from: value.type_name(),
+                to: "Matrix".to_string(),
+                message: Some("expected table with m0..m15 fields or 16 numbers".to_string()),
+            });
+        };
+
+        let mut m = [0.0f32; 16];
+        if table.contains_key("m0")? {
+            // raylib field names are column-major: m0, m1, m2, m3 is the first column
+            for (i, value) in m.iter_mut().enumerate() {
+                let (row, col) = (i / 4, i % 4);
+                *value = table.get(format!("m{}", col * 4 + row))?;
+            }
+        } else {
+            if table.raw_len() != 16 {
+                return Err(LuaError::runtime(format!(
+                    "Matrix table must have 16 numbers, got {}",
+                    table.raw_len()
+                )));
+            }
+            for (i, value) in m.iter_mut().enumerate() {
+                *value = table.get(i + 1)?;
+            }
+        }
+
+        Ok(LuaMatrix::from_rows(m))
+    }
+}
+
+impl LuaMatrix {
+    /// Matrix from 16 numbers listed row by row.
+    pub fn from_rows(m: [f32; 16]) -> Self {
+        LuaMatrix(Matrix {
+            m0: m[0],
+            m4: m[1],
+            m8: m[2],
+            m12: m[3],
+            m1: m[4],
+            m5: m[5],
+            m9: m[6],
+            m13: m[7],
+            m2: m[8],
+            m6: m[9],
+            m10: m[10],
+            m14: m[11],
+            m3: m[12],
+            m7: m[13],
+            m11: m[14],
+            m15: m[15],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f32 = 1e-5;
+
+    fn a() -> LuaVector3 {
+        LuaVector3::new(1.5, -2.0, 3.25)
+    }
+
+    fn b() -> LuaVector3 {
+        LuaVector3::new(-0.5, 4.0, 2.0)
+    }
+
+    fn assert_close(actual: LuaVector3, expected: Vector3) {
+        assert!(
+            (actual.x - expected.x).abs() < EPS
+                && (actual.y - expected.y).abs() < EPS
+                && (actual.z - expected.z).abs() < EPS,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    fn v(x: LuaVector3) -> Operand {
+        Operand::Vector(x)
+    }
+
+    fn s(x: f32) -> Operand {
+        Operand::Scalar(x)
+    }
+
+    #[test]
+    fn vector_vector_operators() {
+        let (ra, rb) = (Vector3::from(a()), Vector3::from(b()));
+        assert_close(add(v(a()), v(b())), ra + rb);
+        assert_close(sub(v(a()), v(b())), ra - rb);
+        assert_close(mul(v(a()), v(b())), ra * rb);
+        assert_close(div(v(a()), v(b())), ra / rb);
+    }
+
+    #[test]
+    fn vector_scalar_operators() {
+        let ra = Vector3::from(a());
+        assert_close(add(v(a()), s(2.0)), ra + 2.0);
+        assert_close(sub(v(a()), s(2.0)), ra - 2.0);
+        assert_close(mul(v(a()), s(2.0)), ra * 2.0);
+        assert_close(div(v(a()), s(2.0)), ra / 2.0);
+    }
+
+    #[test]
+    fn scalar_vector_operators() {
+        let ra = Vector3::from(a());
+        let two = Vector3::new(2.0, 2.0, 2.0);
+        assert_close(add(s(2.0), v(a())), two + ra);
+        assert_close(sub(s(2.0), v(a())), two - ra);
+        assert_close(mul(s(2.0), v(a())), ra * 2.0);
+        assert_close(div(s(2.0), v(a())), two / ra);
+    }
+
+    #[test]
+    fn remainder_and_power_are_componentwise() {
+        assert_close(
+            rem(v(a()), s(1.0)),
+            Vector3::new(1.5 % 1.0, -2.0 % 1.0, 3.25 % 1.0),
+        );
+        assert_close(
+            pow(v(a()), s(2.0)),
+            Vector3::new(1.5f32.powf(2.0), 4.0, 3.25f32.powf(2.0)),
+        );
+    }
+
+    #[test]
+    fn products_match_raymath() {
+        let (ra, rb) = (Vector3::from(a()), Vector3::from(b()));
+        assert!((a().dot(b()) - ra.dot(rb)).abs() < EPS);
+        assert_close(a().cross(b()), ra.cross(rb));
+    }
+
+    #[test]
+    fn length_and_distance_match_raymath() {
+        let (ra, rb) = (Vector3::from(a()), Vector3::from(b()));
+        assert!((a().length() - ra.length()).abs() < EPS);
+        assert!((a().distance(b()) - ra.distance_to(rb)).abs() < EPS);
+    }
+
+    #[test]
+    fn normalize_matches_raymath() {
+        assert_close(a().normalize(), Vector3::from(a()).normalized());
+        assert_close(LuaVector3::new(0.0, 0.0, 0.0).normalize(), Vector3::zero());
+    }
+
+    #[test]
+    fn lerp_matches_raymath() {
+        let (ra, rb) = (Vector3::from(a()), Vector3::from(b()));
+        assert_close(a().lerp(b(), 0.25), ra.lerp(rb, 0.25));
+    }
+
+    #[test]
+    fn rotate_by_axis_angle_matches_quaternion_rotation() {
+        let axis = LuaVector3::new(0.3, 1.0, -0.2);
+        let angle = 1.2;
+        let expected =
+            Vector3::from(a()).rotate_by(Quaternion::from_axis_angle(axis.into(), angle));
+        assert_close(a().rotate_by_axis_angle(axis, angle), expected);
+    }
+
+    #[test]
+    fn transform_matches_raymath() {
+        let mat =
+            Matrix::rotate_xyz(Vector3::new(0.4, -1.1, 0.7)) * Matrix::translate(1.0, 2.0, 3.0);
+        assert_close(a().transform(&mat), Vector3::from(a()).transform_with(mat));
+    }
+
+    #[test]
+    fn reflect_matches_raymath() {
+        let normal = LuaVector3::new(0.0, 1.0, 0.0);
+        assert_close(
+            a().reflect(normal),
+            Vector3::from(a()).reflect_from(normal.into()),
+        );
+    }
+
+    #[test]
+    fn matrix_rows_put_the_translation_in_the_last_column() {
+        #[rustfmt::skip]
+        let LuaMatrix(mat) = LuaMatrix::from_rows([
+            1.0, 0.0, 0.0, 5.0,
+            0.0, 2.0, 0.0, 6.0,
+            0.0, 0.0, 3.0, 7.0,
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+        assert_eq!((mat.m12, mat.m13, mat.m14), (5.0, 6.0, 7.0));
+        assert_eq!((mat.m0, mat.m5, mat.m10, mat.m15), (1.0, 2.0, 3.0, 1.0));
+        assert_close(a().transform(&mat), Vector3::new(6.5, 2.0, 16.75));
+    }
+}
+
+// Tests needing a Lua state, which only links when Lua is built in:
+// `cargo test --no-default-features --features runner`
+#[cfg(all(test, not(feature = "module")))]
+mod lua_tests {
+    use super::*;
+
+    #[test]
+    fn matrix_tables_accept_fields_and_rows() {
+        let lua = Lua::new();
+        let rows: LuaMatrix = lua
+            .load("return { 1, 0, 0, 5,  0, 2, 0, 6,  0, 0, 3, 7,  0, 0, 0, 1 }")
+            .eval()
+            .unwrap();
+        let fields: LuaMatrix = lua
+            .load(
+                r#"
+                local m = {}
+                for i = 0, 15 do m["m" .. i] = 0 end
+                m.m0, m.m5, m.m10, m.m15 = 1, 2, 3, 1
+                m.m12, m.m13, m.m14 = 5, 6, 7
+                return m
+                "#,
+            )
+            .eval()
+            .unwrap();
+        assert_eq!(rows.0, fields.0);
+        assert_eq!((rows.0.m12, rows.0.m13, rows.0.m14), (5.0, 6.0, 7.0));
+
+        let short: LuaResult<LuaMatrix> = lua.load("return { 1, 2, 3 }").eval();
+        assert!(
+            short
+                .err()
+                .unwrap()
+                .to_string()
+                .contains("Matrix table must have 16 numbers, got 3")
+        );
+    }
+}
@@ -0,0 +1,268 @@
+use mlua::prelude::*;
+use raylib::prelude::*;
+
+use crate::image::LuaImage;
+use crate::{LuaRaylib, LuaVector2};
+
+/// Option names accepted by `init_window`, used for the unknown option error.
+const WINDOW_OPTIONS: &[&str] = &[
+    "resizable",
+    "vsync",
+    "msaa_4x",
+    "fullscreen",
+    "undecorated",
+    "highdpi",
+    "transparent",
+    "hidden",
+    "always_run",
+    "strict",
+];
+
+/// Configuration flags passed to `init_window` as an options table.
+#[derive(Default)]
+pub struct WindowOptions {
+    resizable: bool,
+    vsync: bool,
+    msaa_4x: bool,
+    fullscreen: bool,
+    undecorated: bool,
+    highdpi: bool,
+    transparent: bool,
+    hidden: bool,
+    always_run: bool,
+    /// Turns strict mode on or off when given, see `rl.set_strict`.
+    pub strict: Option<bool>,
+}
+
+impl WindowOptions {
+    /// Build the window with these flags applied.
+    pub fn build(&self, width: i32, height: i32, title: &str) -> (RaylibHandle, RaylibThread) {
+        let mut builder = raylib::init();
+        builder.size(width, height).title(title);
+        if self.resizable {
+            builder.resizable();
+        }
+        if self.vsync {
+            builder.vsync();
+        }
+        if self.msaa_4x {
+            builder.msaa_4x();
+        }
+        if self.fullscreen {
+            builder.fullscreen();
+        }
+        if self.undecorated {
+            builder.undecorated();
+        }
+        if self.transparent {
+            builder.transparent();
+        }
+
+        // The builder has no setters for these; raylib ORs config flags together,
+        // so setting them beforehand survives the builder's own SetConfigFlags.
+        let mut extra = 0u32;
+        if self.highdpi {
+            extra |= ConfigFlags::FLAG_WINDOW_HIGHDPI as u32;
+        }
+        if self.hidden {
+            extra |= ConfigFlags::FLAG_WINDOW_HIDDEN as u32;
+        }
+        if self.always_run {
+            extra |= ConfigFlags::FLAG_WINDOW_ALWAYS_RUN as u32;
+        }
+        if extra != 0 {
+            unsafe { ffi::SetConfigFlags(extra) };
+        }
+
+        builder.build()
+    }
+}
+
+impl FromLua for WindowOptions {
+    fn from_lua(value: LuaValue, _lua: &Lua) -> LuaResult<Self> {
+        let table = match value {
+            LuaValue::Nil => return Ok(WindowOptions::default()),
+            LuaValue::Table(table) => table,
+            other => {
+                return Err(LuaError::FromLuaConversionError {
+                    from: other.type_name(),
+                    to: "WindowOptions".to_string(),
+                    message: Some("expected a table of window flags".to_string()),
+                });
+            }
+        };
+
+        for pair in table.pairs::<LuaValue, LuaValue>() {
+            let (key, _) = pair?;
+            let known = match &key {
+                LuaValue::String(s) => WINDOW_OPTIONS.contains(&&*s.to_str()?),
+                _ => false,
+            };
+            if !known {
+                return Err(LuaError::runtime(format!(
+                    "init_window: unknown option {}, expected one of: {}",
+                    key.to_string()?,
+                    WINDOW_OPTIONS.join(", ")
+                )));
+            }
+        }
+
+        let flag = |name: &str| -> LuaResult<bool> {
+            Ok(table.get::<Option<bool>>(name)?.unwrap_or(false))
+        };
+        Ok(WindowOptions {
+            resizable: flag("resizable")?,
+            vsync: flag("vsync")?,
+            msaa_4x: flag("msaa_4x")?,
+            fullscreen: flag("fullscreen")?,
+            undecorated: flag("undecorated")?,
+            highdpi: flag("highdpi")?,
+            transparent: flag("transparent")?,
+            hidden: flag("hidden")?,
+            always_run: flag("always_run")?,
+            strict: table.get("strict")?,
+        })
+    }
+}
+
+/// Window management methods on the window.
+pub fn add_window_methods<M: LuaUserDataMethods<LuaRaylib>>(methods: &mut M) {
+    methods.add_method("set_window_title", |_, this, title: String| {
+        if title.contains('\0') {
+            return Err(LuaError::runtime(
+                "set_window_title: title must not contain NUL bytes",
+            ));
+        }
+        this.rl.set_window_title(&this.thread, &title);
+        Ok(())
+    });
+
+    methods.add_method_mut("set_window_size", |_, this, (width, height): (i32, i32)| {
+        this.rl.set_window_size(width, height);
+        Ok(())
+    });
+
+    methods.add_method_mut("set_window_position", |_, this, (x, y): (i32, i32)| {
+        this.rl.set_window_position(x, y);
+        Ok(())
+    });
+
+    methods.add_method_mut(
+        "set_window_min_size",
+        |_, this, (width, height): (i32, i32)| {
+            this.rl.set_window_min_size(width, height);
+            Ok(())
+        },
+    );
+
+    methods.add_method_mut("toggle_fullscreen", |_, this, ()| {
+        this.rl.toggle_fullscreen();
+        Ok(())
+    });
+
+    methods.add_method("toggle_borderless_windowed", |_, this, ()| {
+        this.rl.toggle_borderless_windowed();
+        Ok(())
+    });
+
+    methods.add_method_mut("maximize", |_, this, ()| {
+        this.rl.maximize_window();
+        Ok(())
+    });
+
+    methods.add_method_mut("minimize", |_, this, ()| {
+        this.rl.minimize_window();
+        Ok(())
+    });
+
+    methods.add_method_mut("restore", |_, this, ()| {
+        this.rl.restore_window();
+        Ok(())
+    });
+
+    methods.add_method("is_window_resized", |_, this, ()| {
+        Ok(this.rl.is_window_resized())
+    });
+
+    methods.add_method("is_window_focused", |_, this, ()| {
+        Ok(this.rl.is_window_focused())
+    });
+
+    methods.add_method("get_window_position", |_, this, ()| {
+        Ok(LuaVector2::from(this.rl.get_window_position()))
+    });
+
+    methods.add_method("get_window_scale_dpi", |_, this, ()| {
+        Ok(LuaVector2::from(this.rl.get_window_scale_dpi()))
+    });
+
+    methods.add_method_mut(
+        "set_window_icon",
+        |_, this, image: LuaUserDataRef<LuaImage>| {
+            this.rl.set_window_icon(&image.image);
+            Ok(())
+        },
+    );
+}
+
+// Tests needing a Lua state, which only links when Lua is built in:
+// `cargo test --no-default-features --features runner`
+#[cfg(all(test, not(feature = "module")))]
+mod lua_tests {
+    use super::*;
+
+    /// Flags in the order of `WINDOW_OPTIONS`.
+    fn flags(options: &WindowOptions) -> [bool; 9] {
+        [
+            options.resizable,
+            options.vsync,
+            options.msaa_4x,
+            options.fullscreen,
+            options.undecorated,
+            options.highdpi,
+            options.transparent,
+            options.hidden,
+            options.always_run,
+        ]
+    }
+
+    #[test]
+    fn each_option_sets_its_own_flag() {
+        let lua = Lua::new();
+        let options: WindowOptions = lua.load("return nil").eval().unwrap();
+        assert_eq!(flags(&options), [false; 9]);
+        assert_eq!(options.strict, None);
+
+        for (i, name) in WINDOW_OPTIONS.iter().take(9).enumerate() {
+            let options: WindowOptions = lua
+                .load(format!("return {{ {} = true }}", name))
+                .eval()
+                .unwrap();
+            let mut expected = [false; 9];
+            expected[i] = true;
+            assert_eq!(flags(&options), expected, "{}", name);
+            assert_eq!(options.strict, None);
+        }
+    }
+
+    #[test]
+    fn strict_is_only_set_when_given() {
+        let lua = Lua::new();
+        let options: WindowOptions = lua.load("return { strict = false }").eval().unwrap();
+        assert_eq!(options.strict, Some(false));
+        let options: WindowOptions = lua.load("return { strict = true }").eval().unwrap();
+        assert_eq!(options.strict, Some(true));
+    }
+
+    #[test]
+    fn unknown_options_list_the_valid_names() {
+        let lua = Lua::new();
+        let options: LuaResult<WindowOptions> = lua.load("return { vsinc = true }").eval();
+        let message = options.err().unwrap().to_string();
+        assert!(message.contains("init_window: unknown option vsinc, expected one of: resizable"));
+        assert!(message.contains("always_run, strict"));
+
+        let options: LuaResult<WindowOptions> = lua.load("return 'vsync'").eval();
+        assert!(options.is_err());
+    }
+}